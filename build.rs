@@ -7,7 +7,7 @@ use std::fs::File;
 fn main() {
   // let out_dir = env::var("OUT_DIR").unwrap();
   let output = File::create("src/itch.rs").unwrap();
-  Command::new("python").args(&["src/codegen.py", "src/nasdaq_totalview_itch.xml"])
+  Command::new("python").args(["src/codegen.py", "src/nasdaq_totalview_itch.xml"])
     .stdout(output)
     .status().unwrap();
 
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::error::AlphaError;
+
+// Fixed width ascii field, left justified and right padded with spaces on
+// the wire ("Alpha" in the ITCH spec).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Alpha<const N: usize>(pub [u8; N]);
+
+pub type Symbol = Alpha<8>;
+pub type Mpid = Alpha<4>;
+pub type IssueSubType = Alpha<2>;
+pub type Session = Alpha<10>;
+
+impl<const N: usize> Alpha<N> {
+  pub fn new(s: &str) -> Result<Self, AlphaError> {
+    if s.len() > N {
+      return Err(AlphaError::TooLong { max: N, actual: s.len() });
+    }
+    if !s.is_ascii() {
+      return Err(AlphaError::NotAscii);
+    }
+    let mut bytes = [b' '; N];
+    bytes[..s.len()].copy_from_slice(s.as_bytes());
+    Ok(Alpha(bytes))
+  }
+  // raw wire bytes, padding included
+  pub fn from_bytes(bytes: [u8; N]) -> Self {
+    Alpha(bytes)
+  }
+  pub fn as_bytes(&self) -> &[u8; N] {
+    &self.0
+  }
+  // without the trailing padding; bytes off the wire that aren't valid text
+  // are cut off at the first bad byte
+  pub fn as_str(&self) -> &str {
+    let trimmed = match self.0.iter().rposition(|c| *c != b' ') {
+      Some(last) => &self.0[..last+1],
+      None => &self.0[..0],
+    };
+    match std::str::from_utf8(trimmed) {
+      Ok(s) => s,
+      Err(e) => std::str::from_utf8(&trimmed[..e.valid_up_to()]).unwrap(),
+    }
+  }
+  pub fn is_blank(&self) -> bool {
+    self.0.iter().all(|c| *c == b' ')
+  }
+}
+
+impl<const N: usize> Default for Alpha<N> {
+  fn default() -> Self {
+    Alpha([b' '; N])
+  }
+}
+
+impl<const N: usize> fmt::Display for Alpha<N> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.pad(self.as_str())
+  }
+}
+
+impl<const N: usize> fmt::Debug for Alpha<N> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:?}", String::from_utf8_lossy(&self.0))
+  }
+}
+
+impl<const N: usize> FromStr for Alpha<N> {
+  type Err = AlphaError;
+  fn from_str(s: &str) -> Result<Self, AlphaError> {
+    Alpha::new(s)
+  }
+}
+
+impl<const N: usize> TryFrom<&str> for Alpha<N> {
+  type Error = AlphaError;
+  fn try_from(s: &str) -> Result<Self, AlphaError> {
+    Alpha::new(s)
+  }
+}
+
+impl<const N: usize> From<[u8; N]> for Alpha<N> {
+  fn from(bytes: [u8; N]) -> Self {
+    Alpha(bytes)
+  }
+}
+
+impl<const N: usize> PartialEq<str> for Alpha<N> {
+  fn eq(&self, other: &str) -> bool {
+    self.as_str() == other
+  }
+}
+
+impl<const N: usize> PartialEq<&str> for Alpha<N> {
+  fn eq(&self, other: &&str) -> bool {
+    self.as_str() == *other
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for Alpha<N> {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(self.as_str())
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for Alpha<N> {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    Alpha::new(&s).map_err(serde::de::Error::custom)
+  }
+}
+
+#[test]
+fn symbol_padding() {
+  let amzn = Symbol::new("AMZN").unwrap();
+  assert_eq!(amzn.as_bytes(), b"AMZN    ");
+  assert_eq!(amzn.as_str(), "AMZN");
+  assert_eq!(amzn, "AMZN");
+  assert_eq!(format!("[{:<6}]", amzn), "[AMZN  ]");
+  assert_eq!(Symbol::from_bytes(*b"BRK A   ").as_str(), "BRK A");
+  assert_eq!(Symbol::new("TOOLONGSYM"), Err(AlphaError::TooLong{max: 8, actual: 10}));
+  assert_eq!(Mpid::new("ÄBC"), Err(AlphaError::NotAscii));
+  assert!(Mpid::default().is_blank());
+  assert!(Symbol::new("AAPL").unwrap() < amzn);
+}
@@ -0,0 +1,254 @@
+use std::collections::VecDeque;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::SystemTime;
+use futures_core::Stream;
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::net::{ToSocketAddrs, UdpSocket};
+use crate::context::MessageContext;
+use crate::error::StreamError;
+use crate::itch::{ItchMessage, MessageBlock, MESSAGE_BLOCK_SIZE, PACKET_HEADER_SIZE};
+use crate::message::LengthCheck;
+use crate::moldudp::{MoldReader, PacketKind};
+use crate::stream::decode_frame;
+
+pub type SequencedMessage = (ItchMessage, MessageContext);
+
+// Decoded messages from MoldUDP64 packets on a UDP socket, each with the
+// session and sequence number from its packet and the time the packet was
+// received. A packet is only read off the socket once every message of the
+// previous one has been taken, so a slow consumer leaves packets queued in
+// the socket's receive buffer. Heartbeats carry no messages and yield
+// nothing; a packet with bad framing yields a StreamError::Mold after any
+// messages before the problem. The stream ends after an end-of-session
+// packet, use MoldReceiver to also hear about heartbeats and a quiet line.
+pub struct MoldUdpStream {
+  socket: UdpSocket,
+  buf: Box<[u8]>,
+  pending: VecDeque<Result<SequencedMessage, StreamError>>,
+  check: LengthCheck,
+  ended: bool,
+}
+
+impl MoldUdpStream {
+  pub fn new(socket: UdpSocket) -> Self {
+    MoldUdpStream{socket, buf: vec![0u8; 64 * 1024].into_boxed_slice(), pending: VecDeque::new(), check: LengthCheck::Lenient, ended: false}
+  }
+  pub async fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+    UdpSocket::bind(addr).await.map(Self::new)
+  }
+  pub fn with_length_check(mut self, check: LengthCheck) -> Self {
+    self.check = check;
+    self
+  }
+  pub fn socket(&self) -> &UdpSocket {
+    &self.socket
+  }
+  pub fn into_inner(self) -> UdpSocket {
+    self.socket
+  }
+
+  fn queue_packet(&mut self, packet_len: usize) {
+    let reader = match MoldReader::new(&self.buf[..packet_len]) {
+      Ok(reader) => reader,
+      Err(err) => {
+        self.pending.push_back(Err(err.into()));
+        return;
+      },
+    };
+    if reader.kind() == PacketKind::EndOfSession {
+      self.ended = true;
+      return;
+    }
+    let received = SystemTime::now();
+    let mut offset = PACKET_HEADER_SIZE;
+    for (index, msg) in reader.iter().enumerate() {
+      let msg = match msg {
+        Ok(msg) => msg,
+        Err(err) => {
+          self.pending.push_back(Err(err.into()));
+          break;
+        },
+      };
+      offset += MESSAGE_BLOCK_SIZE;
+      let ctx = MessageContext::for_packet(&reader, index).with_received(received);
+      let decoded = decode_frame(msg, self.check).map(|msg| (msg, ctx)).map_err(|err| err.at_offset(offset).into());
+      self.pending.push_back(decoded);
+      offset += msg.len();
+    }
+  }
+}
+
+impl Stream for MoldUdpStream {
+  type Item = Result<SequencedMessage, StreamError>;
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = &mut *self;
+    loop {
+      if let Some(next) = this.pending.pop_front() {
+        return Poll::Ready(Some(next));
+      }
+      if this.ended {
+        return Poll::Ready(None);
+      }
+      let mut buf = ReadBuf::new(&mut this.buf);
+      if let Err(err) = ready!(this.socket.poll_recv(cx, &mut buf)) {
+        return Poll::Ready(Some(Err(err.into())));
+      }
+      let len = buf.filled().len();
+      this.queue_packet(len);
+    }
+  }
+}
+
+// Decoded messages from an async reader of MessageBlock framed ITCH, such
+// as a day's capture file. There is no session on a file, so each message's
+// context carries a blank session and its 1-based position in the file as
+// the sequence number. Follows the same error rules as ItchStream.
+pub struct AsyncItchStream<R> {
+  rdr: R,
+  buf: Vec<u8>,
+  filled: usize,
+  in_body: bool,
+  seqno: u64,
+  offset: usize,
+  check: LengthCheck,
+  done: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncItchStream<R> {
+  pub fn new(rdr: R) -> Self {
+    AsyncItchStream{rdr, buf: vec![0u8; MESSAGE_BLOCK_SIZE], filled: 0, in_body: false, seqno: 0, offset: 0, check: LengthCheck::Lenient, done: false}
+  }
+  pub fn with_length_check(mut self, check: LengthCheck) -> Self {
+    self.check = check;
+    self
+  }
+  pub fn into_inner(self) -> R {
+    self.rdr
+  }
+
+  // fills buf, Ok(false) on a clean end of input between messages
+  fn poll_fill(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<bool>> {
+    while self.filled < self.buf.len() {
+      let mut buf = ReadBuf::new(&mut self.buf[self.filled..]);
+      ready!(Pin::new(&mut self.rdr).poll_read(cx, &mut buf))?;
+      let n = buf.filled().len();
+      if n == 0 {
+        if !self.in_body && self.filled == 0 {
+          return Poll::Ready(Ok(false));
+        }
+        return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+      }
+      self.filled += n;
+      self.offset += n;
+    }
+    Poll::Ready(Ok(true))
+  }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncItchStream<R> {
+  type Item = Result<SequencedMessage, StreamError>;
+  fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    let this = &mut *self;
+    loop {
+      if this.done {
+        return Poll::Ready(None);
+      }
+      match ready!(this.poll_fill(cx)) {
+        Ok(true) => {},
+        Ok(false) => {
+          this.done = true;
+          return Poll::Ready(None);
+        },
+        Err(err) => {
+          this.done = true;
+          return Poll::Ready(Some(Err(err.into())));
+        },
+      }
+      this.filled = 0;
+      if !this.in_body {
+        let (block, _) = MessageBlock::from_bytes(&this.buf).expect("buffer holds a full length prefix");
+        this.buf.resize(block.message_length as usize, 0);
+        this.in_body = true;
+        continue;
+      }
+      let base = this.offset - this.buf.len();
+      let decoded = decode_frame(&this.buf, this.check).map_err(|err| err.at_offset(base));
+      this.buf.resize(MESSAGE_BLOCK_SIZE, 0);
+      this.in_body = false;
+      this.seqno += 1;
+      let ctx = MessageContext{seqno: this.seqno, ..Default::default()};
+      return Poll::Ready(Some(decoded.map(|msg| (msg, ctx)).map_err(StreamError::from)));
+    }
+  }
+}
+
+#[cfg(test)]
+async fn next<S: Stream + Unpin>(stream: &mut S) -> Option<S::Item> {
+  std::future::poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+}
+
+#[cfg(test)]
+fn sample_deletes() -> Vec<crate::itch::OrderDelete> {
+  use crate::itch::OrderDelete;
+  (1..=3).map(|i| OrderDelete{message_type: OrderDelete::TYPE, stock_locate: i, order_reference_number: 100 + i as u64, ..Default::default()}).collect()
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn mold_udp_loopback() {
+  use crate::alpha::Session;
+  use crate::error::MoldError;
+  use crate::moldudp::MoldWriter;
+  let mut stream = MoldUdpStream::bind("127.0.0.1:0").await.unwrap();
+  let sender = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+  sender.connect(stream.socket().local_addr().unwrap()).await.unwrap();
+  let deletes = sample_deletes();
+  let mut writer = MoldWriter::new("SESSION042", 7);
+  writer.add_encoded(&deletes[0]).add_encoded(&deletes[1]);
+  sender.send(writer.data()).await.unwrap();
+  let heartbeat = MoldWriter::new("SESSION042", 9);
+  sender.send(heartbeat.data()).await.unwrap();
+  let mut writer = MoldWriter::new("SESSION042", 9);
+  writer.add_encoded(&deletes[2]);
+  sender.send(writer.data()).await.unwrap();
+  sender.send(b"short").await.unwrap();
+  sender.send(MoldWriter::end_of_session(Session::new("SESSION042").unwrap(), 10).data()).await.unwrap();
+
+  for (i, delete) in deletes.iter().enumerate() {
+    let (msg, ctx) = next(&mut stream).await.unwrap().unwrap();
+    assert_eq!(msg, ItchMessage::OrderDelete(*delete));
+    assert_eq!(ctx.session, "SESSION042");
+    assert_eq!(ctx.seqno, 7 + i as u64);
+    assert!(ctx.received.is_some());
+  }
+  assert!(matches!(next(&mut stream).await, Some(Err(StreamError::Mold(MoldError::ShortHeader { actual: 5 })))));
+  assert!(next(&mut stream).await.is_none());
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn async_read_length_prefixed() {
+  use crate::encode::Encode;
+  let mut file = Vec::new();
+  for delete in sample_deletes() {
+    file.extend_from_slice(&(crate::itch::ORDER_DELETE_SIZE as u16).to_be_bytes());
+    delete.encode(&mut file).unwrap();
+  }
+  let mut stream = AsyncItchStream::new(&file[..]);
+  let mut seen = Vec::new();
+  while let Some(next) = next(&mut stream).await {
+    let (msg, ctx) = next.unwrap();
+    seen.push((msg.stock_locate(), ctx.seqno));
+  }
+  assert_eq!(seen, vec![(Some(1), 1), (Some(2), 2), (Some(3), 3)]);
+
+  let mut cut = AsyncItchStream::new(&file[..file.len() - 4]);
+  let mut results = Vec::new();
+  while let Some(next) = next(&mut cut).await {
+    results.push(next);
+  }
+  assert_eq!(results.len(), 3);
+  assert!(matches!(results[2], Err(StreamError::Io(ref err)) if err.kind() == io::ErrorKind::UnexpectedEof));
+}
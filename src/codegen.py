@@ -1,3 +1,4 @@
+import re
 import sys
 import xml.etree.ElementTree as ET
 
@@ -10,17 +11,63 @@ type_map = {'char_t': 'u8'
         , 'char_20_t': '[u8;20]'
         , 'u64_t': 'u64'
         #, 'u48_t': '[u8;6]'
-        , 'u48_t': 'u64'
+        , 'u48_t': 'ItchTimestamp'
         , 'u32_t': 'u32'
         , 'u16_t': 'u16'
-        , 'price_4_t': 'u32'
+        , 'price_4_t': 'Price4'
+        , 'price_8_t': 'Price8'
+        }
+
+# wire representation of the fixed point price types
+price_raw = {'price_4_t': 'u32'
         , 'price_8_t': 'u64'
         }
 
+# fixed width ascii fields that get their own type instead of a byte array
+alpha_fields = {'stock': 'Symbol'
+        , 'mpid': 'Mpid'
+        , 'attribution': 'Mpid'
+        , 'issue_sub_type': 'IssueSubType'
+        , 'session': 'Session'
+        }
+
+def rust_type(field):
+    return alpha_fields.get(field.get('name'), type_map[field.get('type')])
+
+# widest display form of each schema type; enums are as wide as their
+# longest name and fixed width text as wide as the field
+# an unprintable char or unknown enum code shows as 0x.. so needs 4
+display_width = {'char_t': 4
+        , 'u16_t': 5
+        , 'u32_t': 10
+        , 'u64_t': 20
+        , 'u48_t': 18
+        , 'price_4_t': 11
+        , 'price_8_t': 21
+        }
+
+def field_width(field):
+    tipe = field.get('type')
+    if tipe in display_width:
+        return display_width[tipe]
+    if tipe.startswith('char_'):
+        return int(field.get('len'))
+    return max([4] + [len(name) for name in enum_values[tipe]])
+
+# variant names of each generated enum, by enum name
+enum_values = {}
+
 def do_header(xml):
     print('use std::fmt;')
     print('use std::io::{Cursor, Read, Write};')
     print('use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};')
+    print('use crate::context::MessageContext;')
+    print('use crate::encode::Encode;')
+    print('use crate::error::{DecodeError, UnknownCode};')
+    print('use crate::message::{FieldValue, FieldVisitor, ItchMessageType, LengthCheck};')
+    print('use crate::alpha::{IssueSubType, Mpid, Session, Symbol};')
+    print('use crate::price::{Price4, Price8};')
+    print('use crate::timestamp::ItchTimestamp;')
     print('')
     print('pub fn u48_to_u64(num: &[u8;6]) -> u64 {')
     print('  ((num[0] as u64) << 40) +')
@@ -28,36 +75,125 @@ def do_header(xml):
     print('  ((num[2] as u64) << 24) +')
     print('  ((num[3] as u64) << 16) +')
     print('  ((num[4] as u64) << 8) +')
-    print('  ((num[5] as u64) << 0)')
+    print('  (num[5] as u64)')
     print('}')
     print('')
 
 def do_constants(xml):
-    pass
+    # Price4Scale -> PRICE4_SCALE
+    const_name = lambda x: re.sub(r'([a-z0-9])([A-Z])', r'\1_\2', x).upper()
+    print('// Constants')
+    for item in xml.find('Constants'):
+        print('pub const {} : {} = {};'.format(const_name(item.get('name')), type_map[item.get('type')], item.get('value')))
+    print('')
 
 def do_enums(xml):
     print('// Enums')
     for item in xml.find('Enums'):
-        print('#[derive(PartialEq, Default)]')
+        name = item.get('name')
+        print('#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]')
         print('#[allow(non_camel_case_types)]')
-        print('pub struct {}(pub {});'.format(item.get('name'), type_map[item.get('type')]))
-        print('#[allow(non_upper_case_globals)]')
-        print('impl {} {{'.format(item.get('name')))
+        print('#[repr({})]'.format(type_map[item.get('type')]))
+        print('pub enum {} {{'.format(name))
         for value in item:
-            print('  pub const {} : {} = \'{}\' as {};'.format(value.get('name'), type_map[item.get('type')], value.get('value'), type_map[item.get('type')]))
-        # print('  fn new(code : u8) -> Self {{ {}(code) }}'.format(item.get('name')))
-        print('}} // {}'.format(item.get('name')))
+            print('  {} = b\'{}\','.format(value.get('name'), value.get('value')))
+        print('  Unknown({}) = 0,'.format(type_map[item.get('type')]))
+        print('}} // {}'.format(name))
         print('')
-        type_map[item.get('name')] = item.get('name')
+        type_map[name] = name
+        enum_values[name] = [value.get('name') for value in item]
 
         stringize = lambda x: x.replace('_', ' ')
 
-        print('impl fmt::Display for {} {{'.format(item.get('name')))
+        print('impl {} {{'.format(name))
+        print('  pub fn from_code(code: u8) -> Self {')
+        print('    match code {')
+        for value in item:
+            print('      b\'{}\' => {}::{},'.format(value.get('value'), name, value.get('name')))
+        print('      _ => {}::Unknown(code),'.format(name))
+        print('    }')
+        print('  }')
+        print('  pub fn code(&self) -> u8 {')
+        print('    match *self {')
+        for value in item:
+            print('      {}::{} => b\'{}\','.format(name, value.get('name'), value.get('value')))
+        print('      {}::Unknown(code) => code,'.format(name))
+        print('    }')
+        print('  }')
+        print('  pub fn is_known(&self) -> bool {')
+        print('    !matches!(*self, {}::Unknown(_))'.format(name))
+        print('  }')
+        print('  pub fn name(&self) -> &\'static str {')
+        print('    match *self {')
+        for value in item:
+            print('      {}::{} => "{}",'.format(name, value.get('name'), value.get('name')))
+        print('      {}::Unknown(_) => "Unknown",'.format(name))
+        print('    }')
+        print('  }')
+        print('  pub fn from_name(name: &str) -> Option<Self> {')
+        print('    match name {')
+        for value in item:
+            print('      "{}" => Some({}::{}),'.format(value.get('name'), name, value.get('name')))
+        print('      _ => None,')
+        print('    }')
+        print('  }')
+        print('}} // {}'.format(name))
+        print('')
+
+        # the zero byte, same as the old newtype default
+        print('impl Default for {} {{'.format(name))
+        print('  fn default() -> Self {{ {}::Unknown(0) }}'.format(name))
+        print('}')
+        print('')
+
+        print('impl TryFrom<u8> for {} {{'.format(name))
+        print('  type Error = UnknownCode;')
+        print('  fn try_from(code: u8) -> Result<Self, UnknownCode> {')
+        print('    match Self::from_code(code) {')
+        print('      {}::Unknown(code) => Err(UnknownCode {{ enum_name: "{}", code }}),'.format(name, name))
+        print('      known => Ok(known),')
+        print('    }')
+        print('  }')
+        print('}')
+        print('')
+
+        print('impl From<{}> for u8 {{'.format(name))
+        print('  fn from(value: {}) -> u8 {{ value.code() }}'.format(name))
+        print('}')
+        print('')
+
+        # by name, with codes outside the table kept as the bare code character
+        print('#[cfg(feature = "serde")]')
+        print('impl serde::Serialize for {} {{'.format(name))
+        print('  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {')
+        print('    match *self {')
+        print('      {}::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),'.format(name))
+        print('      known => serializer.serialize_str(known.name()),')
+        print('    }')
+        print('  }')
+        print('}')
+        print('')
+        print('#[cfg(feature = "serde")]')
+        print('impl<\'de> serde::Deserialize<\'de> for {} {{'.format(name))
+        print('  fn deserialize<D: serde::Deserializer<\'de>>(deserializer: D) -> Result<Self, D::Error> {')
+        print('    let s = <String as serde::Deserialize>::deserialize(deserializer)?;')
+        print('    if let Some(value) = Self::from_name(&s) {')
+        print('      return Ok(value);')
+        print('    }')
+        print('    match s.as_bytes() {')
+        print('      [code] => Ok(Self::from_code(*code)),')
+        print('      _ => Err(serde::de::Error::custom(format!("unknown {} \'{{}}\'", s))),'.format(name))
+        print('    }')
+        print('  }')
+        print('}')
+        print('')
+
+        print('impl fmt::Display for {} {{'.format(name))
         print('  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {')
-        print('    let s = match self.0 {')
+        print('    let s = match *self {')
         for value in item:
-            print('      {}::{} => "\'{}\' ({})".to_string(),'.format(item.get('name'), value.get('name'), value.get('value'), stringize(value.get('name'))))
-        print('      _ => "Unknown".to_string(),')
+            print('      {}::{} => "\'{}\' ({})",'.format(name, value.get('name'), value.get('value'), stringize(value.get('name'))))
+        print('      {}::Unknown(_) => "Unknown",'.format(name))
         print('    };')
         print('    write!(f, "{}", s)')
         print('  }')
@@ -72,10 +208,11 @@ def do_structs(xml):
     for item in xml.find('Structs'):
         len = item.get('len')
         # print('#[repr(C, packed)]')
-        print('#[derive(Default)]')
+        print('#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]')
+        print('#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]')
         print('pub struct {} {{'.format(struct_name(item.get('name'))))
         for field in item:
-            print('  pub {}: {},'.format(field.get('name'), type_map[field.get('type')]))
+            print('  pub {}: {},'.format(field.get('name'), rust_type(field)))
         print('}} // {}'.format(struct_name(item.get('name'))))
         print('pub const {}_SIZE : usize = {};'.format(item.get('name').upper(), item.get('len')))
         # size check
@@ -85,26 +222,35 @@ def do_structs(xml):
 
         print('impl {} {{'.format(struct_name(item.get('name'))))
         if item.get('id') is not None:
-            print('  pub const TYPE : u8 = \'{}\' as u8;'.format(item.get('id')))
+            print('  pub const TYPE : u8 = b\'{}\';'.format(item.get('id')))
 
         def do_struct_parser(error_str):
             print('    let mut obj = Self::default();')
             for field in item:
                 if field.get('type') == 'char_t':
                     print('    rdr.read_exact(std::slice::from_mut(&mut obj.{})){};'.format(field.get('name'), error_str));
+                elif field.get('name') in alpha_fields:
+                    print('    rdr.read_exact(&mut obj.{}.0[..{}]){};'.format(field.get('name'), field.get('type').split('_')[1], error_str));
                 elif field.get('type').startswith('char_'):
                     print('    rdr.read_exact(&mut obj.{}[..{}]){};'.format(field.get('name'), field.get('type').split('_')[1], error_str));
                 elif field.get('type') == 'u48_t':
                     print('    let mut the_u48 = [0u8; 6];')
                     print('    rdr.read_exact(&mut the_u48[..6]){};'.format(error_str))
-                    print('    obj.{} = u48_to_u64(&the_u48);'.format(field.get('name')));
+                    print('    obj.{} = ItchTimestamp(u48_to_u64(&the_u48));'.format(field.get('name')));
+                elif field.get('type') in price_raw:
+                    print('    obj.{} = {}(rdr.read_{}::<BigEndian>(){});'.format(field.get('name'), type_map[field.get('type')], price_raw[field.get('type')], error_str))
                 elif type_map[field.get('type')] in ['u16', 'u32', 'u64']:
                     print('    obj.{} = rdr.read_{}::<BigEndian>(){};'.format(field.get('name'), type_map[field.get('type')], error_str))
                 elif field.get('type')[0] == 'e':
-                    print('    rdr.read_exact(std::slice::from_mut(&mut obj.{}.0)){};'.format(field.get('name'), error_str))
+                    print('    obj.{} = {}::from_code(rdr.read_u8(){});'.format(field.get('name'), type_map[field.get('type')], error_str))
                 else:
                     print('    // TODO: parse {} of type {}'.format(field.get('name'), field.get('type')))
 
+        def allow_reassign():
+            if sum(1 for _ in item) == 1:
+                print('  #[allow(clippy::field_reassign_with_default)]')
+
+        allow_reassign()
         print('  pub fn from_bytes(bytes: &[u8]) -> Option<({},usize)> {{'.format(struct_name(item.get('name'))))
         print('    if bytes.len() < {}_SIZE {{'.format(item.get('name').upper()))
         print('      return None;')
@@ -114,14 +260,29 @@ def do_structs(xml):
         print('    Some((obj, {}_SIZE))'.format(item.get('name').upper()))
         print('  }')
 
+        allow_reassign()
         print('  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<{}> {{'.format(struct_name(item.get('name'))))
         do_struct_parser('?')
         print('    Ok(obj)')
         print('  }')
+
+        print('  pub fn decode(bytes: &[u8]) -> Result<{}, DecodeError> {{'.format(struct_name(item.get('name'))))
+        print('    if bytes.is_empty() {')
+        print('      return Err(DecodeError::Empty);')
+        print('    }')
+        if item.get('id') is not None:
+            print('    if bytes[0] != Self::TYPE {')
+            print('      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });')
+            print('    }')
+        print('    match Self::from_bytes(bytes) {')
+        print('      Some((obj, _)) => Ok(obj),')
+        print('      None => Err(DecodeError::Truncated {{ type_byte: bytes[0], expected: {}_SIZE, actual: bytes.len(), offset: 0 }}),'.format(item.get('name').upper()))
+        print('    }')
+        print('  }')
         print('}')
 
         def maybe_transform(field):
-            if field.get('type').startswith('char') and field.get('type') != 'char_t':
+            if field.get('type').startswith('char') and field.get('type') != 'char_t' and field.get('name') not in alpha_fields:
                 return 'String::from_utf8_lossy(&self.{}[..])'.format(field.get('name'))
             # elif field.get('type') == 'u48_t':
                 # return 'u64::from_be(self.' + field.get('name') + ')'
@@ -144,102 +305,397 @@ def do_structs(xml):
         print('}')
 
 
-def do_functions(xml):
+def do_views(xml):
     struct_name = lambda x: ''.join(map(lambda n: n[0].upper() + n[1:], x.split('_')))
-    print('pub trait ItchHandler {')
+    print('// Views')
+    for item in xml.find('Structs'):
+        name = struct_name(item.get('name'))
+        size = '{}_SIZE'.format(item.get('name').upper())
+        print('#[derive(Clone, Copy)]')
+        print('pub struct {}View<\'a> {{'.format(name))
+        print('  data: &\'a [u8],')
+        print('}')
+        print('')
+        print('impl<\'a> {}View<\'a> {{'.format(name))
+        print('  pub fn new(data: &\'a [u8]) -> Result<{}View<\'a>, DecodeError> {{'.format(name))
+        print('    if data.is_empty() {')
+        print('      return Err(DecodeError::Empty);')
+        print('    }')
+        if item.get('id') is not None:
+            print('    if data[0] != {}::TYPE {{'.format(name))
+            print('      return Err(DecodeError::WrongType {{ type_byte: data[0], expected: {}::TYPE, offset: 0 }});'.format(name))
+            print('    }')
+        print('    if data.len() < {} {{'.format(size))
+        print('      return Err(DecodeError::Truncated {{ type_byte: data[0], expected: {}, actual: data.len(), offset: 0 }});'.format(size))
+        print('    }')
+        print('    Ok(Self{data})')
+        print('  }')
+        print('  pub fn as_bytes(&self) -> &\'a [u8] {')
+        print('    &self.data[..{}]'.format(size))
+        print('  }')
+        print('  // bytes past the schema size, e.g. fields from a newer spec revision')
+        print('  pub fn trailing(&self) -> &\'a [u8] {')
+        print('    &self.data[{}..]'.format(size))
+        print('  }')
+        for field in item:
+            begin = int(field.get('offset'))
+            end = begin + int(field.get('len'))
+            if field.get('type') == 'char_t':
+                print('  pub fn {}(&self) -> u8 {{'.format(field.get('name')))
+                print('    self.data[{}]'.format(begin))
+            elif field.get('name') in alpha_fields:
+                print('  pub fn {}(&self) -> {} {{'.format(field.get('name'), rust_type(field)))
+                print('    {}::from_bytes(self.data[{}..{}].try_into().unwrap())'.format(rust_type(field), begin, end))
+            elif field.get('type').startswith('char_'):
+                print('  pub fn {}(&self) -> &\'a {} {{'.format(field.get('name'), type_map[field.get('type')]))
+                print('    self.data[{}..{}].try_into().unwrap()'.format(begin, end))
+            elif field.get('type') == 'u48_t':
+                print('  pub fn {}(&self) -> ItchTimestamp {{'.format(field.get('name')))
+                print('    ItchTimestamp(u48_to_u64(self.data[{}..{}].try_into().unwrap()))'.format(begin, end))
+            elif field.get('type') in price_raw:
+                print('  pub fn {}(&self) -> {} {{'.format(field.get('name'), type_map[field.get('type')]))
+                print('    {}({}::from_be_bytes(self.data[{}..{}].try_into().unwrap()))'.format(type_map[field.get('type')], price_raw[field.get('type')], begin, end))
+            elif type_map[field.get('type')] in ['u16', 'u32', 'u64']:
+                print('  pub fn {}(&self) -> {} {{'.format(field.get('name'), type_map[field.get('type')]))
+                print('    {}::from_be_bytes(self.data[{}..{}].try_into().unwrap())'.format(type_map[field.get('type')], begin, end))
+            elif field.get('type')[0] == 'e':
+                print('  pub fn {}(&self) -> {} {{'.format(field.get('name'), type_map[field.get('type')]))
+                print('    {}::from_code(self.data[{}])'.format(type_map[field.get('type')], begin))
+            else:
+                print('  // TODO: view {} of type {}'.format(field.get('name'), field.get('type')))
+                continue
+            print('  }')
+        print('  pub fn to_message(&self) -> {} {{'.format(name))
+        print('    {} {{'.format(name))
+        for field in item:
+            if field.get('type').startswith('char_') and field.get('type') != 'char_t' and field.get('name') not in alpha_fields:
+                print('      {0}: *self.{0}(),'.format(field.get('name')))
+            else:
+                print('      {0}: self.{0}(),'.format(field.get('name')))
+        print('    }')
+        print('  }')
+        print('}} // {}View'.format(name))
+        print('')
+
+    print('pub trait ItchViewHandler {')
     for item in xml.find('Structs'):
         if item.get('id') is None:
             continue
-        print('  fn on_{}(&mut self, _msg: {}) {{}}'.format(item.get('name'), struct_name(item.get('name'))))
+        print('  fn on_{}(&mut self, _msg: {}View<\'_>) {{}}'.format(item.get('name'), struct_name(item.get('name'))))
     print('}')
     print('')
 
-    print('pub fn crack_message<T: ItchHandler>(msg: &[u8], handler: &mut T) {')
-    print('  let tipe = msg[0];')
-    print('  let mut rdr = Cursor::new(msg);')
+    print('pub fn crack_message_view<T: ItchViewHandler>(msg: &[u8], handler: &mut T) -> Result<(), DecodeError> {')
+    print('  let tipe = match msg.first() {')
+    print('    Some(tipe) => *tipe,')
+    print('    None => return Err(DecodeError::Empty),')
+    print('  };')
     print('  match tipe {')
     for item in xml.find('Structs'):
         if item.get('id') is None:
             continue
-        print('    {}::TYPE => {{'.format(struct_name(item.get('name'))))
-        print('      //let msg = unsafe {{ std::ptr::read(msg[..].as_ptr() as *const {}) }};'.format(struct_name(item.get('name'))))
-        print('      if let Ok(msg) = {}::from_cursor(&mut rdr) {{'.format(struct_name(item.get('name'))))
-        print('        handler.on_{}(msg);'.format(item.get('name')))
-        print('      }')
-        print('      else { unreachable!() }') # FIXME
-        print('    },')
-    print('    _ => { panic!("unknown type!") },')
+        print('    {}::TYPE => handler.on_{}({}View::new(msg)?),'.format(struct_name(item.get('name')), item.get('name'), struct_name(item.get('name'))))
+    print('    _ => return Err(DecodeError::UnknownType { type_byte: tipe, offset: 0 }),')
     print('  }')
+    print('  Ok(())')
     print('}')
+    print('')
+
 
+def do_functions(xml):
+    struct_name = lambda x: ''.join(map(lambda n: n[0].upper() + n[1:], x.split('_')))
+    has_field = lambda item, name: any(f.get('name') == name for f in item)
     for item in xml.find('Structs'):
         if item.get('id') is None:
             continue
-        print('pub fn write_{}(wrt: &mut Cursor<&mut [u8]>, '.format(item.get('name')), end='')
-        print(', '.join(map(lambda f: '{}: {}'.format(f.get('name'), type_map[f.get('type')]), filter(lambda f: f.get('name') != 'message_type', item))), end='')
-        print(') -> std::io::Result<()> {')
-        print('  let start_pos = wrt.position();');
+        print('impl ItchMessageType for {} {{'.format(struct_name(item.get('name'))))
+        print('  const TYPE : u8 = b\'{}\';'.format(item.get('id')))
+        print('  const SIZE : usize = {}_SIZE;'.format(item.get('name').upper()))
+        print('  const NAME : &\'static str = "{}";'.format(struct_name(item.get('name'))))
+        print('  const FIELDS : &\'static [&\'static str] = &[{}];'.format(', '.join('"{}"'.format(f.get('name')) for f in item)))
+        print('  const FIELD_WIDTHS : &\'static [usize] = &[{}];'.format(', '.join(str(field_width(f)) for f in item)))
+        print('  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {')
+        print('    {}::decode(bytes)'.format(struct_name(item.get('name'))))
+        print('  }')
+        print('  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {')
         for field in item:
-            if field.get('name') == 'message_type':
-                print('  let tipe = {}::TYPE;'.format(struct_name(item.get('name'))))
-                print('  wrt.write_all(std::slice::from_ref(&tipe))?;')
-            elif field.get('type') == 'u48_t':
-                print('  wrt.write_all(&u64::to_be_bytes({})[2..])?;'.format(field.get('name')))
-            elif type_map[field.get('type')] in ['u16','u32','u64']:
-                print('  wrt.write_{}::<BigEndian>({})?;'.format(type_map[field.get('type')], field.get('name')))
-            elif field.get('type') == 'char_t':
-                print('  wrt.write_all(std::slice::from_ref(&{}))?;'.format(field.get('name')))
-            elif field.get('type').startswith('char_'):
-                print('  wrt.write_all(&{}[..{}])?;'.format(field.get('name'), field.get('type').split('_')[1]))
-            elif field.get('type')[0] == 'e':
-                print('  wrt.write_all(std::slice::from_ref(&{}.0))?;'.format(field.get('name')))
+            name, tipe = field.get('name'), field.get('type')
+            if name in alpha_fields:
+                value = 'FieldValue::Text(self.{}.as_str())'.format(name)
+            elif tipe == 'char_t':
+                value = 'FieldValue::Char(self.{})'.format(name)
+            elif tipe.startswith('char_'):
+                value = 'FieldValue::Bytes(&self.{})'.format(name)
+            elif tipe == 'u48_t':
+                value = 'FieldValue::Timestamp(self.{})'.format(name)
+            elif tipe in price_raw:
+                value = 'FieldValue::{}(self.{})'.format(type_map[tipe], name)
+            elif tipe[0] == 'e':
+                value = 'FieldValue::Code {{ code: self.{0}.code(), name: self.{0}.name() }}'.format(name)
             else:
-                print('  // TODO write({}) type {}'.format(field.get('name'), field.get('type')))
-
-        print('  assert_eq!(wrt.position() - start_pos, {}_SIZE as u64);'.format(item.get('name').upper()));
-        print('  Ok(())')
+                value = 'FieldValue::{}(self.{})'.format(type_map[tipe].upper(), name)
+            print('    visitor.field("{}", {});'.format(name, value))
+        print('  }')
+        for accessor, tipe in [('stock_locate', 'u16'), ('tracking_number', 'u16'), ('timestamp', 'ItchTimestamp'), ('stock', 'Symbol')]:
+            if has_field(item, accessor):
+                print('  fn {}(&self) -> Option<{}> {{'.format(accessor, tipe))
+                print('    Some(self.{})'.format(accessor))
+                print('  }')
+        for field_name in ['order_reference_number', 'new_order_reference_number']:
+            if has_field(item, field_name):
+                print('  fn order_reference_number(&self) -> Option<u64> {')
+                print('    Some(self.{})'.format(field_name))
+                print('  }')
         print('}')
         print('')
-        print('pub fn write_{}_struct(wrt: &mut Cursor<&mut [u8]>, msg: {}) -> std::io::Result<()> {{'.format(item.get('name'), struct_name(item.get('name'))))
-        print('  let start_pos = wrt.position();');
+
+    print('pub trait ItchHandler {')
+    for item in xml.find('Structs'):
+        if item.get('id') is None:
+            continue
+        print('  fn on_{}(&mut self, _msg: &{}, _ctx: &MessageContext) {{}}'.format(item.get('name'), struct_name(item.get('name'))))
+    print('  // a type byte the schema doesn\'t know, e.g. a message added after this was generated')
+    print('  fn on_unknown(&mut self, _type_byte: u8, _raw: &[u8], _ctx: &MessageContext) {}')
+    print('  // a known message type that failed to decode, usually truncated')
+    print('  fn on_decode_error(&mut self, _err: DecodeError, _raw: &[u8], _ctx: &MessageContext) {}')
+    print('  // bytes past the schema size of a message that was just dispatched, see LengthCheck')
+    print('  fn on_trailing_bytes(&mut self, _type_byte: u8, _trailing: &[u8], _ctx: &MessageContext) {}')
+    print('}')
+    print('')
+
+    print('// Unknown message types go to on_unknown and are not an error, decode')
+    print('// failures go to on_decode_error and are also returned.')
+    print('pub fn crack_message<T: ItchHandler>(msg: &[u8], handler: &mut T) -> Result<(), DecodeError> {')
+    print('  crack_message_with_context(msg, &MessageContext::default(), handler)')
+    print('}')
+    print('')
+
+    print('pub fn crack_message_with_context<T: ItchHandler>(msg: &[u8], ctx: &MessageContext, handler: &mut T) -> Result<(), DecodeError> {')
+    print('  crack_message_checked(msg, ctx, LengthCheck::Lenient, handler)')
+    print('}')
+    print('')
+
+    print('pub fn crack_message_checked<T: ItchHandler>(msg: &[u8], ctx: &MessageContext, check: LengthCheck, handler: &mut T) -> Result<(), DecodeError> {')
+    print('  let tipe = match msg.first() {')
+    print('    Some(tipe) => *tipe,')
+    print('    None => {')
+    print('      handler.on_decode_error(DecodeError::Empty, msg, ctx);')
+    print('      return Err(DecodeError::Empty);')
+    print('    },')
+    print('  };')
+    print('  let size = ItchMessage::size_of_type(tipe);')
+    print('  if let Some(size) = size {')
+    print('    if let Err(err) = check.check(tipe, size, msg.len()) {')
+    print('      handler.on_decode_error(err.clone(), msg, ctx);')
+    print('      return Err(err);')
+    print('    }')
+    print('  }')
+    print('  let result = match tipe {')
+    for item in xml.find('Structs'):
+        if item.get('id') is None:
+            continue
+        print('    {0}::TYPE => {0}::decode(msg).map(|decoded| handler.on_{1}(&decoded, ctx)),'.format(struct_name(item.get('name')), item.get('name')))
+    print('    _ => {')
+    print('      handler.on_unknown(tipe, msg, ctx);')
+    print('      Ok(())')
+    print('    },')
+    print('  };')
+    print('  match (&result, size) {')
+    print('    (Err(err), _) => handler.on_decode_error(err.clone(), msg, ctx),')
+    print('    (Ok(()), Some(size)) if msg.len() > size => handler.on_trailing_bytes(tipe, &msg[size..], ctx),')
+    print('    _ => {},')
+    print('  }')
+    print('  result')
+    print('}')
+
+    for item in xml.find('Structs'):
+        print('impl Encode for {} {{'.format(struct_name(item.get('name'))))
+        print('  const ENCODED_LEN : usize = {}_SIZE;'.format(item.get('name').upper()))
+        print('  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {')
         for field in item:
-            if field.get('name') == 'message_type':
-                print('  let tipe = {}::TYPE;'.format(struct_name(item.get('name'))))
-                print('  wrt.write_all(std::slice::from_ref(&tipe))?;')
+            if field.get('name') == 'message_type' and item.get('id') is not None:
+                print('    wrt.write_u8({}::TYPE)?;'.format(struct_name(item.get('name'))))
             elif field.get('type') == 'u48_t':
-                print('  wrt.write_all(&u64::to_be_bytes(msg.{})[2..])?;'.format(field.get('name')))
+                print('    wrt.write_all(&u64::to_be_bytes(self.{}.0)[2..])?;'.format(field.get('name')))
+            elif field.get('type') in price_raw:
+                print('    wrt.write_{}::<BigEndian>(self.{}.0)?;'.format(price_raw[field.get('type')], field.get('name')))
             elif type_map[field.get('type')] in ['u16','u32','u64']:
-                print('  wrt.write_{}::<BigEndian>(msg.{})?;'.format(type_map[field.get('type')], field.get('name')))
+                print('    wrt.write_{}::<BigEndian>(self.{})?;'.format(type_map[field.get('type')], field.get('name')))
             elif field.get('type') == 'char_t':
-                print('  wrt.write_all(std::slice::from_ref(&msg.{}))?;'.format(field.get('name')))
+                print('    wrt.write_u8(self.{})?;'.format(field.get('name')))
+            elif field.get('name') in alpha_fields:
+                print('    wrt.write_all(&self.{}.0[..{}])?;'.format(field.get('name'), field.get('type').split('_')[1]))
             elif field.get('type').startswith('char_'):
-                print('  wrt.write_all(&msg.{}[..{}])?;'.format(field.get('name'), field.get('type').split('_')[1]))
+                print('    wrt.write_all(&self.{}[..{}])?;'.format(field.get('name'), field.get('type').split('_')[1]))
             elif field.get('type')[0] == 'e':
-                print('  wrt.write_all(std::slice::from_ref(&msg.{}.0))?;'.format(field.get('name')))
+                print('    wrt.write_u8(self.{}.code())?;'.format(field.get('name')))
             else:
-                print('  // TODO write({}) type {}'.format(field.get('name'), field.get('type')))
-        print('  assert_eq!(wrt.position() - start_pos, {}_SIZE as u64);'.format(item.get('name').upper()));
-        print('  Ok(())')
+                print('    // TODO write({}) type {}'.format(field.get('name'), field.get('type')))
+        print('    Ok(())')
+        print('  }')
         print('}')
+        print('')
 
-def do_utils(xml):
-    struct_name = lambda x: ''.join(map(lambda n: n[0].upper() + n[1:], x.split('_')))
-    print('')
-    print('pub struct Dumper {}')
-    print('impl ItchHandler for Dumper {')
     for item in xml.find('Structs'):
         if item.get('id') is None:
             continue
-        print('  fn on_{}(&mut self, msg: {}) {{ println!("{{}}", msg); }}'.format(item.get('name'), struct_name(item.get('name'))))
+        print('#[allow(clippy::too_many_arguments)]')
+        if any(f.get('name') in enum_values.get(f.get('type'), []) for f in item):
+            print('#[allow(bindings_with_variant_name)]')
+        print('pub fn write_{}<W: Write>(wrt: &mut W, '.format(item.get('name')), end='')
+        print(', '.join(map(lambda f: '{}: {}'.format(f.get('name'), rust_type(f)), filter(lambda f: f.get('name') != 'message_type', item))), end='')
+        print(') -> std::io::Result<()> {')
+        print('  {} {{'.format(struct_name(item.get('name'))))
+        for field in item:
+            if field.get('name') == 'message_type':
+                print('    message_type: {}::TYPE,'.format(struct_name(item.get('name'))))
+            else:
+                print('    {},'.format(field.get('name')))
+        print('  }.encode(wrt)')
+        print('}')
+        print('')
+        print('pub fn write_{}_struct<W: Write>(wrt: &mut W, msg: {}) -> std::io::Result<()> {{'.format(item.get('name'), struct_name(item.get('name'))))
+        print('  msg.encode(wrt)')
+        print('}')
+        print('')
+
+def do_message_enum(xml):
+    struct_name = lambda x: ''.join(map(lambda n: n[0].upper() + n[1:], x.split('_')))
+    messages = [item for item in xml.find('Structs') if item.get('id') is not None]
+    has_field = lambda item, name: any(f.get('name') == name for f in item)
+
+    print('#[derive(Clone, Copy, Debug, PartialEq, Eq)]')
+    print('#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]')
+    print('pub enum ItchMessage {')
+    for item in messages:
+        print('  {0}({0}),'.format(struct_name(item.get('name'))))
+    print('}')
+    print('')
+
+    print('impl ItchMessage {')
+    print('  pub fn decode(bytes: &[u8]) -> Result<(ItchMessage, usize), DecodeError> {')
+    print('    let tipe = match bytes.first() {')
+    print('      Some(tipe) => *tipe,')
+    print('      None => return Err(DecodeError::Empty),')
+    print('    };')
+    print('    match tipe {')
+    for item in messages:
+        print('      {0}::TYPE => Ok((ItchMessage::{0}({0}::decode(bytes)?), {1}_SIZE)),'.format(struct_name(item.get('name')), item.get('name').upper()))
+    print('      _ => Err(DecodeError::UnknownType { type_byte: tipe, offset: 0 }),')
+    print('    }')
+    print('  }')
+    print('  // wire size of a message from its type byte, None if the type is unknown')
+    print('  pub fn size_of_type(tipe: u8) -> Option<usize> {')
+    print('    match tipe {')
+    for item in messages:
+        print('      {}::TYPE => Some({}_SIZE),'.format(struct_name(item.get('name')), item.get('name').upper()))
+    print('      _ => None,')
+    print('    }')
+    print('  }')
+    print('  pub fn name_of_type(tipe: u8) -> Option<&\'static str> {')
+    print('    match tipe {')
+    for item in messages:
+        print('      {0}::TYPE => Some({0}::NAME),'.format(struct_name(item.get('name'))))
+    print('      _ => None,')
+    print('    }')
+    print('  }')
+    print('  pub fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(ref msg) => msg.encode(wrt),'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('  pub fn encode_to_slice(&self, buf: &mut [u8]) -> std::io::Result<usize> {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(ref msg) => msg.encode_to_slice(buf),'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('  pub fn to_bytes(&self) -> Vec<u8> {')
+    print('    let mut bytes = Vec::with_capacity(self.encoded_len());')
+    print('    self.encode(&mut bytes).unwrap();')
+    print('    bytes')
+    print('  }')
+    print('  pub fn encoded_len(&self) -> usize {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(_) => {}_SIZE,'.format(struct_name(item.get('name')), item.get('name').upper()))
+    print('    }')
+    print('  }')
+    print('  pub fn name(&self) -> &\'static str {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{0}(_) => {0}::NAME,'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('  pub fn field_names(&self) -> &\'static [&\'static str] {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{0}(_) => {0}::FIELDS,'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('  pub fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(ref msg) => msg.visit_fields(visitor),'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('  pub fn message_type(&self) -> u8 {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{0}(_) => {0}::TYPE,'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    for accessor, tipe in [('stock_locate', 'u16'), ('tracking_number', 'u16'), ('timestamp', 'ItchTimestamp')]:
+        print('  pub fn {}(&self) -> Option<{}> {{'.format(accessor, tipe))
+        print('    match *self {')
+        for item in messages:
+            if has_field(item, accessor):
+                print('      ItchMessage::{}(ref msg) => Some(msg.{}),'.format(struct_name(item.get('name')), accessor))
+            else:
+                print('      ItchMessage::{}(_) => None,'.format(struct_name(item.get('name'))))
+        print('    }')
+        print('  }')
+    print('  pub fn dispatch<T: ItchHandler>(&self, ctx: &MessageContext, handler: &mut T) {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(ref msg) => handler.on_{}(msg, ctx),'.format(struct_name(item.get('name')), item.get('name')))
+    print('    }')
+    print('  }')
     print('}')
+    print('')
+
+    # invokes the given macro with the (callback, message type) pair of every
+    # ItchHandler method, for code that has to implement all of them. Any
+    # extra tokens are passed through in brackets ahead of the list.
+    print('#[macro_export]')
+    print('macro_rules! for_each_itch_message {')
+    print('  ($m:ident $($args:tt)*) => {')
+    print('    $m! {')
+    print('      [$($args)*]')
+    for item in messages:
+        print('      (on_{}, $crate::itch::{}),'.format(item.get('name'), struct_name(item.get('name'))))
+    print('    }')
+    print('  };')
+    print('}')
+    print('')
+
+    print('impl fmt::Display for ItchMessage {')
+    print('  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {')
+    print('    match *self {')
+    for item in messages:
+        print('      ItchMessage::{}(ref msg) => msg.fmt(f),'.format(struct_name(item.get('name'))))
+    print('    }')
+    print('  }')
+    print('}')
+    print('')
+
+    for item in messages:
+        print('impl From<{0}> for ItchMessage {{'.format(struct_name(item.get('name'))))
+        print('  fn from(msg: {0}) -> ItchMessage {{ ItchMessage::{0}(msg) }}'.format(struct_name(item.get('name'))))
+        print('}')
+    print('')
 
-    # print('')
-    # print('//fn main() {')
-    # print('//  let msgbuf = "A\\x00\\x00\\x00\\x00\\x01\\x02\\x03\\x04\\x05\\x06\\x07\\x08\\x10\\x20\\x30\\x40\\x50\\x60\\x70\\x7FB\\x64\\x00\\x00\\x00AMZN    \\x10\\x20\\x30\\x40";')
-    # print('//  let mut handler = Dumper{};')
-    # print('//  crack_message(msgbuf.as_bytes(), &mut handler);')
-    # print('//}')
-    # print('')
 
 def main():
     tree = ET.parse(sys.argv[1])
@@ -249,8 +705,9 @@ def main():
     do_constants(root)
     do_enums(root)
     do_structs(root)
+    do_views(root)
     do_functions(root)
-    do_utils(root)
+    do_message_enum(root)
     
     # print('fn main() {}')
 
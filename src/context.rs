@@ -0,0 +1,43 @@
+use std::time::SystemTime;
+use crate::alpha::Session;
+use crate::moldudp::MoldReader;
+
+// Which of the redundant multicast lines a packet arrived on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Feed {
+  A,
+  B,
+}
+
+// Where a message came from, handed to every ItchHandler callback next to
+// the message itself. Messages that weren't read out of a MoldUDP64 packet
+// get the default: blank session, seqno 0, no receive time or feed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MessageContext {
+  pub session: Session,
+  // sequence number of this message, not of the packet it came in
+  pub seqno: u64,
+  pub received: Option<SystemTime>,
+  pub feed: Option<Feed>,
+}
+
+impl MessageContext {
+  pub fn new(session: Session, seqno: u64) -> Self {
+    MessageContext{session, seqno, received: None, feed: None}
+  }
+  // context for the index'th message of a packet. The packet's seqno is
+  // whatever came off the wire, so it wraps rather than overflowing; a
+  // SequenceTracker is what finds such packets suspect.
+  pub fn for_packet(reader: &MoldReader, index: usize) -> Self {
+    let session = Session::from_bytes(reader.session().try_into().unwrap());
+    MessageContext::new(session, reader.seqno().wrapping_add(index as u64))
+  }
+  pub fn with_received(mut self, received: SystemTime) -> Self {
+    self.received = Some(received);
+    self
+  }
+  pub fn with_feed(mut self, feed: Feed) -> Self {
+    self.feed = Some(feed);
+    self
+  }
+}
@@ -0,0 +1,364 @@
+use std::fmt::{Display, Write as _};
+use std::io::{self, BufWriter, Stdout, Write};
+use crate::alpha::Symbol;
+use crate::context::MessageContext;
+use crate::error::DecodeError;
+use crate::handlers::{MessageTypeMask, SymbolMatcher};
+use crate::hexdump::hex_dump;
+use crate::itch::ItchHandler;
+use crate::message::{FieldValue, FieldVisitor, ItchMessageType};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DumpFormat {
+  // each message's Display form, one per line
+  #[default]
+  Text,
+  // one JSON object per message: numbers for integers and prices,
+  // nanoseconds since midnight for timestamps, names for enum codes
+  JsonLines,
+  // a header row the first time each message type shows up, then one row
+  // per message; the first column is the message name in both
+  Csv,
+  // aligned columns: wall clock time, message name, then name=value pairs
+  // padded to a fixed width for each message type
+  Pretty,
+}
+
+// Writes every message it is handed, in one of the DumpFormats, to a
+// buffered writer. Output can be narrowed to some symbols (see
+// FilterBySymbol) and message types. The first write error stops output and
+// is kept for error() and into_inner(). Unknown types, decode errors and
+// trailing bytes get hex dumps in the Text and Pretty formats, JSON objects
+// in JsonLines, and are left out of Csv so every row fits its header.
+pub struct Dumper<W: Write = Stdout> {
+  out: BufWriter<W>,
+  format: DumpFormat,
+  symbols: Option<SymbolMatcher>,
+  types: MessageTypeMask,
+  csv_headers: MessageTypeMask,
+  passed: bool,
+  error: Option<io::Error>,
+  line: String,
+}
+
+impl Dumper<Stdout> {
+  pub fn stdout() -> Self {
+    Dumper::new(io::stdout(), DumpFormat::Text)
+  }
+}
+
+impl<W: Write> Dumper<W> {
+  pub fn new(out: W, format: DumpFormat) -> Self {
+    Dumper{
+      out: BufWriter::with_capacity(64 * 1024, out),
+      format,
+      symbols: None,
+      types: MessageTypeMask::ALL,
+      csv_headers: MessageTypeMask::NONE,
+      passed: false,
+      error: None,
+      line: String::with_capacity(256),
+    }
+  }
+  pub fn with_symbols<I: IntoIterator<Item = Symbol>>(mut self, symbols: I) -> Self {
+    self.symbols = Some(SymbolMatcher::new(symbols));
+    self
+  }
+  pub fn with_types(mut self, types: MessageTypeMask) -> Self {
+    self.types = types;
+    self
+  }
+  pub fn format(&self) -> DumpFormat {
+    self.format
+  }
+  pub fn error(&self) -> Option<&io::Error> {
+    self.error.as_ref()
+  }
+  pub fn flush(&mut self) -> io::Result<()> {
+    self.out.flush()
+  }
+  // flushes and hands back the writer, or the first error hit while dumping
+  pub fn into_inner(mut self) -> io::Result<W> {
+    if let Some(err) = self.error.take() {
+      return Err(err);
+    }
+    self.out.into_inner().map_err(|err| err.into_error())
+  }
+
+  fn dump<M: ItchMessageType + Display>(&mut self, msg: &M) {
+    self.passed = self.types.contains(M::TYPE)
+      && self.symbols.as_mut().is_none_or(|symbols| symbols.accepts(msg));
+    if !self.passed {
+      return;
+    }
+    self.line.clear();
+    match self.format {
+      DumpFormat::Text => {
+        write!(self.line, "{}", msg).unwrap();
+      },
+      DumpFormat::JsonLines => {
+        write!(self.line, "{{\"type\":\"{}\"", M::NAME).unwrap();
+        msg.visit_fields(&mut JsonFields(&mut self.line));
+        self.line.push('}');
+      },
+      DumpFormat::Csv => {
+        if !self.csv_headers.contains(M::TYPE) {
+          self.csv_headers = self.csv_headers.with_type::<M>();
+          writeln!(self.line, "type,{}", M::FIELDS.join(",")).unwrap();
+        }
+        self.line.push_str(M::NAME);
+        msg.visit_fields(&mut CsvFields(&mut self.line));
+      },
+      DumpFormat::Pretty => {
+        match msg.timestamp() {
+          Some(timestamp) => write!(self.line, "{} ", timestamp).unwrap(),
+          None => self.line.push_str(&" ".repeat(19)),
+        }
+        write!(self.line, "{:<31}", M::NAME).unwrap();
+        msg.visit_fields(&mut PrettyFields{out: &mut self.line, widths: M::FIELD_WIDTHS, index: 0});
+        self.line.truncate(self.line.trim_end().len());
+      },
+    }
+    self.line.push('\n');
+    self.emit();
+  }
+
+  fn dump_problem(&mut self, text: std::fmt::Arguments, json: std::fmt::Arguments, raw: &[u8]) {
+    self.line.clear();
+    match self.format {
+      DumpFormat::Text | DumpFormat::Pretty => writeln!(self.line, "{}\n{}", text, hex_dump(raw)).unwrap(),
+      DumpFormat::JsonLines => {
+        write!(self.line, "{{{},\"raw\":\"", json).unwrap();
+        raw.iter().for_each(|byte| write!(self.line, "{:02x}", byte).unwrap());
+        self.line.push_str("\"}\n");
+      },
+      DumpFormat::Csv => return,
+    }
+    self.emit();
+  }
+
+  fn emit(&mut self) {
+    if self.error.is_none() {
+      if let Err(err) = self.out.write_all(self.line.as_bytes()) {
+        self.error = Some(err);
+      }
+    }
+  }
+}
+
+macro_rules! dump_message {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, _ctx: &MessageContext) {
+        self.dump(msg);
+      }
+    )*
+  };
+}
+
+impl<W: Write> ItchHandler for Dumper<W> {
+  crate::for_each_itch_message!(dump_message);
+  fn on_unknown(&mut self, type_byte: u8, raw: &[u8], _ctx: &MessageContext) {
+    if self.types.contains(type_byte) {
+      self.dump_problem(
+        format_args!("unknown message type 0x{:02x}, {} bytes", type_byte, raw.len()),
+        format_args!("\"unknown_type\":{}", type_byte),
+        raw);
+    }
+  }
+  fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], _ctx: &MessageContext) {
+    if !err.type_byte().is_none_or(|tipe| self.types.contains(tipe)) {
+      return;
+    }
+    let mut error = String::new();
+    push_json_str(&mut error, &err.to_string());
+    self.dump_problem(format_args!("{}", err), format_args!("\"error\":{}", error), raw);
+  }
+  fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], _ctx: &MessageContext) {
+    if self.passed {
+      self.dump_problem(
+        format_args!("{} trailing bytes after message type 0x{:02x}", trailing.len(), type_byte),
+        format_args!("\"trailing_type\":{}", type_byte),
+        trailing);
+    }
+  }
+}
+
+fn push_json_str(out: &mut String, s: &str) {
+  out.push('"');
+  for c in s.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+}
+
+// printable ascii as is, anything else escaped so no control or non-ascii
+// bytes end up in the output
+fn bytes_as_str(bytes: &[u8]) -> String {
+  let mut out = String::with_capacity(bytes.len());
+  for &b in bytes {
+    match b {
+      b'\\' => out.push_str("\\\\"),
+      b' '..=b'~' => out.push(b as char),
+      _ => write!(out, "\\x{:02x}", b).unwrap(),
+    }
+  }
+  out
+}
+
+fn char_str(c: u8) -> String {
+  if c.is_ascii_graphic() || c == b' ' { (c as char).to_string() } else { format!("0x{:02x}", c) }
+}
+
+// enum codes print as their name, or as the bare code when it isn't in the schema
+fn code_str(code: u8, name: &'static str) -> String {
+  if name == "Unknown" { char_str(code) } else { name.to_string() }
+}
+
+struct JsonFields<'a>(&'a mut String);
+
+impl FieldVisitor for JsonFields<'_> {
+  fn field(&mut self, name: &'static str, value: FieldValue<'_>) {
+    let out = &mut *self.0;
+    write!(out, ",\"{}\":", name).unwrap();
+    match value {
+      FieldValue::Char(c) => push_json_str(out, &char_str(c)),
+      FieldValue::U16(n) => write!(out, "{}", n).unwrap(),
+      FieldValue::U32(n) => write!(out, "{}", n).unwrap(),
+      FieldValue::U64(n) => write!(out, "{}", n).unwrap(),
+      FieldValue::Price4(price) => write!(out, "{}", price).unwrap(),
+      FieldValue::Price8(price) => write!(out, "{}", price).unwrap(),
+      FieldValue::Timestamp(timestamp) => write!(out, "{}", timestamp.0).unwrap(),
+      FieldValue::Text(text) => push_json_str(out, &bytes_as_str(text.as_bytes())),
+      FieldValue::Bytes(bytes) => push_json_str(out, &bytes_as_str(bytes)),
+      FieldValue::Code { code, name } => push_json_str(out, &code_str(code, name)),
+    }
+  }
+}
+
+struct CsvFields<'a>(&'a mut String);
+
+impl FieldVisitor for CsvFields<'_> {
+  fn field(&mut self, _name: &'static str, value: FieldValue<'_>) {
+    let text = match value {
+      FieldValue::Char(c) => char_str(c),
+      FieldValue::U16(n) => n.to_string(),
+      FieldValue::U32(n) => n.to_string(),
+      FieldValue::U64(n) => n.to_string(),
+      FieldValue::Price4(price) => price.to_string(),
+      FieldValue::Price8(price) => price.to_string(),
+      FieldValue::Timestamp(timestamp) => timestamp.to_string(),
+      FieldValue::Text(text) => bytes_as_str(text.as_bytes()),
+      FieldValue::Bytes(bytes) => bytes_as_str(bytes),
+      FieldValue::Code { code, name } => code_str(code, name),
+    };
+    self.0.push(',');
+    if text.contains([',', '"', '\n', '\r']) {
+      write!(self.0, "\"{}\"", text.replace('"', "\"\"")).unwrap();
+    } else {
+      self.0.push_str(&text);
+    }
+  }
+}
+
+// name=value pairs, each value padded to its field's width so messages of
+// one type line up
+struct PrettyFields<'a> {
+  out: &'a mut String,
+  widths: &'static [usize],
+  index: usize,
+}
+
+impl FieldVisitor for PrettyFields<'_> {
+  fn field(&mut self, name: &'static str, value: FieldValue<'_>) {
+    let width = self.widths.get(self.index).copied().unwrap_or(0);
+    self.index += 1;
+    // already in the leading columns
+    if name == "message_type" || name == "timestamp" {
+      return;
+    }
+    let out = &mut *self.out;
+    write!(out, " {}=", name).unwrap();
+    match value {
+      FieldValue::Char(c) => write!(out, "{:<width$}", char_str(c)).unwrap(),
+      FieldValue::U16(n) => write!(out, "{:>width$}", n).unwrap(),
+      FieldValue::U32(n) => write!(out, "{:>width$}", n).unwrap(),
+      FieldValue::U64(n) => write!(out, "{:>width$}", n).unwrap(),
+      FieldValue::Price4(price) => write!(out, "{:>width$}", price.to_string()).unwrap(),
+      FieldValue::Price8(price) => write!(out, "{:>width$}", price.to_string()).unwrap(),
+      FieldValue::Timestamp(timestamp) => write!(out, "{:<width$}", timestamp.to_string()).unwrap(),
+      FieldValue::Text(text) => write!(out, "{:<width$}", bytes_as_str(text.as_bytes())).unwrap(),
+      FieldValue::Bytes(bytes) => write!(out, "{:<width$}", bytes_as_str(bytes).trim_end()).unwrap(),
+      FieldValue::Code { code, name } => write!(out, "{:<width$}", code_str(code, name)).unwrap(),
+    }
+  }
+}
+
+#[cfg(test)]
+fn dump_samples(dumper: Dumper<Vec<u8>>) -> String {
+  use crate::itch::*;
+  use crate::price::Price4;
+  use crate::timestamp::ItchTimestamp;
+  let mut dumper = dumper;
+  let ctx = MessageContext::default();
+  let msgs = [
+    ItchMessage::SystemEvent(SystemEvent{message_type: SystemEvent::TYPE, timestamp: ItchTimestamp::from_hms_nano(4, 0, 0, 5).unwrap(), event_code: eSystemEvent::Start_of_Messages, ..Default::default()}),
+    ItchMessage::AddOrder(AddOrder{message_type: AddOrder::TYPE, stock_locate: 7, timestamp: ItchTimestamp::from_hms_nano(9, 30, 0, 0).unwrap(), order_reference_number: 42, buy_sell_indicator: eBuySellIndicator::Buy_Order, shares: 100, stock: Symbol::new("AAPL").unwrap(), price: Price4(1234500), ..Default::default()}),
+    ItchMessage::AddOrder(AddOrder{message_type: AddOrder::TYPE, stock_locate: 8, stock: Symbol::new("MSFT").unwrap(), ..Default::default()}),
+  ];
+  for msg in &msgs {
+    msg.dispatch(&ctx, &mut dumper);
+  }
+  dumper.on_unknown(b'~', b"~\x01", &ctx);
+  String::from_utf8(dumper.into_inner().unwrap()).unwrap()
+}
+
+#[test]
+fn dump_formats() {
+  let text = dump_samples(Dumper::new(Vec::new(), DumpFormat::Text));
+  assert!(text.starts_with("SystemEvent("));
+  assert!(text.ends_with("unknown message type 0x7e, 2 bytes\n0000  7e 01                                             |~.|\n"));
+
+  let json = dump_samples(Dumper::new(Vec::new(), DumpFormat::JsonLines));
+  let lines = json.lines().collect::<Vec<_>>();
+  assert_eq!(lines[1], "{\"type\":\"AddOrder\",\"message_type\":\"A\",\"stock_locate\":7,\"tracking_number\":0,\"timestamp\":34200000000000,\"order_reference_number\":42,\"buy_sell_indicator\":\"Buy_Order\",\"shares\":100,\"stock\":\"AAPL\",\"price\":123.4500}");
+  assert_eq!(lines[3], "{\"unknown_type\":126,\"raw\":\"7e01\"}");
+
+  let csv = dump_samples(Dumper::new(Vec::new(), DumpFormat::Csv).with_symbols([Symbol::new("AAPL").unwrap()]));
+  assert_eq!(csv.lines().collect::<Vec<_>>(), vec![
+    "type,message_type,stock_locate,tracking_number,timestamp,event_code",
+    "SystemEvent,S,0,0,04:00:00.000000005,Start_of_Messages",
+    "type,message_type,stock_locate,tracking_number,timestamp,order_reference_number,buy_sell_indicator,shares,stock,price",
+    "AddOrder,A,7,0,09:30:00.000000000,42,Buy_Order,100,AAPL,123.4500",
+  ]);
+
+  let pretty = dump_samples(Dumper::new(Vec::new(), DumpFormat::Pretty).with_types(MessageTypeMask::of(b"A")));
+  let lines = pretty.lines().collect::<Vec<_>>();
+  assert_eq!(lines[0],
+    "09:30:00.000000000 AddOrder                        stock_locate=    7 tracking_number=    0 order_reference_number=                  42 buy_sell_indicator=Buy_Order  shares=       100 stock=AAPL     price=   123.4500");
+  for column in ["shares=", "stock=", "price="] {
+    assert_eq!(lines[0].find(column), lines[1].find(column));
+  }
+  assert_eq!(lines[0].len(), lines[1].len());
+  assert_eq!(lines.iter().filter(|line| line.contains("AddOrder")).count(), 2);
+  // the second order's side is the default unknown code
+  assert!(lines[1].contains(" buy_sell_indicator=0x00       shares="), "{}", lines[1]);
+  for format in [DumpFormat::Text, DumpFormat::JsonLines, DumpFormat::Csv, DumpFormat::Pretty] {
+    assert!(!dump_samples(Dumper::new(Vec::new(), format)).contains('\0'));
+  }
+  assert_eq!(bytes_as_str(b"AB\x01\\\xe9 "), "AB\\x01\\\\\\xe9 ");
+
+  // decode errors of filtered out types are left out too
+  let mut dumper = Dumper::new(Vec::new(), DumpFormat::Text).with_types(MessageTypeMask::of(b"A"));
+  let ctx = MessageContext::default();
+  crate::itch::crack_message_with_context(b"D\x00\x10", &ctx, &mut dumper).unwrap_err();
+  crate::itch::crack_message_with_context(b"A\x00\x10", &ctx, &mut dumper).unwrap_err();
+  let text = String::from_utf8(dumper.into_inner().unwrap()).unwrap();
+  assert!(text.starts_with("truncated message type 0x41 "), "{}", text);
+  assert_eq!(text.lines().count(), 2);
+}
@@ -0,0 +1,26 @@
+use std::io::{self, Write};
+
+// Wire encoding of a fixed size message.
+pub trait Encode {
+  const ENCODED_LEN : usize;
+
+  fn encode<W: Write>(&self, wrt: &mut W) -> io::Result<()>;
+
+  // returns the number of bytes written, fails without writing anything if
+  // buf is too small
+  fn encode_to_slice(&self, buf: &mut [u8]) -> io::Result<usize> {
+    if buf.len() < Self::ENCODED_LEN {
+      return Err(io::Error::new(io::ErrorKind::WriteZero,
+        format!("need {} bytes to encode, have {}", Self::ENCODED_LEN, buf.len())));
+    }
+    let mut wrt = &mut buf[..Self::ENCODED_LEN];
+    self.encode(&mut wrt)?;
+    Ok(Self::ENCODED_LEN)
+  }
+
+  fn to_vec(&self) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(Self::ENCODED_LEN);
+    self.encode(&mut bytes).expect("writing to a Vec can't fail");
+    bytes
+  }
+}
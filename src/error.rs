@@ -0,0 +1,248 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+  // nothing to decode
+  Empty,
+  // type byte doesn't match any message in the schema
+  UnknownType { type_byte: u8, offset: usize },
+  // decoding as one message type, but the type byte says another
+  WrongType { type_byte: u8, expected: u8, offset: usize },
+  // fewer bytes than the schema says the message needs
+  Truncated { type_byte: u8, expected: usize, actual: usize, offset: usize },
+  // more bytes than the schema says the message has, only under LengthCheck::Strict
+  LengthMismatch { type_byte: u8, expected: usize, actual: usize, offset: usize },
+}
+
+impl DecodeError {
+  pub fn type_byte(&self) -> Option<u8> {
+    match *self {
+      DecodeError::Empty => None,
+      DecodeError::UnknownType { type_byte, .. } => Some(type_byte),
+      DecodeError::WrongType { type_byte, .. } => Some(type_byte),
+      DecodeError::Truncated { type_byte, .. } => Some(type_byte),
+      DecodeError::LengthMismatch { type_byte, .. } => Some(type_byte),
+    }
+  }
+
+  // offset of the offending message within the buffer that was being decoded
+  pub fn offset(&self) -> usize {
+    match *self {
+      DecodeError::Empty => 0,
+      DecodeError::UnknownType { offset, .. } => offset,
+      DecodeError::WrongType { offset, .. } => offset,
+      DecodeError::Truncated { offset, .. } => offset,
+      DecodeError::LengthMismatch { offset, .. } => offset,
+    }
+  }
+
+  // shift the reported offset, for callers that decode a message out of a larger buffer
+  pub fn at_offset(self, base: usize) -> Self {
+    match self {
+      DecodeError::Empty => DecodeError::Empty,
+      DecodeError::UnknownType { type_byte, offset } =>
+        DecodeError::UnknownType { type_byte, offset: base + offset },
+      DecodeError::WrongType { type_byte, expected, offset } =>
+        DecodeError::WrongType { type_byte, expected, offset: base + offset },
+      DecodeError::Truncated { type_byte, expected, actual, offset } =>
+        DecodeError::Truncated { type_byte, expected, actual, offset: base + offset },
+      DecodeError::LengthMismatch { type_byte, expected, actual, offset } =>
+        DecodeError::LengthMismatch { type_byte, expected, actual, offset: base + offset },
+    }
+  }
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      DecodeError::Empty => write!(f, "empty message"),
+      DecodeError::UnknownType { type_byte, offset } =>
+        write!(f, "unknown message type 0x{:02x} at offset {}", type_byte, offset),
+      DecodeError::WrongType { type_byte, expected, offset } =>
+        write!(f, "message type 0x{:02x} at offset {}, expected 0x{:02x}", type_byte, offset, expected),
+      DecodeError::Truncated { type_byte, expected, actual, offset } =>
+        write!(f, "truncated message type 0x{:02x} at offset {}: expected {} bytes, got {}", type_byte, offset, expected, actual),
+      DecodeError::LengthMismatch { type_byte, expected, actual, offset } =>
+        write!(f, "oversized message type 0x{:02x} at offset {}: expected {} bytes, got {}", type_byte, offset, expected, actual),
+    }
+  }
+}
+
+impl std::error::Error for DecodeError {}
+
+// a code byte that isn't in one of the schema's enum tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownCode {
+  pub enum_name: &'static str,
+  pub code: u8,
+}
+
+impl fmt::Display for UnknownCode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "unknown {} code 0x{:02x}", self.enum_name, self.code)
+  }
+}
+
+impl std::error::Error for UnknownCode {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceError {
+  // not a plain decimal number
+  Invalid,
+  // more decimal places than the price type carries
+  TooPrecise,
+  // doesn't fit the price type
+  OutOfRange,
+}
+
+impl fmt::Display for PriceError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      PriceError::Invalid => write!(f, "invalid price"),
+      PriceError::TooPrecise => write!(f, "price has too many decimal places"),
+      PriceError::OutOfRange => write!(f, "price out of range"),
+    }
+  }
+}
+
+impl std::error::Error for PriceError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampError {
+  // not in one of the accepted time or date formats
+  Invalid,
+  // well formed but not a real time of day or calendar date
+  OutOfRange,
+}
+
+impl fmt::Display for TimestampError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      TimestampError::Invalid => write!(f, "invalid time"),
+      TimestampError::OutOfRange => write!(f, "time out of range"),
+    }
+  }
+}
+
+impl std::error::Error for TimestampError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphaError {
+  // longer than the fixed field width
+  TooLong { max: usize, actual: usize },
+  NotAscii,
+}
+
+impl fmt::Display for AlphaError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      AlphaError::TooLong { max, actual } => write!(f, "{} bytes doesn't fit in a {} byte field", actual, max),
+      AlphaError::NotAscii => write!(f, "not ascii"),
+    }
+  }
+}
+
+impl std::error::Error for AlphaError {}
+
+// reading messages off a byte stream can fail in the reader or in the decoder
+#[derive(Debug)]
+pub enum StreamError {
+  Io(std::io::Error),
+  Decode(DecodeError),
+  Mold(MoldError),
+}
+
+impl fmt::Display for StreamError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      StreamError::Io(ref err) => write!(f, "read error: {}", err),
+      StreamError::Decode(ref err) => write!(f, "decode error: {}", err),
+      StreamError::Mold(ref err) => write!(f, "bad packet: {}", err),
+    }
+  }
+}
+
+impl std::error::Error for StreamError {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match *self {
+      StreamError::Io(ref err) => Some(err),
+      StreamError::Decode(ref err) => Some(err),
+      StreamError::Mold(ref err) => Some(err),
+    }
+  }
+}
+
+impl From<std::io::Error> for StreamError {
+  fn from(err: std::io::Error) -> Self {
+    StreamError::Io(err)
+  }
+}
+
+impl From<DecodeError> for StreamError {
+  fn from(err: DecodeError) -> Self {
+    StreamError::Decode(err)
+  }
+}
+
+impl From<MoldError> for StreamError {
+  fn from(err: MoldError) -> Self {
+    StreamError::Mold(err)
+  }
+}
+
+// a SystemEvent that doesn't fit the day's O S Q M E C sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionError {
+  // event code not in the schema
+  UnknownEvent { code: u8 },
+  // the same event a second time
+  Duplicate { code: u8 },
+  // an event that belongs before one already seen
+  OutOfOrder { code: u8, after: u8 },
+  // an event that arrived without missed, which belongs between it and the
+  // previous event
+  Skipped { code: u8, missed: u8 },
+}
+
+impl fmt::Display for SessionError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      SessionError::UnknownEvent { code } => write!(f, "unknown system event '{}'", code as char),
+      SessionError::Duplicate { code } => write!(f, "duplicate system event '{}'", code as char),
+      SessionError::OutOfOrder { code, after } => write!(f, "system event '{}' after '{}'", code as char, after as char),
+      SessionError::Skipped { code, missed } => write!(f, "system event '{}' without '{}' before it", code as char, missed as char),
+    }
+  }
+}
+
+impl std::error::Error for SessionError {}
+
+// a MoldUDP64 packet whose framing doesn't add up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoldError {
+  // shorter than the 20 byte packet header
+  ShortHeader { actual: usize },
+  // a message block's length prefix or body runs past the end of the packet
+  TruncatedBlock { index: u16, offset: usize, expected: usize, actual: usize },
+  // the packet ends cleanly after fewer messages than the header declares
+  MissingMessages { declared: u16, found: u16, offset: usize },
+  // bytes left over after the declared number of messages
+  TrailingBytes { offset: usize, len: usize },
+}
+
+impl fmt::Display for MoldError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      MoldError::ShortHeader { actual } =>
+        write!(f, "packet of {} bytes is too short for a header", actual),
+      MoldError::TruncatedBlock { index, offset, expected, actual } =>
+        write!(f, "message {} at offset {} truncated: expected {} bytes, got {}", index, offset, expected, actual),
+      MoldError::MissingMessages { declared, found, .. } =>
+        write!(f, "packet declares {} messages but holds {}", declared, found),
+      MoldError::TrailingBytes { offset, len } =>
+        write!(f, "{} trailing bytes at offset {}", len, offset),
+    }
+  }
+}
+
+impl std::error::Error for MoldError {}
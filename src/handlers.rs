@@ -0,0 +1,500 @@
+use std::collections::HashSet;
+use crate::alpha::Symbol;
+use crate::context::MessageContext;
+use crate::error::DecodeError;
+use crate::itch::{ItchHandler, ItchMessage};
+use crate::message::ItchMessageType;
+
+// Handlers that wrap other handlers. All of them implement ItchHandler
+// themselves, so they nest and can be handed straight to crack_message.
+
+macro_rules! forward_to_deref {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        (**self).$on(msg, ctx);
+      }
+    )*
+  };
+}
+
+macro_rules! fan_out_to_vec {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        for handler in self.iter_mut() {
+          handler.$on(msg, ctx);
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! fan_out_to_fields {
+  ([$fields:tt] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        fan_out_call!(self, $on, msg, ctx, $fields);
+      }
+    )*
+  };
+}
+
+macro_rules! fan_out_call {
+  ($self:ident, $on:ident, $msg:ident, $ctx:ident, [$($idx:tt)*]) => {
+    $( $self.$idx.$on($msg, $ctx); )*
+  };
+}
+
+macro_rules! fan_out_to_tuple {
+  ($($h:ident $idx:tt),*) => {
+    impl<$($h: ItchHandler),*> ItchHandler for ($($h,)*) {
+      crate::for_each_itch_message!(fan_out_to_fields [$($idx)*]);
+      fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+        $( self.$idx.on_unknown(type_byte, raw, ctx); )*
+      }
+      fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+        $( self.$idx.on_decode_error(err.clone(), raw, ctx); )*
+      }
+      fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+        $( self.$idx.on_trailing_bytes(type_byte, trailing, ctx); )*
+      }
+    }
+  };
+}
+
+// the unknown type, decode error and trailing bytes hooks go straight to self.inner
+macro_rules! forward_hooks_to_inner {
+  () => {
+    fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+      self.inner.on_unknown(type_byte, raw, ctx);
+    }
+    fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+      self.inner.on_decode_error(err, raw, ctx);
+    }
+    fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+      self.inner.on_trailing_bytes(type_byte, trailing, ctx);
+    }
+  };
+}
+
+// forwards to self.inner when self.accepts(msg), remembering the outcome so
+// trailing bytes of a dropped message are dropped too
+macro_rules! forward_if_accepted {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        self.passed = self.accepts(msg);
+        if self.passed {
+          self.inner.$on(msg, ctx);
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! map_message {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        let mapped = (self.f)(ItchMessage::from(*msg), ctx);
+        self.passed = mapped.is_some();
+        if let Some(mapped) = mapped {
+          mapped.dispatch(ctx, &mut self.inner);
+        }
+      }
+    )*
+  };
+}
+
+macro_rules! inspect_message {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, ctx: &MessageContext) {
+        (self.f)(&ItchMessage::from(*msg), ctx);
+        self.inner.$on(msg, ctx);
+      }
+    )*
+  };
+}
+
+macro_rules! forward_hooks_to_deref {
+  () => {
+    fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+      (**self).on_unknown(type_byte, raw, ctx);
+    }
+    fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+      (**self).on_decode_error(err, raw, ctx);
+    }
+    fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+      (**self).on_trailing_bytes(type_byte, trailing, ctx);
+    }
+  };
+}
+
+// unknown and undecodable messages pass, trailing bytes follow their message
+macro_rules! forward_filtered_hooks {
+  () => {
+    fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+      self.inner.on_unknown(type_byte, raw, ctx);
+    }
+    fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+      self.inner.on_decode_error(err, raw, ctx);
+    }
+    fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+      if self.passed {
+        self.inner.on_trailing_bytes(type_byte, trailing, ctx);
+      }
+    }
+  };
+}
+
+impl<H: ItchHandler + ?Sized> ItchHandler for &mut H {
+  crate::for_each_itch_message!(forward_to_deref);
+  forward_hooks_to_deref!();
+}
+
+impl<H: ItchHandler + ?Sized> ItchHandler for Box<H> {
+  crate::for_each_itch_message!(forward_to_deref);
+  forward_hooks_to_deref!();
+}
+
+// Fan out: every handler sees every message, in order. Works for
+// Vec<Box<dyn ItchHandler>> as well as for a vector of one handler type.
+impl<H: ItchHandler> ItchHandler for Vec<H> {
+  crate::for_each_itch_message!(fan_out_to_vec);
+  fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+    for handler in self.iter_mut() {
+      handler.on_unknown(type_byte, raw, ctx);
+    }
+  }
+  fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+    for handler in self.iter_mut() {
+      handler.on_decode_error(err.clone(), raw, ctx);
+    }
+  }
+  fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+    for handler in self.iter_mut() {
+      handler.on_trailing_bytes(type_byte, trailing, ctx);
+    }
+  }
+}
+
+fan_out_to_tuple!(A 0, B 1);
+fan_out_to_tuple!(A 0, B 1, C 2);
+fan_out_to_tuple!(A 0, B 1, C 2, D 3);
+fan_out_to_tuple!(A 0, B 1, C 2, D 3, E 4);
+
+// Only messages for the given stock locates. Messages without a locate
+// (EndOfSnapshot) always pass; market wide messages carry locate 0 and pass
+// only if 0 is in the set. Unknown and undecodable messages always pass.
+pub struct FilterByLocate<H> {
+  inner: H,
+  locates: HashSet<u16>,
+  passed: bool,
+}
+
+impl<H> FilterByLocate<H> {
+  pub fn new<I: IntoIterator<Item = u16>>(inner: H, locates: I) -> Self {
+    FilterByLocate{inner, locates: locates.into_iter().collect(), passed: false}
+  }
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+  pub fn inner_mut(&mut self) -> &mut H {
+    &mut self.inner
+  }
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+  fn accepts<M: ItchMessageType>(&mut self, msg: &M) -> bool {
+    msg.stock_locate().is_none_or(|locate| self.locates.contains(&locate))
+  }
+}
+
+impl<H: ItchHandler> ItchHandler for FilterByLocate<H> {
+  crate::for_each_itch_message!(forward_if_accepted);
+  forward_filtered_hooks!();
+}
+
+// Only messages for the given symbols. Locates are learned from any message
+// that carries the symbol (normally the morning StockDirectory), so messages
+// that only carry a locate pass once their symbol has been seen. Market wide
+// messages (locate 0, or no locate at all) always pass.
+pub struct FilterBySymbol<H> {
+  inner: H,
+  matcher: SymbolMatcher,
+  passed: bool,
+}
+
+// the symbol and learned locate sets behind FilterBySymbol
+pub(crate) struct SymbolMatcher {
+  symbols: HashSet<Symbol>,
+  locates: HashSet<u16>,
+}
+
+impl SymbolMatcher {
+  pub(crate) fn new<I: IntoIterator<Item = Symbol>>(symbols: I) -> Self {
+    SymbolMatcher{symbols: symbols.into_iter().collect(), locates: HashSet::new()}
+  }
+  pub(crate) fn accepts<M: ItchMessageType>(&mut self, msg: &M) -> bool {
+    match (msg.stock(), msg.stock_locate()) {
+      (Some(stock), locate) => {
+        let wanted = self.symbols.contains(&stock);
+        if let (true, Some(locate)) = (wanted, locate) {
+          self.locates.insert(locate);
+        }
+        wanted
+      },
+      (None, None) | (None, Some(0)) => true,
+      (None, Some(locate)) => self.locates.contains(&locate),
+    }
+  }
+}
+
+impl<H> FilterBySymbol<H> {
+  pub fn new<I: IntoIterator<Item = Symbol>>(inner: H, symbols: I) -> Self {
+    FilterBySymbol{inner, matcher: SymbolMatcher::new(symbols), passed: false}
+  }
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+  pub fn inner_mut(&mut self) -> &mut H {
+    &mut self.inner
+  }
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+  // locates seen so far for the filtered symbols
+  pub fn locates(&self) -> &HashSet<u16> {
+    &self.matcher.locates
+  }
+  fn accepts<M: ItchMessageType>(&mut self, msg: &M) -> bool {
+    self.matcher.accepts(msg)
+  }
+}
+
+impl<H: ItchHandler> ItchHandler for FilterBySymbol<H> {
+  crate::for_each_itch_message!(forward_if_accepted);
+  forward_filtered_hooks!();
+}
+
+// Set of message type bytes. All ITCH types are ascii so a 128 bit mask
+// covers them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MessageTypeMask(pub u128);
+
+impl MessageTypeMask {
+  pub const NONE : MessageTypeMask = MessageTypeMask(0);
+  pub const ALL : MessageTypeMask = MessageTypeMask(!0);
+
+  pub fn of(types: &[u8]) -> Self {
+    types.iter().fold(Self::NONE, |mask, tipe| mask.with(*tipe))
+  }
+  pub fn with(self, tipe: u8) -> Self {
+    if tipe < 128 { MessageTypeMask(self.0 | 1 << tipe) } else { self }
+  }
+  pub fn with_type<M: ItchMessageType>(self) -> Self {
+    self.with(M::TYPE)
+  }
+  pub fn without(self, tipe: u8) -> Self {
+    if tipe < 128 { MessageTypeMask(self.0 & !(1 << tipe)) } else { self }
+  }
+  pub fn contains(&self, tipe: u8) -> bool {
+    tipe < 128 && self.0 & (1 << tipe) != 0
+  }
+}
+
+pub struct FilterByMessageType<H> {
+  inner: H,
+  mask: MessageTypeMask,
+  passed: bool,
+}
+
+impl<H> FilterByMessageType<H> {
+  pub fn new(inner: H, mask: MessageTypeMask) -> Self {
+    FilterByMessageType{inner, mask, passed: false}
+  }
+  pub fn inner(&self) -> &H {
+    &self.inner
+  }
+  pub fn inner_mut(&mut self) -> &mut H {
+    &mut self.inner
+  }
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+  fn accepts<M: ItchMessageType>(&mut self, _msg: &M) -> bool {
+    self.mask.contains(M::TYPE)
+  }
+}
+
+impl<H: ItchHandler> ItchHandler for FilterByMessageType<H> {
+  crate::for_each_itch_message!(forward_if_accepted);
+  fn on_unknown(&mut self, type_byte: u8, raw: &[u8], ctx: &MessageContext) {
+    if self.mask.contains(type_byte) {
+      self.inner.on_unknown(type_byte, raw, ctx);
+    }
+  }
+  fn on_decode_error(&mut self, err: DecodeError, raw: &[u8], ctx: &MessageContext) {
+    if err.type_byte().is_none_or(|tipe| self.mask.contains(tipe)) {
+      self.inner.on_decode_error(err, raw, ctx);
+    }
+  }
+  fn on_trailing_bytes(&mut self, type_byte: u8, trailing: &[u8], ctx: &MessageContext) {
+    if self.passed {
+      self.inner.on_trailing_bytes(type_byte, trailing, ctx);
+    }
+  }
+}
+
+// Rewrites or drops messages on the way to the inner handler. The closure
+// may return a message of a different type. Trailing bytes follow their
+// message, so they're dropped along with it.
+pub struct Map<H, F> {
+  inner: H,
+  f: F,
+  passed: bool,
+}
+
+impl<H, F> Map<H, F> where F: FnMut(ItchMessage, &MessageContext) -> Option<ItchMessage> {
+  pub fn new(inner: H, f: F) -> Self {
+    Map{inner, f, passed: false}
+  }
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+}
+
+impl<H: ItchHandler, F> ItchHandler for Map<H, F> where F: FnMut(ItchMessage, &MessageContext) -> Option<ItchMessage> {
+  crate::for_each_itch_message!(map_message);
+  forward_filtered_hooks!();
+}
+
+// Calls the closure with each message before passing it on unchanged.
+pub struct Inspect<H, F> {
+  inner: H,
+  f: F,
+}
+
+impl<H, F> Inspect<H, F> where F: FnMut(&ItchMessage, &MessageContext) {
+  pub fn new(inner: H, f: F) -> Self {
+    Inspect{inner, f}
+  }
+  pub fn into_inner(self) -> H {
+    self.inner
+  }
+}
+
+impl<H: ItchHandler, F> ItchHandler for Inspect<H, F> where F: FnMut(&ItchMessage, &MessageContext) {
+  crate::for_each_itch_message!(inspect_message);
+  forward_hooks_to_inner!();
+}
+
+#[cfg(test)]
+#[derive(Default)]
+struct Collect(Vec<ItchMessage>);
+
+#[cfg(test)]
+impl ItchHandler for Collect {
+  crate::for_each_itch_message!(collect_message);
+}
+
+#[cfg(test)]
+macro_rules! collect_message {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, _ctx: &MessageContext) {
+        self.0.push(ItchMessage::from(*msg));
+      }
+    )*
+  };
+}
+#[cfg(test)]
+use collect_message;
+
+#[cfg(test)]
+fn sample_messages() -> Vec<ItchMessage> {
+  use crate::itch::*;
+  vec![
+    ItchMessage::SystemEvent(SystemEvent{message_type: SystemEvent::TYPE, event_code: eSystemEvent::Start_of_Messages, ..Default::default()}),
+    ItchMessage::StockDirectory(StockDirectory{message_type: StockDirectory::TYPE, stock_locate: 1, stock: Symbol::new("AAPL").unwrap(), ..Default::default()}),
+    ItchMessage::StockDirectory(StockDirectory{message_type: StockDirectory::TYPE, stock_locate: 2, stock: Symbol::new("AMZN").unwrap(), ..Default::default()}),
+    ItchMessage::OrderDelete(OrderDelete{message_type: OrderDelete::TYPE, stock_locate: 1, order_reference_number: 10, ..Default::default()}),
+    ItchMessage::OrderDelete(OrderDelete{message_type: OrderDelete::TYPE, stock_locate: 2, order_reference_number: 20, ..Default::default()}),
+  ]
+}
+
+#[test]
+fn fan_out() {
+  let mut handlers = (Collect::default(), vec![Collect::default(), Collect::default()]);
+  let (mut first, mut second) = (Collect::default(), Collect::default());
+  let mut boxed : Vec<Box<dyn ItchHandler + '_>> = vec![Box::new(&mut first), Box::new(&mut second)];
+  for msg in sample_messages() {
+    msg.dispatch(&MessageContext::default(), &mut handlers);
+    msg.dispatch(&MessageContext::default(), &mut boxed);
+  }
+  drop(boxed);
+  assert_eq!(handlers.0 .0, sample_messages());
+  assert_eq!(handlers.1[0].0, sample_messages());
+  assert_eq!(handlers.1[1].0, sample_messages());
+  assert_eq!(first.0, sample_messages());
+  assert_eq!(second.0, sample_messages());
+}
+
+#[test]
+fn filters() {
+  use crate::itch::{OrderDelete, StockDirectory};
+  let mut by_locate = FilterByLocate::new(Collect::default(), [2]);
+  let mut by_symbol = FilterBySymbol::new(Collect::default(), [Symbol::new("AAPL").unwrap()]);
+  let mut by_type = FilterByMessageType::new(Collect::default(), MessageTypeMask::NONE.with_type::<OrderDelete>());
+  for msg in sample_messages() {
+    let ctx = MessageContext::default();
+    msg.dispatch(&ctx, &mut (&mut by_locate, &mut by_symbol, &mut by_type));
+  }
+  let locates = |msgs: &[ItchMessage]| msgs.iter().map(|msg| msg.stock_locate()).collect::<Vec<_>>();
+  assert_eq!(locates(&by_locate.inner().0), vec![Some(2), Some(2)]);
+  assert_eq!(locates(&by_symbol.inner().0), vec![Some(0), Some(1), Some(1)]);
+  assert_eq!(by_type.inner().0.iter().map(|msg| msg.message_type()).collect::<Vec<_>>(), vec![b'D', b'D']);
+  assert!(!MessageTypeMask::ALL.without(StockDirectory::TYPE).contains(b'R'));
+  assert!(!MessageTypeMask::ALL.contains(0xff));
+}
+
+#[test]
+fn map_and_inspect() {
+  let mut seen = 0;
+  let mut mapped = {
+    let inspect = Inspect::new(Collect::default(), |_msg: &ItchMessage, _ctx: &MessageContext| seen += 1);
+    let mut map = Map::new(inspect, |msg: ItchMessage, _ctx: &MessageContext| match msg {
+      ItchMessage::OrderDelete(mut delete) => { delete.order_reference_number += 1; Some(ItchMessage::OrderDelete(delete)) },
+      _ => None,
+    });
+    for msg in sample_messages() {
+      msg.dispatch(&MessageContext::default(), &mut map);
+    }
+    map.into_inner().into_inner()
+  };
+  assert_eq!(seen, 2);
+  let refs = mapped.0.drain(..).map(|msg| match msg {
+    ItchMessage::OrderDelete(delete) => delete.order_reference_number,
+    _ => 0,
+  }).collect::<Vec<_>>();
+  assert_eq!(refs, vec![11, 21]);
+
+  // trailing bytes of a dropped message don't reach the inner handler
+  #[derive(Default)]
+  struct Trailing(Vec<u8>);
+  impl ItchHandler for Trailing {
+    fn on_trailing_bytes(&mut self, type_byte: u8, _trailing: &[u8], _ctx: &MessageContext) {
+      self.0.push(type_byte);
+    }
+  }
+  use crate::itch::crack_message;
+  let mut map = Map::new(Trailing::default(), |msg: ItchMessage, _ctx: &MessageContext| match msg {
+    ItchMessage::OrderDelete(_) => Some(msg),
+    _ => None,
+  });
+  crack_message(b"S\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00O\xAB", &mut map).unwrap();
+  crack_message(b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F\xAB", &mut map).unwrap();
+  assert_eq!(map.into_inner().0, b"D");
+}
@@ -0,0 +1,35 @@
+use std::fmt::Write;
+
+// Classic offset / hex / ascii dump, 16 bytes to a line, e.g.
+// 0000  44 00 10 00 00 00 00 00  00 00 ff 10 20           |D........... |
+pub fn hex_dump(bytes: &[u8]) -> String {
+  let mut out = String::new();
+  for (line, chunk) in bytes.chunks(16).enumerate() {
+    if line > 0 {
+      out.push('\n');
+    }
+    write!(out, "{:04x} ", line * 16).unwrap();
+    for i in 0..16 {
+      if i == 8 {
+        out.push(' ');
+      }
+      match chunk.get(i) {
+        Some(byte) => write!(out, " {:02x}", byte).unwrap(),
+        None => out.push_str("   "),
+      }
+    }
+    out.push_str("  |");
+    out.extend(chunk.iter().map(|&c| if c.is_ascii_graphic() || c == b' ' { c as char } else { '.' }));
+    out.push('|');
+  }
+  out
+}
+
+#[test]
+fn hex_dump_lines() {
+  assert_eq!(hex_dump(b""), "");
+  assert_eq!(hex_dump(b"D\x00\x10"), "0000  44 00 10                                          |D..|");
+  let dump = hex_dump(b"0123456789abcdefXY");
+  assert_eq!(dump.lines().count(), 2);
+  assert!(dump.ends_with("0010  58 59                                             |XY|"));
+}
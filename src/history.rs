@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+use std::path::Path;
+
+// The last so many messages of a session by sequence number, for answering
+// retransmission requests. Sequence numbers start at 1 and go up by one per
+// message appended; once full the oldest message is dropped to make room.
+pub trait MessageHistory {
+  // adds the next message, returning its sequence number
+  fn append(&mut self, msg: &[u8]) -> io::Result<u64>;
+  // the sequence numbers still held
+  fn window(&self) -> Range<u64>;
+  // replaces buf with message seqno, false if it's outside the window
+  fn read(&mut self, seqno: u64, buf: &mut Vec<u8>) -> io::Result<bool>;
+}
+
+impl<H: MessageHistory + ?Sized> MessageHistory for &mut H {
+  fn append(&mut self, msg: &[u8]) -> io::Result<u64> {
+    (**self).append(msg)
+  }
+  fn window(&self) -> Range<u64> {
+    (**self).window()
+  }
+  fn read(&mut self, seqno: u64, buf: &mut Vec<u8>) -> io::Result<bool> {
+    (**self).read(seqno, buf)
+  }
+}
+
+pub struct MemoryHistory {
+  msgs: VecDeque<Box<[u8]>>,
+  first: u64,
+  capacity: usize,
+}
+
+impl MemoryHistory {
+  pub fn new(capacity: usize) -> Self {
+    MemoryHistory{msgs: VecDeque::with_capacity(capacity.min(1 << 20)), first: 1, capacity: capacity.max(1)}
+  }
+}
+
+impl MessageHistory for MemoryHistory {
+  fn append(&mut self, msg: &[u8]) -> io::Result<u64> {
+    if self.msgs.len() == self.capacity {
+      self.msgs.pop_front();
+      self.first += 1;
+    }
+    self.msgs.push_back(msg.into());
+    Ok(self.first + self.msgs.len() as u64 - 1)
+  }
+  fn window(&self) -> Range<u64> {
+    self.first..self.first + self.msgs.len() as u64
+  }
+  fn read(&mut self, seqno: u64, buf: &mut Vec<u8>) -> io::Result<bool> {
+    if !self.window().contains(&seqno) {
+      return Ok(false);
+    }
+    buf.clear();
+    buf.extend_from_slice(&self.msgs[(seqno - self.first) as usize]);
+    Ok(true)
+  }
+}
+
+// don't bother compacting a file smaller than this
+const COMPACT_MIN : u64 = 1 << 20;
+
+// History in a file of length prefixed messages, the same framing as an
+// ITCH capture file, with only the offsets kept in memory. Dropped messages
+// stay in the file until they take up more room than the window does, then
+// the window is moved to the start of the file.
+pub struct FileHistory {
+  file: File,
+  offsets: VecDeque<u64>,
+  first: u64,
+  capacity: usize,
+  end: u64,
+}
+
+impl FileHistory {
+  // truncates the file if it exists
+  pub fn create<P: AsRef<Path>>(path: P, capacity: usize) -> io::Result<Self> {
+    let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+    Ok(FileHistory{file, offsets: VecDeque::new(), first: 1, capacity: capacity.max(1), end: 0})
+  }
+  // bytes of file in use, dropped messages included
+  pub fn file_len(&self) -> u64 {
+    self.end
+  }
+
+  fn compact(&mut self) -> io::Result<()> {
+    let start = self.offsets.front().copied().unwrap_or(self.end);
+    let mut live = vec![0u8; (self.end - start) as usize];
+    self.file.seek(SeekFrom::Start(start))?;
+    self.file.read_exact(&mut live)?;
+    self.file.seek(SeekFrom::Start(0))?;
+    self.file.write_all(&live)?;
+    self.file.set_len(live.len() as u64)?;
+    for offset in self.offsets.iter_mut() {
+      *offset -= start;
+    }
+    self.end -= start;
+    Ok(())
+  }
+}
+
+impl MessageHistory for FileHistory {
+  fn append(&mut self, msg: &[u8]) -> io::Result<u64> {
+    let len: u16 = msg.len().try_into().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "message too long for a length prefix"))?;
+    self.file.seek(SeekFrom::Start(self.end))?;
+    self.file.write_all(&len.to_be_bytes())?;
+    self.file.write_all(msg)?;
+    self.offsets.push_back(self.end);
+    self.end += 2 + msg.len() as u64;
+    if self.offsets.len() > self.capacity {
+      self.offsets.pop_front();
+      self.first += 1;
+      let dead = self.offsets[0];
+      if dead >= COMPACT_MIN && dead > self.end - dead {
+        self.compact()?;
+      }
+    }
+    Ok(self.first + self.offsets.len() as u64 - 1)
+  }
+  fn window(&self) -> Range<u64> {
+    self.first..self.first + self.offsets.len() as u64
+  }
+  fn read(&mut self, seqno: u64, buf: &mut Vec<u8>) -> io::Result<bool> {
+    if !self.window().contains(&seqno) {
+      return Ok(false);
+    }
+    let mut len = [0u8; 2];
+    self.file.seek(SeekFrom::Start(self.offsets[(seqno - self.first) as usize]))?;
+    self.file.read_exact(&mut len)?;
+    buf.resize(u16::from_be_bytes(len) as usize, 0);
+    self.file.read_exact(buf)?;
+    Ok(true)
+  }
+}
+
+#[test]
+fn history_windows() {
+  let path = std::env::temp_dir().join(format!("itch-history-{}", std::process::id()));
+  let histories: Vec<Box<dyn MessageHistory>> = vec![Box::new(MemoryHistory::new(10)), Box::new(FileHistory::create(&path, 10).unwrap())];
+  for mut history in histories {
+    assert_eq!(history.window(), 1..1);
+    for n in 1..=25u64 {
+      assert_eq!(history.append(&n.to_be_bytes()[..(n % 8) as usize + 1]).unwrap(), n);
+    }
+    assert_eq!(history.window(), 16..26);
+    let mut buf = Vec::new();
+    assert!(!history.read(15, &mut buf).unwrap());
+    assert!(!history.read(26, &mut buf).unwrap());
+    for n in 16..26u64 {
+      assert!(history.read(n, &mut buf).unwrap());
+      assert_eq!(buf, &n.to_be_bytes()[..(n % 8) as usize + 1]);
+    }
+  }
+
+  // enough dropped messages to compact the file, a few times over
+  let mut history = FileHistory::create(&path, 100).unwrap();
+  let msg = [7u8; 250];
+  for n in 1..=20_000u64 {
+    history.append(&msg[..(n % 200) as usize + 50]).unwrap();
+  }
+  assert!(history.file_len() < 2 * COMPACT_MIN + 100 * 252);
+  assert_eq!(history.window(), 19_901..20_001);
+  let mut buf = Vec::new();
+  for n in history.window() {
+    assert!(history.read(n, &mut buf).unwrap());
+    assert_eq!(buf.len(), (n % 200) as usize + 50);
+  }
+  std::fs::remove_file(&path).unwrap();
+}
@@ -1,6 +1,13 @@
 use std::fmt;
 use std::io::{Cursor, Read, Write};
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use crate::context::MessageContext;
+use crate::encode::Encode;
+use crate::error::{DecodeError, UnknownCode};
+use crate::message::{FieldValue, FieldVisitor, ItchMessageType, LengthCheck};
+use crate::alpha::{IssueSubType, Mpid, Session, Symbol};
+use crate::price::{Price4, Price8};
+use crate::timestamp::ItchTimestamp;
 
 pub fn u48_to_u64(num: &[u8;6]) -> u64 {
   ((num[0] as u64) << 40) +
@@ -8,2843 +15,8322 @@ pub fn u48_to_u64(num: &[u8;6]) -> u64 {
   ((num[2] as u64) << 24) +
   ((num[3] as u64) << 16) +
   ((num[4] as u64) << 8) +
-  ((num[5] as u64) << 0)
+  (num[5] as u64)
 }
 
+// Constants
+pub const PRICE4_SCALE : u32 = 10000;
+pub const MAX_PRICE4 : u32 = 2000000000;
+pub const PRICE8_SCALE : u64 = 100000000;
+pub const MAX_PRICE8 : u64 = 20000000000000;
+
 // Enums
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eSystemEvent(pub u8);
-#[allow(non_upper_case_globals)]
+#[repr(u8)]
+pub enum eSystemEvent {
+  Start_of_Messages = b'O',
+  Start_of_System_hours = b'S',
+  Start_of_Market_hours = b'Q',
+  End_of_Market_hours = b'M',
+  End_of_System_hours = b'E',
+  End_of_Messages = b'C',
+  Unknown(u8) = 0,
+} // eSystemEvent
+
 impl eSystemEvent {
-  pub const Start_of_Messages : u8 = 'O' as u8;
-  pub const Start_of_System_hours : u8 = 'S' as u8;
-  pub const Start_of_Market_hours : u8 = 'Q' as u8;
-  pub const End_of_Market_hours : u8 = 'M' as u8;
-  pub const End_of_System_hours : u8 = 'E' as u8;
-  pub const End_of_Messages : u8 = 'C' as u8;
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'O' => eSystemEvent::Start_of_Messages,
+      b'S' => eSystemEvent::Start_of_System_hours,
+      b'Q' => eSystemEvent::Start_of_Market_hours,
+      b'M' => eSystemEvent::End_of_Market_hours,
+      b'E' => eSystemEvent::End_of_System_hours,
+      b'C' => eSystemEvent::End_of_Messages,
+      _ => eSystemEvent::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eSystemEvent::Start_of_Messages => b'O',
+      eSystemEvent::Start_of_System_hours => b'S',
+      eSystemEvent::Start_of_Market_hours => b'Q',
+      eSystemEvent::End_of_Market_hours => b'M',
+      eSystemEvent::End_of_System_hours => b'E',
+      eSystemEvent::End_of_Messages => b'C',
+      eSystemEvent::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eSystemEvent::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eSystemEvent::Start_of_Messages => "Start_of_Messages",
+      eSystemEvent::Start_of_System_hours => "Start_of_System_hours",
+      eSystemEvent::Start_of_Market_hours => "Start_of_Market_hours",
+      eSystemEvent::End_of_Market_hours => "End_of_Market_hours",
+      eSystemEvent::End_of_System_hours => "End_of_System_hours",
+      eSystemEvent::End_of_Messages => "End_of_Messages",
+      eSystemEvent::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Start_of_Messages" => Some(eSystemEvent::Start_of_Messages),
+      "Start_of_System_hours" => Some(eSystemEvent::Start_of_System_hours),
+      "Start_of_Market_hours" => Some(eSystemEvent::Start_of_Market_hours),
+      "End_of_Market_hours" => Some(eSystemEvent::End_of_Market_hours),
+      "End_of_System_hours" => Some(eSystemEvent::End_of_System_hours),
+      "End_of_Messages" => Some(eSystemEvent::End_of_Messages),
+      _ => None,
+    }
+  }
 } // eSystemEvent
 
-impl fmt::Display for eSystemEvent {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eSystemEvent::Start_of_Messages => "'O' (Start of Messages)".to_string(),
-      eSystemEvent::Start_of_System_hours => "'S' (Start of System hours)".to_string(),
-      eSystemEvent::Start_of_Market_hours => "'Q' (Start of Market hours)".to_string(),
-      eSystemEvent::End_of_Market_hours => "'M' (End of Market hours)".to_string(),
-      eSystemEvent::End_of_System_hours => "'E' (End of System hours)".to_string(),
-      eSystemEvent::End_of_Messages => "'C' (End of Messages)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl Default for eSystemEvent {
+  fn default() -> Self { eSystemEvent::Unknown(0) }
+}
+
+impl TryFrom<u8> for eSystemEvent {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eSystemEvent::Unknown(code) => Err(UnknownCode { enum_name: "eSystemEvent", code }),
+      known => Ok(known),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eMarketCategory(pub u8);
-#[allow(non_upper_case_globals)]
-impl eMarketCategory {
-  pub const Nasdaq_Global_Select_MarketSM : u8 = 'Q' as u8;
-  pub const Nasdaq_Global_MarketSM : u8 = 'G' as u8;
-  pub const Nasdaq_Capital_Market : u8 = 'S' as u8;
-  pub const New_York_Stock_Exchange : u8 = 'N' as u8;
-  pub const NYSE_MKT : u8 = 'A' as u8;
-  pub const NYSE_Arca : u8 = 'P' as u8;
-  pub const BATS_Z_Exchange : u8 = 'Z' as u8;
-  pub const Investors_Exchange_LLC : u8 = 'V' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eMarketCategory
+impl From<eSystemEvent> for u8 {
+  fn from(value: eSystemEvent) -> u8 { value.code() }
+}
 
-impl fmt::Display for eMarketCategory {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eMarketCategory::Nasdaq_Global_Select_MarketSM => "'Q' (Nasdaq Global Select MarketSM)".to_string(),
-      eMarketCategory::Nasdaq_Global_MarketSM => "'G' (Nasdaq Global MarketSM)".to_string(),
-      eMarketCategory::Nasdaq_Capital_Market => "'S' (Nasdaq Capital Market)".to_string(),
-      eMarketCategory::New_York_Stock_Exchange => "'N' (New York Stock Exchange)".to_string(),
-      eMarketCategory::NYSE_MKT => "'A' (NYSE MKT)".to_string(),
-      eMarketCategory::NYSE_Arca => "'P' (NYSE Arca)".to_string(),
-      eMarketCategory::BATS_Z_Exchange => "'Z' (BATS Z Exchange)".to_string(),
-      eMarketCategory::Investors_Exchange_LLC => "'V' (Investors Exchange LLC)".to_string(),
-      eMarketCategory::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+#[cfg(feature = "serde")]
+impl serde::Serialize for eSystemEvent {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eSystemEvent::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eFinancialStatusIndicator(pub u8);
-#[allow(non_upper_case_globals)]
-impl eFinancialStatusIndicator {
-  pub const Deficient : u8 = 'D' as u8;
-  pub const Delinquent : u8 = 'E' as u8;
-  pub const Bankrupt : u8 = 'Q' as u8;
-  pub const Suspended : u8 = 'S' as u8;
-  pub const Deficient_and_Bankrupt : u8 = 'G' as u8;
-  pub const Deficient_and_Delinquent : u8 = 'H' as u8;
-  pub const Delinquent_and_Bankrupt : u8 = 'J' as u8;
-  pub const Deficient_Delinquent_and_Bankrupt : u8 = 'K' as u8;
-  pub const Creations_and_or_Redemptions_Suspended : u8 = 'C' as u8;
-  pub const Normal : u8 = 'N' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eFinancialStatusIndicator
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eSystemEvent {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eSystemEvent '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for eFinancialStatusIndicator {
+impl fmt::Display for eSystemEvent {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eFinancialStatusIndicator::Deficient => "'D' (Deficient)".to_string(),
-      eFinancialStatusIndicator::Delinquent => "'E' (Delinquent)".to_string(),
-      eFinancialStatusIndicator::Bankrupt => "'Q' (Bankrupt)".to_string(),
-      eFinancialStatusIndicator::Suspended => "'S' (Suspended)".to_string(),
-      eFinancialStatusIndicator::Deficient_and_Bankrupt => "'G' (Deficient and Bankrupt)".to_string(),
-      eFinancialStatusIndicator::Deficient_and_Delinquent => "'H' (Deficient and Delinquent)".to_string(),
-      eFinancialStatusIndicator::Delinquent_and_Bankrupt => "'J' (Delinquent and Bankrupt)".to_string(),
-      eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt => "'K' (Deficient Delinquent and Bankrupt)".to_string(),
-      eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended => "'C' (Creations and or Redemptions Suspended)".to_string(),
-      eFinancialStatusIndicator::Normal => "'N' (Normal)".to_string(),
-      eFinancialStatusIndicator::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eSystemEvent::Start_of_Messages => "'O' (Start of Messages)",
+      eSystemEvent::Start_of_System_hours => "'S' (Start of System hours)",
+      eSystemEvent::Start_of_Market_hours => "'Q' (Start of Market hours)",
+      eSystemEvent::End_of_Market_hours => "'M' (End of Market hours)",
+      eSystemEvent::End_of_System_hours => "'E' (End of System hours)",
+      eSystemEvent::End_of_Messages => "'C' (End of Messages)",
+      eSystemEvent::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eRoundLotsOnly(pub u8);
-#[allow(non_upper_case_globals)]
-impl eRoundLotsOnly {
-  pub const Round_Lots_Only : u8 = 'Y' as u8;
-  pub const Accepts_Round_Lots : u8 = 'N' as u8;
-} // eRoundLotsOnly
+#[repr(u8)]
+pub enum eMarketCategory {
+  Nasdaq_Global_Select_MarketSM = b'Q',
+  Nasdaq_Global_MarketSM = b'G',
+  Nasdaq_Capital_Market = b'S',
+  New_York_Stock_Exchange = b'N',
+  NYSE_MKT = b'A',
+  NYSE_Arca = b'P',
+  BATS_Z_Exchange = b'Z',
+  Investors_Exchange_LLC = b'V',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eMarketCategory
 
-impl fmt::Display for eRoundLotsOnly {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eRoundLotsOnly::Round_Lots_Only => "'Y' (Round Lots Only)".to_string(),
-      eRoundLotsOnly::Accepts_Round_Lots => "'N' (Accepts Round Lots)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eMarketCategory {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Q' => eMarketCategory::Nasdaq_Global_Select_MarketSM,
+      b'G' => eMarketCategory::Nasdaq_Global_MarketSM,
+      b'S' => eMarketCategory::Nasdaq_Capital_Market,
+      b'N' => eMarketCategory::New_York_Stock_Exchange,
+      b'A' => eMarketCategory::NYSE_MKT,
+      b'P' => eMarketCategory::NYSE_Arca,
+      b'Z' => eMarketCategory::BATS_Z_Exchange,
+      b'V' => eMarketCategory::Investors_Exchange_LLC,
+      b' ' => eMarketCategory::Not_available,
+      _ => eMarketCategory::Unknown(code),
+    }
   }
-}
+  pub fn code(&self) -> u8 {
+    match *self {
+      eMarketCategory::Nasdaq_Global_Select_MarketSM => b'Q',
+      eMarketCategory::Nasdaq_Global_MarketSM => b'G',
+      eMarketCategory::Nasdaq_Capital_Market => b'S',
+      eMarketCategory::New_York_Stock_Exchange => b'N',
+      eMarketCategory::NYSE_MKT => b'A',
+      eMarketCategory::NYSE_Arca => b'P',
+      eMarketCategory::BATS_Z_Exchange => b'Z',
+      eMarketCategory::Investors_Exchange_LLC => b'V',
+      eMarketCategory::Not_available => b' ',
+      eMarketCategory::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eMarketCategory::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eMarketCategory::Nasdaq_Global_Select_MarketSM => "Nasdaq_Global_Select_MarketSM",
+      eMarketCategory::Nasdaq_Global_MarketSM => "Nasdaq_Global_MarketSM",
+      eMarketCategory::Nasdaq_Capital_Market => "Nasdaq_Capital_Market",
+      eMarketCategory::New_York_Stock_Exchange => "New_York_Stock_Exchange",
+      eMarketCategory::NYSE_MKT => "NYSE_MKT",
+      eMarketCategory::NYSE_Arca => "NYSE_Arca",
+      eMarketCategory::BATS_Z_Exchange => "BATS_Z_Exchange",
+      eMarketCategory::Investors_Exchange_LLC => "Investors_Exchange_LLC",
+      eMarketCategory::Not_available => "Not_available",
+      eMarketCategory::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Nasdaq_Global_Select_MarketSM" => Some(eMarketCategory::Nasdaq_Global_Select_MarketSM),
+      "Nasdaq_Global_MarketSM" => Some(eMarketCategory::Nasdaq_Global_MarketSM),
+      "Nasdaq_Capital_Market" => Some(eMarketCategory::Nasdaq_Capital_Market),
+      "New_York_Stock_Exchange" => Some(eMarketCategory::New_York_Stock_Exchange),
+      "NYSE_MKT" => Some(eMarketCategory::NYSE_MKT),
+      "NYSE_Arca" => Some(eMarketCategory::NYSE_Arca),
+      "BATS_Z_Exchange" => Some(eMarketCategory::BATS_Z_Exchange),
+      "Investors_Exchange_LLC" => Some(eMarketCategory::Investors_Exchange_LLC),
+      "Not_available" => Some(eMarketCategory::Not_available),
+      _ => None,
+    }
+  }
+} // eMarketCategory
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eAuthenticity(pub u8);
-#[allow(non_upper_case_globals)]
-impl eAuthenticity {
-  pub const Production : u8 = 'P' as u8;
-  pub const Test : u8 = 'T' as u8;
-} // eAuthenticity
+impl Default for eMarketCategory {
+  fn default() -> Self { eMarketCategory::Unknown(0) }
+}
 
-impl fmt::Display for eAuthenticity {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eAuthenticity::Production => "'P' (Production)".to_string(),
-      eAuthenticity::Test => "'T' (Test)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl TryFrom<u8> for eMarketCategory {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eMarketCategory::Unknown(code) => Err(UnknownCode { enum_name: "eMarketCategory", code }),
+      known => Ok(known),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eShortSaleThresholdIndicator(pub u8);
-#[allow(non_upper_case_globals)]
-impl eShortSaleThresholdIndicator {
-  pub const Restricted : u8 = 'Y' as u8;
-  pub const Not_Restricted : u8 = 'N' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eShortSaleThresholdIndicator
+impl From<eMarketCategory> for u8 {
+  fn from(value: eMarketCategory) -> u8 { value.code() }
+}
 
-impl fmt::Display for eShortSaleThresholdIndicator {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eShortSaleThresholdIndicator::Restricted => "'Y' (Restricted)".to_string(),
-      eShortSaleThresholdIndicator::Not_Restricted => "'N' (Not Restricted)".to_string(),
-      eShortSaleThresholdIndicator::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+#[cfg(feature = "serde")]
+impl serde::Serialize for eMarketCategory {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eMarketCategory::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eIPOFlag(pub u8);
-#[allow(non_upper_case_globals)]
-impl eIPOFlag {
-  pub const New_IPO_Security : u8 = 'Y' as u8;
-  pub const Not_A_New_IPO_Security : u8 = 'N' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eIPOFlag
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eMarketCategory {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eMarketCategory '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for eIPOFlag {
+impl fmt::Display for eMarketCategory {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eIPOFlag::New_IPO_Security => "'Y' (New IPO Security)".to_string(),
-      eIPOFlag::Not_A_New_IPO_Security => "'N' (Not A New IPO Security)".to_string(),
-      eIPOFlag::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eMarketCategory::Nasdaq_Global_Select_MarketSM => "'Q' (Nasdaq Global Select MarketSM)",
+      eMarketCategory::Nasdaq_Global_MarketSM => "'G' (Nasdaq Global MarketSM)",
+      eMarketCategory::Nasdaq_Capital_Market => "'S' (Nasdaq Capital Market)",
+      eMarketCategory::New_York_Stock_Exchange => "'N' (New York Stock Exchange)",
+      eMarketCategory::NYSE_MKT => "'A' (NYSE MKT)",
+      eMarketCategory::NYSE_Arca => "'P' (NYSE Arca)",
+      eMarketCategory::BATS_Z_Exchange => "'Z' (BATS Z Exchange)",
+      eMarketCategory::Investors_Exchange_LLC => "'V' (Investors Exchange LLC)",
+      eMarketCategory::Not_available => "' ' (Not available)",
+      eMarketCategory::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eLULDReferencePriceTier(pub u8);
-#[allow(non_upper_case_globals)]
-impl eLULDReferencePriceTier {
-  pub const Tier_1_NMS_Stocks_and_select_ETPs : u8 = '1' as u8;
-  pub const Tier_2_NMSStocks : u8 = '2' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eLULDReferencePriceTier
+#[repr(u8)]
+pub enum eFinancialStatusIndicator {
+  Deficient = b'D',
+  Delinquent = b'E',
+  Bankrupt = b'Q',
+  Suspended = b'S',
+  Deficient_and_Bankrupt = b'G',
+  Deficient_and_Delinquent = b'H',
+  Delinquent_and_Bankrupt = b'J',
+  Deficient_Delinquent_and_Bankrupt = b'K',
+  Creations_and_or_Redemptions_Suspended = b'C',
+  Normal = b'N',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eFinancialStatusIndicator
 
-impl fmt::Display for eLULDReferencePriceTier {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs => "'1' (Tier 1 NMS Stocks and select ETPs)".to_string(),
-      eLULDReferencePriceTier::Tier_2_NMSStocks => "'2' (Tier 2 NMSStocks)".to_string(),
-      eLULDReferencePriceTier::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eFinancialStatusIndicator {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'D' => eFinancialStatusIndicator::Deficient,
+      b'E' => eFinancialStatusIndicator::Delinquent,
+      b'Q' => eFinancialStatusIndicator::Bankrupt,
+      b'S' => eFinancialStatusIndicator::Suspended,
+      b'G' => eFinancialStatusIndicator::Deficient_and_Bankrupt,
+      b'H' => eFinancialStatusIndicator::Deficient_and_Delinquent,
+      b'J' => eFinancialStatusIndicator::Delinquent_and_Bankrupt,
+      b'K' => eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt,
+      b'C' => eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended,
+      b'N' => eFinancialStatusIndicator::Normal,
+      b' ' => eFinancialStatusIndicator::Not_available,
+      _ => eFinancialStatusIndicator::Unknown(code),
+    }
   }
-}
+  pub fn code(&self) -> u8 {
+    match *self {
+      eFinancialStatusIndicator::Deficient => b'D',
+      eFinancialStatusIndicator::Delinquent => b'E',
+      eFinancialStatusIndicator::Bankrupt => b'Q',
+      eFinancialStatusIndicator::Suspended => b'S',
+      eFinancialStatusIndicator::Deficient_and_Bankrupt => b'G',
+      eFinancialStatusIndicator::Deficient_and_Delinquent => b'H',
+      eFinancialStatusIndicator::Delinquent_and_Bankrupt => b'J',
+      eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt => b'K',
+      eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended => b'C',
+      eFinancialStatusIndicator::Normal => b'N',
+      eFinancialStatusIndicator::Not_available => b' ',
+      eFinancialStatusIndicator::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eFinancialStatusIndicator::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eFinancialStatusIndicator::Deficient => "Deficient",
+      eFinancialStatusIndicator::Delinquent => "Delinquent",
+      eFinancialStatusIndicator::Bankrupt => "Bankrupt",
+      eFinancialStatusIndicator::Suspended => "Suspended",
+      eFinancialStatusIndicator::Deficient_and_Bankrupt => "Deficient_and_Bankrupt",
+      eFinancialStatusIndicator::Deficient_and_Delinquent => "Deficient_and_Delinquent",
+      eFinancialStatusIndicator::Delinquent_and_Bankrupt => "Delinquent_and_Bankrupt",
+      eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt => "Deficient_Delinquent_and_Bankrupt",
+      eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended => "Creations_and_or_Redemptions_Suspended",
+      eFinancialStatusIndicator::Normal => "Normal",
+      eFinancialStatusIndicator::Not_available => "Not_available",
+      eFinancialStatusIndicator::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Deficient" => Some(eFinancialStatusIndicator::Deficient),
+      "Delinquent" => Some(eFinancialStatusIndicator::Delinquent),
+      "Bankrupt" => Some(eFinancialStatusIndicator::Bankrupt),
+      "Suspended" => Some(eFinancialStatusIndicator::Suspended),
+      "Deficient_and_Bankrupt" => Some(eFinancialStatusIndicator::Deficient_and_Bankrupt),
+      "Deficient_and_Delinquent" => Some(eFinancialStatusIndicator::Deficient_and_Delinquent),
+      "Delinquent_and_Bankrupt" => Some(eFinancialStatusIndicator::Delinquent_and_Bankrupt),
+      "Deficient_Delinquent_and_Bankrupt" => Some(eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt),
+      "Creations_and_or_Redemptions_Suspended" => Some(eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended),
+      "Normal" => Some(eFinancialStatusIndicator::Normal),
+      "Not_available" => Some(eFinancialStatusIndicator::Not_available),
+      _ => None,
+    }
+  }
+} // eFinancialStatusIndicator
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eETPFlag(pub u8);
-#[allow(non_upper_case_globals)]
-impl eETPFlag {
-  pub const Instrument_is_an_ETP : u8 = 'Y' as u8;
-  pub const Instrument_is_not_an_ETP : u8 = 'N' as u8;
-  pub const Not_available : u8 = ' ' as u8;
-} // eETPFlag
+impl Default for eFinancialStatusIndicator {
+  fn default() -> Self { eFinancialStatusIndicator::Unknown(0) }
+}
 
-impl fmt::Display for eETPFlag {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eETPFlag::Instrument_is_an_ETP => "'Y' (Instrument is an ETP)".to_string(),
-      eETPFlag::Instrument_is_not_an_ETP => "'N' (Instrument is not an ETP)".to_string(),
-      eETPFlag::Not_available => "' ' (Not available)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl TryFrom<u8> for eFinancialStatusIndicator {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eFinancialStatusIndicator::Unknown(code) => Err(UnknownCode { enum_name: "eFinancialStatusIndicator", code }),
+      known => Ok(known),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eInverseIndicator(pub u8);
-#[allow(non_upper_case_globals)]
-impl eInverseIndicator {
-  pub const ETP_is_an_Inverse_ETP : u8 = 'Y' as u8;
-  pub const ETP_is_not_an_Inverse_ETP : u8 = 'N' as u8;
-} // eInverseIndicator
+impl From<eFinancialStatusIndicator> for u8 {
+  fn from(value: eFinancialStatusIndicator) -> u8 { value.code() }
+}
 
-impl fmt::Display for eInverseIndicator {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eInverseIndicator::ETP_is_an_Inverse_ETP => "'Y' (ETP is an Inverse ETP)".to_string(),
-      eInverseIndicator::ETP_is_not_an_Inverse_ETP => "'N' (ETP is not an Inverse ETP)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+#[cfg(feature = "serde")]
+impl serde::Serialize for eFinancialStatusIndicator {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eFinancialStatusIndicator::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eTradingState(pub u8);
-#[allow(non_upper_case_globals)]
-impl eTradingState {
-  pub const Halted : u8 = 'H' as u8;
-  pub const Paused : u8 = 'P' as u8;
-  pub const Quotation_only : u8 = 'Q' as u8;
-  pub const Trading : u8 = 'T' as u8;
-} // eTradingState
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eFinancialStatusIndicator {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eFinancialStatusIndicator '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for eTradingState {
+impl fmt::Display for eFinancialStatusIndicator {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eTradingState::Halted => "'H' (Halted)".to_string(),
-      eTradingState::Paused => "'P' (Paused)".to_string(),
-      eTradingState::Quotation_only => "'Q' (Quotation only)".to_string(),
-      eTradingState::Trading => "'T' (Trading)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eFinancialStatusIndicator::Deficient => "'D' (Deficient)",
+      eFinancialStatusIndicator::Delinquent => "'E' (Delinquent)",
+      eFinancialStatusIndicator::Bankrupt => "'Q' (Bankrupt)",
+      eFinancialStatusIndicator::Suspended => "'S' (Suspended)",
+      eFinancialStatusIndicator::Deficient_and_Bankrupt => "'G' (Deficient and Bankrupt)",
+      eFinancialStatusIndicator::Deficient_and_Delinquent => "'H' (Deficient and Delinquent)",
+      eFinancialStatusIndicator::Delinquent_and_Bankrupt => "'J' (Delinquent and Bankrupt)",
+      eFinancialStatusIndicator::Deficient_Delinquent_and_Bankrupt => "'K' (Deficient Delinquent and Bankrupt)",
+      eFinancialStatusIndicator::Creations_and_or_Redemptions_Suspended => "'C' (Creations and or Redemptions Suspended)",
+      eFinancialStatusIndicator::Normal => "'N' (Normal)",
+      eFinancialStatusIndicator::Not_available => "' ' (Not available)",
+      eFinancialStatusIndicator::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eRegSHOAction(pub u8);
-#[allow(non_upper_case_globals)]
-impl eRegSHOAction {
-  pub const No_price_test_in_place : u8 = '0' as u8;
-  pub const Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect : u8 = '1' as u8;
-  pub const Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect : u8 = '2' as u8;
-} // eRegSHOAction
+#[repr(u8)]
+pub enum eRoundLotsOnly {
+  Round_Lots_Only = b'Y',
+  Accepts_Round_Lots = b'N',
+  Unknown(u8) = 0,
+} // eRoundLotsOnly
 
-impl fmt::Display for eRegSHOAction {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eRegSHOAction::No_price_test_in_place => "'0' (No price test in place)".to_string(),
-      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect => "'1' (Reg SHO Short Sale Price Test Restriction in effect)".to_string(),
-      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect => "'2' (Reg SHO Short Sale Price Test Restriction remains in effect)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eRoundLotsOnly {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => eRoundLotsOnly::Round_Lots_Only,
+      b'N' => eRoundLotsOnly::Accepts_Round_Lots,
+      _ => eRoundLotsOnly::Unknown(code),
+    }
   }
-}
+  pub fn code(&self) -> u8 {
+    match *self {
+      eRoundLotsOnly::Round_Lots_Only => b'Y',
+      eRoundLotsOnly::Accepts_Round_Lots => b'N',
+      eRoundLotsOnly::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eRoundLotsOnly::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eRoundLotsOnly::Round_Lots_Only => "Round_Lots_Only",
+      eRoundLotsOnly::Accepts_Round_Lots => "Accepts_Round_Lots",
+      eRoundLotsOnly::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Round_Lots_Only" => Some(eRoundLotsOnly::Round_Lots_Only),
+      "Accepts_Round_Lots" => Some(eRoundLotsOnly::Accepts_Round_Lots),
+      _ => None,
+    }
+  }
+} // eRoundLotsOnly
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct ePrimaryMarketMaker(pub u8);
-#[allow(non_upper_case_globals)]
-impl ePrimaryMarketMaker {
-  pub const primary_market_maker : u8 = 'Y' as u8;
-  pub const non_primary_market_maker : u8 = 'N' as u8;
-} // ePrimaryMarketMaker
+impl Default for eRoundLotsOnly {
+  fn default() -> Self { eRoundLotsOnly::Unknown(0) }
+}
 
-impl fmt::Display for ePrimaryMarketMaker {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      ePrimaryMarketMaker::primary_market_maker => "'Y' (primary market maker)".to_string(),
-      ePrimaryMarketMaker::non_primary_market_maker => "'N' (non primary market maker)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl TryFrom<u8> for eRoundLotsOnly {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eRoundLotsOnly::Unknown(code) => Err(UnknownCode { enum_name: "eRoundLotsOnly", code }),
+      known => Ok(known),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eMarketMakerMode(pub u8);
-#[allow(non_upper_case_globals)]
-impl eMarketMakerMode {
-  pub const normal : u8 = 'N' as u8;
-  pub const passive : u8 = 'P' as u8;
-  pub const syndicate : u8 = 'S' as u8;
-  pub const pre_syndicate : u8 = 'R' as u8;
-  pub const penalty : u8 = 'L' as u8;
-} // eMarketMakerMode
+impl From<eRoundLotsOnly> for u8 {
+  fn from(value: eRoundLotsOnly) -> u8 { value.code() }
+}
 
-impl fmt::Display for eMarketMakerMode {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eMarketMakerMode::normal => "'N' (normal)".to_string(),
-      eMarketMakerMode::passive => "'P' (passive)".to_string(),
-      eMarketMakerMode::syndicate => "'S' (syndicate)".to_string(),
-      eMarketMakerMode::pre_syndicate => "'R' (pre syndicate)".to_string(),
-      eMarketMakerMode::penalty => "'L' (penalty)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+#[cfg(feature = "serde")]
+impl serde::Serialize for eRoundLotsOnly {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eRoundLotsOnly::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eMarketParticipantState(pub u8);
-#[allow(non_upper_case_globals)]
-impl eMarketParticipantState {
-  pub const Active : u8 = 'A' as u8;
-  pub const Excused_Withdrawn : u8 = 'E' as u8;
-  pub const Withdrawn : u8 = 'W' as u8;
-  pub const Suspended : u8 = 'S' as u8;
-  pub const Deleted : u8 = 'D' as u8;
-} // eMarketParticipantState
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eRoundLotsOnly {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eRoundLotsOnly '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for eMarketParticipantState {
+impl fmt::Display for eRoundLotsOnly {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eMarketParticipantState::Active => "'A' (Active)".to_string(),
-      eMarketParticipantState::Excused_Withdrawn => "'E' (Excused Withdrawn)".to_string(),
-      eMarketParticipantState::Withdrawn => "'W' (Withdrawn)".to_string(),
-      eMarketParticipantState::Suspended => "'S' (Suspended)".to_string(),
-      eMarketParticipantState::Deleted => "'D' (Deleted)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eRoundLotsOnly::Round_Lots_Only => "'Y' (Round Lots Only)",
+      eRoundLotsOnly::Accepts_Round_Lots => "'N' (Accepts Round Lots)",
+      eRoundLotsOnly::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eBreachedLevel(pub u8);
-#[allow(non_upper_case_globals)]
-impl eBreachedLevel {
-  pub const Level_1 : u8 = '1' as u8;
-  pub const Level_2 : u8 = '2' as u8;
-  pub const Level_3 : u8 = '3' as u8;
-} // eBreachedLevel
+#[repr(u8)]
+pub enum eAuthenticity {
+  Production = b'P',
+  Test = b'T',
+  Unknown(u8) = 0,
+} // eAuthenticity
 
-impl fmt::Display for eBreachedLevel {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eBreachedLevel::Level_1 => "'1' (Level 1)".to_string(),
-      eBreachedLevel::Level_2 => "'2' (Level 2)".to_string(),
-      eBreachedLevel::Level_3 => "'3' (Level 3)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eAuthenticity {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'P' => eAuthenticity::Production,
+      b'T' => eAuthenticity::Test,
+      _ => eAuthenticity::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eAuthenticity::Production => b'P',
+      eAuthenticity::Test => b'T',
+      eAuthenticity::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eAuthenticity::Unknown(_))
   }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eAuthenticity::Production => "Production",
+      eAuthenticity::Test => "Test",
+      eAuthenticity::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Production" => Some(eAuthenticity::Production),
+      "Test" => Some(eAuthenticity::Test),
+      _ => None,
+    }
+  }
+} // eAuthenticity
+
+impl Default for eAuthenticity {
+  fn default() -> Self { eAuthenticity::Unknown(0) }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eIPOQuotationReleaseQualifier(pub u8);
-#[allow(non_upper_case_globals)]
-impl eIPOQuotationReleaseQualifier {
-  pub const Anticipated_Quotation_Release_Time : u8 = 'A' as u8;
-  pub const IPO_Release_Canceled_Postponed : u8 = 'C' as u8;
-} // eIPOQuotationReleaseQualifier
+impl TryFrom<u8> for eAuthenticity {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eAuthenticity::Unknown(code) => Err(UnknownCode { enum_name: "eAuthenticity", code }),
+      known => Ok(known),
+    }
+  }
+}
 
-impl fmt::Display for eIPOQuotationReleaseQualifier {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time => "'A' (Anticipated Quotation Release Time)".to_string(),
-      eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed => "'C' (IPO Release Canceled Postponed)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl From<eAuthenticity> for u8 {
+  fn from(value: eAuthenticity) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eAuthenticity {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eAuthenticity::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eMarketCode(pub u8);
-#[allow(non_upper_case_globals)]
-impl eMarketCode {
-  pub const Nasdaq : u8 = 'Q' as u8;
-  pub const BX : u8 = 'B' as u8;
-  pub const PSX : u8 = 'X' as u8;
-} // eMarketCode
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eAuthenticity {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eAuthenticity '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for eMarketCode {
+impl fmt::Display for eAuthenticity {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eMarketCode::Nasdaq => "'Q' (Nasdaq)".to_string(),
-      eMarketCode::BX => "'B' (BX)".to_string(),
-      eMarketCode::PSX => "'X' (PSX)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eAuthenticity::Production => "'P' (Production)",
+      eAuthenticity::Test => "'T' (Test)",
+      eAuthenticity::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eOperationalHaltAction(pub u8);
-#[allow(non_upper_case_globals)]
-impl eOperationalHaltAction {
-  pub const Halted : u8 = 'H' as u8;
-  pub const Trading : u8 = 'T' as u8;
-} // eOperationalHaltAction
+#[repr(u8)]
+pub enum eShortSaleThresholdIndicator {
+  Restricted = b'Y',
+  Not_Restricted = b'N',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eShortSaleThresholdIndicator
 
-impl fmt::Display for eOperationalHaltAction {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eOperationalHaltAction::Halted => "'H' (Halted)".to_string(),
-      eOperationalHaltAction::Trading => "'T' (Trading)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eShortSaleThresholdIndicator {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => eShortSaleThresholdIndicator::Restricted,
+      b'N' => eShortSaleThresholdIndicator::Not_Restricted,
+      b' ' => eShortSaleThresholdIndicator::Not_available,
+      _ => eShortSaleThresholdIndicator::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eShortSaleThresholdIndicator::Restricted => b'Y',
+      eShortSaleThresholdIndicator::Not_Restricted => b'N',
+      eShortSaleThresholdIndicator::Not_available => b' ',
+      eShortSaleThresholdIndicator::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eShortSaleThresholdIndicator::Unknown(_))
   }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eShortSaleThresholdIndicator::Restricted => "Restricted",
+      eShortSaleThresholdIndicator::Not_Restricted => "Not_Restricted",
+      eShortSaleThresholdIndicator::Not_available => "Not_available",
+      eShortSaleThresholdIndicator::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Restricted" => Some(eShortSaleThresholdIndicator::Restricted),
+      "Not_Restricted" => Some(eShortSaleThresholdIndicator::Not_Restricted),
+      "Not_available" => Some(eShortSaleThresholdIndicator::Not_available),
+      _ => None,
+    }
+  }
+} // eShortSaleThresholdIndicator
+
+impl Default for eShortSaleThresholdIndicator {
+  fn default() -> Self { eShortSaleThresholdIndicator::Unknown(0) }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eBuySellIndicator(pub u8);
-#[allow(non_upper_case_globals)]
-impl eBuySellIndicator {
-  pub const Buy_Order : u8 = 'B' as u8;
-  pub const Sell_Order : u8 = 'S' as u8;
-} // eBuySellIndicator
+impl TryFrom<u8> for eShortSaleThresholdIndicator {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eShortSaleThresholdIndicator::Unknown(code) => Err(UnknownCode { enum_name: "eShortSaleThresholdIndicator", code }),
+      known => Ok(known),
+    }
+  }
+}
 
-impl fmt::Display for eBuySellIndicator {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eBuySellIndicator::Buy_Order => "'B' (Buy Order)".to_string(),
-      eBuySellIndicator::Sell_Order => "'S' (Sell Order)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl From<eShortSaleThresholdIndicator> for u8 {
+  fn from(value: eShortSaleThresholdIndicator) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eShortSaleThresholdIndicator {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eShortSaleThresholdIndicator::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct ePrintable(pub u8);
-#[allow(non_upper_case_globals)]
-impl ePrintable {
-  pub const Non_Printable : u8 = 'N' as u8;
-  pub const Printable : u8 = 'Y' as u8;
-} // ePrintable
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eShortSaleThresholdIndicator {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eShortSaleThresholdIndicator '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for ePrintable {
+impl fmt::Display for eShortSaleThresholdIndicator {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      ePrintable::Non_Printable => "'N' (Non Printable)".to_string(),
-      ePrintable::Printable => "'Y' (Printable)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eShortSaleThresholdIndicator::Restricted => "'Y' (Restricted)",
+      eShortSaleThresholdIndicator::Not_Restricted => "'N' (Not Restricted)",
+      eShortSaleThresholdIndicator::Not_available => "' ' (Not available)",
+      eShortSaleThresholdIndicator::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eCrossType(pub u8);
-#[allow(non_upper_case_globals)]
-impl eCrossType {
-  pub const Nasdaq_Opening_Cross : u8 = 'O' as u8;
-  pub const Nasdaq_Closing_Cross : u8 = 'C' as u8;
-  pub const Cross_for_IPO_and_halted_paused_securities : u8 = 'H' as u8;
-  pub const Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross : u8 = 'I' as u8;
-} // eCrossType
+#[repr(u8)]
+pub enum eIPOFlag {
+  New_IPO_Security = b'Y',
+  Not_A_New_IPO_Security = b'N',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eIPOFlag
 
-impl fmt::Display for eCrossType {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eCrossType::Nasdaq_Opening_Cross => "'O' (Nasdaq Opening Cross)".to_string(),
-      eCrossType::Nasdaq_Closing_Cross => "'C' (Nasdaq Closing Cross)".to_string(),
-      eCrossType::Cross_for_IPO_and_halted_paused_securities => "'H' (Cross for IPO and halted paused securities)".to_string(),
-      eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross => "'I' (Nasdaq Cross Network Intraday Cross and Post Close Cross)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl eIPOFlag {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => eIPOFlag::New_IPO_Security,
+      b'N' => eIPOFlag::Not_A_New_IPO_Security,
+      b' ' => eIPOFlag::Not_available,
+      _ => eIPOFlag::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eIPOFlag::New_IPO_Security => b'Y',
+      eIPOFlag::Not_A_New_IPO_Security => b'N',
+      eIPOFlag::Not_available => b' ',
+      eIPOFlag::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eIPOFlag::Unknown(_))
   }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eIPOFlag::New_IPO_Security => "New_IPO_Security",
+      eIPOFlag::Not_A_New_IPO_Security => "Not_A_New_IPO_Security",
+      eIPOFlag::Not_available => "Not_available",
+      eIPOFlag::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "New_IPO_Security" => Some(eIPOFlag::New_IPO_Security),
+      "Not_A_New_IPO_Security" => Some(eIPOFlag::Not_A_New_IPO_Security),
+      "Not_available" => Some(eIPOFlag::Not_available),
+      _ => None,
+    }
+  }
+} // eIPOFlag
+
+impl Default for eIPOFlag {
+  fn default() -> Self { eIPOFlag::Unknown(0) }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct eImbalanceDirection(pub u8);
-#[allow(non_upper_case_globals)]
-impl eImbalanceDirection {
-  pub const buy_imbalance : u8 = 'B' as u8;
-  pub const sell_imbalance : u8 = 'S' as u8;
-  pub const no_imbalance : u8 = 'N' as u8;
-  pub const Insufficient_orders_to_calculate : u8 = 'O' as u8;
-} // eImbalanceDirection
+impl TryFrom<u8> for eIPOFlag {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eIPOFlag::Unknown(code) => Err(UnknownCode { enum_name: "eIPOFlag", code }),
+      known => Ok(known),
+    }
+  }
+}
 
-impl fmt::Display for eImbalanceDirection {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eImbalanceDirection::buy_imbalance => "'B' (buy imbalance)".to_string(),
-      eImbalanceDirection::sell_imbalance => "'S' (sell imbalance)".to_string(),
-      eImbalanceDirection::no_imbalance => "'N' (no imbalance)".to_string(),
-      eImbalanceDirection::Insufficient_orders_to_calculate => "'O' (Insufficient orders to calculate)".to_string(),
-      _ => "Unknown".to_string(),
-    };
-    write!(f, "{}", s)
+impl From<eIPOFlag> for u8 {
+  fn from(value: eIPOFlag) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eIPOFlag {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eIPOFlag::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
   }
 }
 
-#[derive(PartialEq, Default)]
-#[allow(non_camel_case_types)]
-pub struct ePriceVariationIndicator(pub u8);
-#[allow(non_upper_case_globals)]
-impl ePriceVariationIndicator {
-  pub const less_than_1_pct : u8 = 'L' as u8;
-  pub const deviation_1_to_2_pct : u8 = '1' as u8;
-  pub const deviation_2_to_3_pct : u8 = '2' as u8;
-  pub const deviation_3_to_4_pct : u8 = '3' as u8;
-  pub const deviation_4_to_5_pct : u8 = '4' as u8;
-  pub const deviation_5_to_6_pct : u8 = '5' as u8;
-  pub const deviation_6_to_7_pct : u8 = '6' as u8;
-  pub const deviation_7_to_8_pct : u8 = '7' as u8;
-  pub const deviation_8_to_9_pct : u8 = '8' as u8;
-  pub const deviation_9_to_10_pct : u8 = '9' as u8;
-  pub const deviation_10_to_20_pct : u8 = 'A' as u8;
-  pub const deviation_20_to_30_pct : u8 = 'B' as u8;
-  pub const deviation_30_pct_or_greater : u8 = 'C' as u8;
-  pub const Cannot_be_calculated : u8 = ' ' as u8;
-} // ePriceVariationIndicator
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eIPOFlag {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eIPOFlag '{}'", s))),
+    }
+  }
+}
 
-impl fmt::Display for ePriceVariationIndicator {
+impl fmt::Display for eIPOFlag {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      ePriceVariationIndicator::less_than_1_pct => "'L' (less than 1 pct)".to_string(),
-      ePriceVariationIndicator::deviation_1_to_2_pct => "'1' (deviation 1 to 2 pct)".to_string(),
-      ePriceVariationIndicator::deviation_2_to_3_pct => "'2' (deviation 2 to 3 pct)".to_string(),
-      ePriceVariationIndicator::deviation_3_to_4_pct => "'3' (deviation 3 to 4 pct)".to_string(),
-      ePriceVariationIndicator::deviation_4_to_5_pct => "'4' (deviation 4 to 5 pct)".to_string(),
-      ePriceVariationIndicator::deviation_5_to_6_pct => "'5' (deviation 5 to 6 pct)".to_string(),
-      ePriceVariationIndicator::deviation_6_to_7_pct => "'6' (deviation 6 to 7 pct)".to_string(),
-      ePriceVariationIndicator::deviation_7_to_8_pct => "'7' (deviation 7 to 8 pct)".to_string(),
-      ePriceVariationIndicator::deviation_8_to_9_pct => "'8' (deviation 8 to 9 pct)".to_string(),
-      ePriceVariationIndicator::deviation_9_to_10_pct => "'9' (deviation 9 to 10 pct)".to_string(),
-      ePriceVariationIndicator::deviation_10_to_20_pct => "'A' (deviation 10 to 20 pct)".to_string(),
-      ePriceVariationIndicator::deviation_20_to_30_pct => "'B' (deviation 20 to 30 pct)".to_string(),
-      ePriceVariationIndicator::deviation_30_pct_or_greater => "'C' (deviation 30 pct or greater)".to_string(),
-      ePriceVariationIndicator::Cannot_be_calculated => "' ' (Cannot be calculated)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eIPOFlag::New_IPO_Security => "'Y' (New IPO Security)",
+      eIPOFlag::Not_A_New_IPO_Security => "'N' (Not A New IPO Security)",
+      eIPOFlag::Not_available => "' ' (Not available)",
+      eIPOFlag::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eInterestFlag(pub u8);
-#[allow(non_upper_case_globals)]
-impl eInterestFlag {
-  pub const RPI_orders_available_on_the_buy_side : u8 = 'B' as u8;
-  pub const RPI_orders_available_on_the_sell_side : u8 = 'S' as u8;
-  pub const RPI_orders_available_on_both_sides : u8 = 'A' as u8;
-  pub const No_RPI_orders_available : u8 = 'N' as u8;
-} // eInterestFlag
+#[repr(u8)]
+pub enum eLULDReferencePriceTier {
+  Tier_1_NMS_Stocks_and_select_ETPs = b'1',
+  Tier_2_NMSStocks = b'2',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eLULDReferencePriceTier
 
-impl fmt::Display for eInterestFlag {
+impl eLULDReferencePriceTier {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'1' => eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs,
+      b'2' => eLULDReferencePriceTier::Tier_2_NMSStocks,
+      b' ' => eLULDReferencePriceTier::Not_available,
+      _ => eLULDReferencePriceTier::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs => b'1',
+      eLULDReferencePriceTier::Tier_2_NMSStocks => b'2',
+      eLULDReferencePriceTier::Not_available => b' ',
+      eLULDReferencePriceTier::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eLULDReferencePriceTier::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs => "Tier_1_NMS_Stocks_and_select_ETPs",
+      eLULDReferencePriceTier::Tier_2_NMSStocks => "Tier_2_NMSStocks",
+      eLULDReferencePriceTier::Not_available => "Not_available",
+      eLULDReferencePriceTier::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Tier_1_NMS_Stocks_and_select_ETPs" => Some(eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs),
+      "Tier_2_NMSStocks" => Some(eLULDReferencePriceTier::Tier_2_NMSStocks),
+      "Not_available" => Some(eLULDReferencePriceTier::Not_available),
+      _ => None,
+    }
+  }
+} // eLULDReferencePriceTier
+
+impl Default for eLULDReferencePriceTier {
+  fn default() -> Self { eLULDReferencePriceTier::Unknown(0) }
+}
+
+impl TryFrom<u8> for eLULDReferencePriceTier {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eLULDReferencePriceTier::Unknown(code) => Err(UnknownCode { enum_name: "eLULDReferencePriceTier", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eLULDReferencePriceTier> for u8 {
+  fn from(value: eLULDReferencePriceTier) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eLULDReferencePriceTier {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eLULDReferencePriceTier::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eLULDReferencePriceTier {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eLULDReferencePriceTier '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eLULDReferencePriceTier {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eInterestFlag::RPI_orders_available_on_the_buy_side => "'B' (RPI orders available on the buy side)".to_string(),
-      eInterestFlag::RPI_orders_available_on_the_sell_side => "'S' (RPI orders available on the sell side)".to_string(),
-      eInterestFlag::RPI_orders_available_on_both_sides => "'A' (RPI orders available on both sides)".to_string(),
-      eInterestFlag::No_RPI_orders_available => "'N' (No RPI orders available)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eLULDReferencePriceTier::Tier_1_NMS_Stocks_and_select_ETPs => "'1' (Tier 1 NMS Stocks and select ETPs)",
+      eLULDReferencePriceTier::Tier_2_NMSStocks => "'2' (Tier 2 NMSStocks)",
+      eLULDReferencePriceTier::Not_available => "' ' (Not available)",
+      eLULDReferencePriceTier::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-#[derive(PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
-pub struct eIssueClassification(pub u8);
-#[allow(non_upper_case_globals)]
-impl eIssueClassification {
-  pub const American_Depositary_Share : u8 = 'A' as u8;
-  pub const Bond : u8 = 'B' as u8;
-  pub const Common_Stock : u8 = 'C' as u8;
-  pub const Depository_Receipt : u8 = 'F' as u8;
-  pub const Rule_144A : u8 = 'I' as u8;
-  pub const Limited_Partnership : u8 = 'L' as u8;
-  pub const Notes : u8 = 'N' as u8;
-  pub const Ordinary_Share : u8 = 'O' as u8;
-  pub const Preferred_Stock : u8 = 'P' as u8;
-  pub const Other_Securities : u8 = 'Q' as u8;
-  pub const Right : u8 = 'R' as u8;
-  pub const Shares_of_Beneficial_Interest : u8 = 'S' as u8;
-  pub const Convertible_Debenture : u8 = 'T' as u8;
-  pub const Unit : u8 = 'U' as u8;
-  pub const Units_Benif_Int : u8 = 'V' as u8;
-  pub const Warrant : u8 = 'W' as u8;
-} // eIssueClassification
+#[repr(u8)]
+pub enum eETPFlag {
+  Instrument_is_an_ETP = b'Y',
+  Instrument_is_not_an_ETP = b'N',
+  Not_available = b' ',
+  Unknown(u8) = 0,
+} // eETPFlag
 
-impl fmt::Display for eIssueClassification {
+impl eETPFlag {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => eETPFlag::Instrument_is_an_ETP,
+      b'N' => eETPFlag::Instrument_is_not_an_ETP,
+      b' ' => eETPFlag::Not_available,
+      _ => eETPFlag::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eETPFlag::Instrument_is_an_ETP => b'Y',
+      eETPFlag::Instrument_is_not_an_ETP => b'N',
+      eETPFlag::Not_available => b' ',
+      eETPFlag::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eETPFlag::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eETPFlag::Instrument_is_an_ETP => "Instrument_is_an_ETP",
+      eETPFlag::Instrument_is_not_an_ETP => "Instrument_is_not_an_ETP",
+      eETPFlag::Not_available => "Not_available",
+      eETPFlag::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Instrument_is_an_ETP" => Some(eETPFlag::Instrument_is_an_ETP),
+      "Instrument_is_not_an_ETP" => Some(eETPFlag::Instrument_is_not_an_ETP),
+      "Not_available" => Some(eETPFlag::Not_available),
+      _ => None,
+    }
+  }
+} // eETPFlag
+
+impl Default for eETPFlag {
+  fn default() -> Self { eETPFlag::Unknown(0) }
+}
+
+impl TryFrom<u8> for eETPFlag {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eETPFlag::Unknown(code) => Err(UnknownCode { enum_name: "eETPFlag", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eETPFlag> for u8 {
+  fn from(value: eETPFlag) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eETPFlag {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eETPFlag::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eETPFlag {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eETPFlag '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eETPFlag {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let s = match self.0 {
-      eIssueClassification::American_Depositary_Share => "'A' (American Depositary Share)".to_string(),
-      eIssueClassification::Bond => "'B' (Bond)".to_string(),
-      eIssueClassification::Common_Stock => "'C' (Common Stock)".to_string(),
-      eIssueClassification::Depository_Receipt => "'F' (Depository Receipt)".to_string(),
-      eIssueClassification::Rule_144A => "'I' (Rule 144A)".to_string(),
-      eIssueClassification::Limited_Partnership => "'L' (Limited Partnership)".to_string(),
-      eIssueClassification::Notes => "'N' (Notes)".to_string(),
-      eIssueClassification::Ordinary_Share => "'O' (Ordinary Share)".to_string(),
-      eIssueClassification::Preferred_Stock => "'P' (Preferred Stock)".to_string(),
-      eIssueClassification::Other_Securities => "'Q' (Other Securities)".to_string(),
-      eIssueClassification::Right => "'R' (Right)".to_string(),
-      eIssueClassification::Shares_of_Beneficial_Interest => "'S' (Shares of Beneficial Interest)".to_string(),
-      eIssueClassification::Convertible_Debenture => "'T' (Convertible Debenture)".to_string(),
-      eIssueClassification::Unit => "'U' (Unit)".to_string(),
-      eIssueClassification::Units_Benif_Int => "'V' (Units Benif Int)".to_string(),
-      eIssueClassification::Warrant => "'W' (Warrant)".to_string(),
-      _ => "Unknown".to_string(),
+    let s = match *self {
+      eETPFlag::Instrument_is_an_ETP => "'Y' (Instrument is an ETP)",
+      eETPFlag::Instrument_is_not_an_ETP => "'N' (Instrument is not an ETP)",
+      eETPFlag::Not_available => "' ' (Not available)",
+      eETPFlag::Unknown(_) => "Unknown",
     };
     write!(f, "{}", s)
   }
 }
 
-// Structs
-#[derive(Default)]
-pub struct PacketHeader {
-  pub session: [u8;10],
-  pub sequence_number: u64,
-  pub message_count: u16,
-} // PacketHeader
-pub const PACKET_HEADER_SIZE : usize = 20;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eInverseIndicator {
+  ETP_is_an_Inverse_ETP = b'Y',
+  ETP_is_not_an_Inverse_ETP = b'N',
+  Unknown(u8) = 0,
+} // eInverseIndicator
 
-impl PacketHeader {
-  pub fn from_bytes(bytes: &[u8]) -> Option<(PacketHeader,usize)> {
-    if bytes.len() < PACKET_HEADER_SIZE {
-      return None;
+impl eInverseIndicator {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => eInverseIndicator::ETP_is_an_Inverse_ETP,
+      b'N' => eInverseIndicator::ETP_is_not_an_Inverse_ETP,
+      _ => eInverseIndicator::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(&mut obj.session[..10]).unwrap();
-    obj.sequence_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.message_count = rdr.read_u16::<BigEndian>().unwrap();
-    Some((obj, PACKET_HEADER_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<PacketHeader> {
-    let mut obj = Self::default();
-    rdr.read_exact(&mut obj.session[..10])?;
-    obj.sequence_number = rdr.read_u64::<BigEndian>()?;
-    obj.message_count = rdr.read_u16::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eInverseIndicator::ETP_is_an_Inverse_ETP => b'Y',
+      eInverseIndicator::ETP_is_not_an_Inverse_ETP => b'N',
+      eInverseIndicator::Unknown(code) => code,
+    }
   }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eInverseIndicator::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eInverseIndicator::ETP_is_an_Inverse_ETP => "ETP_is_an_Inverse_ETP",
+      eInverseIndicator::ETP_is_not_an_Inverse_ETP => "ETP_is_not_an_Inverse_ETP",
+      eInverseIndicator::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "ETP_is_an_Inverse_ETP" => Some(eInverseIndicator::ETP_is_an_Inverse_ETP),
+      "ETP_is_not_an_Inverse_ETP" => Some(eInverseIndicator::ETP_is_not_an_Inverse_ETP),
+      _ => None,
+    }
+  }
+} // eInverseIndicator
+
+impl Default for eInverseIndicator {
+  fn default() -> Self { eInverseIndicator::Unknown(0) }
 }
-impl fmt::Display for PacketHeader {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "PacketHeader(session:{},sequence_number:{},message_count:{})", String::from_utf8_lossy(&self.session[..]), self.sequence_number, self.message_count)
+
+impl TryFrom<u8> for eInverseIndicator {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eInverseIndicator::Unknown(code) => Err(UnknownCode { enum_name: "eInverseIndicator", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct MessageBlock {
-  pub message_length: u16,
-} // MessageBlock
-pub const MESSAGE_BLOCK_SIZE : usize = 2;
 
-impl MessageBlock {
-  pub fn from_bytes(bytes: &[u8]) -> Option<(MessageBlock,usize)> {
-    if bytes.len() < MESSAGE_BLOCK_SIZE {
-      return None;
+impl From<eInverseIndicator> for u8 {
+  fn from(value: eInverseIndicator) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eInverseIndicator {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eInverseIndicator::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    obj.message_length = rdr.read_u16::<BigEndian>().unwrap();
-    Some((obj, MESSAGE_BLOCK_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MessageBlock> {
-    let mut obj = Self::default();
-    obj.message_length = rdr.read_u16::<BigEndian>()?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eInverseIndicator {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eInverseIndicator '{}'", s))),
+    }
   }
 }
-impl fmt::Display for MessageBlock {
+
+impl fmt::Display for eInverseIndicator {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "MessageBlock(message_length:{})", self.message_length)
+    let s = match *self {
+      eInverseIndicator::ETP_is_an_Inverse_ETP => "'Y' (ETP is an Inverse ETP)",
+      eInverseIndicator::ETP_is_not_an_Inverse_ETP => "'N' (ETP is not an Inverse ETP)",
+      eInverseIndicator::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct RequestPacket {
-  pub session: [u8;10],
-  pub sequence_number: [u8;8],
-  pub requested_message_count: u16,
-} // RequestPacket
-pub const REQUEST_PACKET_SIZE : usize = 20;
 
-impl RequestPacket {
-  pub fn from_bytes(bytes: &[u8]) -> Option<(RequestPacket,usize)> {
-    if bytes.len() < REQUEST_PACKET_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eTradingState {
+  Halted = b'H',
+  Paused = b'P',
+  Quotation_only = b'Q',
+  Trading = b'T',
+  Unknown(u8) = 0,
+} // eTradingState
+
+impl eTradingState {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'H' => eTradingState::Halted,
+      b'P' => eTradingState::Paused,
+      b'Q' => eTradingState::Quotation_only,
+      b'T' => eTradingState::Trading,
+      _ => eTradingState::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(&mut obj.session[..10]).unwrap();
-    rdr.read_exact(&mut obj.sequence_number[..8]).unwrap();
-    obj.requested_message_count = rdr.read_u16::<BigEndian>().unwrap();
-    Some((obj, REQUEST_PACKET_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RequestPacket> {
-    let mut obj = Self::default();
-    rdr.read_exact(&mut obj.session[..10])?;
-    rdr.read_exact(&mut obj.sequence_number[..8])?;
-    obj.requested_message_count = rdr.read_u16::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eTradingState::Halted => b'H',
+      eTradingState::Paused => b'P',
+      eTradingState::Quotation_only => b'Q',
+      eTradingState::Trading => b'T',
+      eTradingState::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eTradingState::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eTradingState::Halted => "Halted",
+      eTradingState::Paused => "Paused",
+      eTradingState::Quotation_only => "Quotation_only",
+      eTradingState::Trading => "Trading",
+      eTradingState::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Halted" => Some(eTradingState::Halted),
+      "Paused" => Some(eTradingState::Paused),
+      "Quotation_only" => Some(eTradingState::Quotation_only),
+      "Trading" => Some(eTradingState::Trading),
+      _ => None,
+    }
   }
+} // eTradingState
+
+impl Default for eTradingState {
+  fn default() -> Self { eTradingState::Unknown(0) }
 }
-impl fmt::Display for RequestPacket {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "RequestPacket(session:{},sequence_number:{},requested_message_count:{})", String::from_utf8_lossy(&self.session[..]), String::from_utf8_lossy(&self.sequence_number[..]), self.requested_message_count)
+
+impl TryFrom<u8> for eTradingState {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eTradingState::Unknown(code) => Err(UnknownCode { enum_name: "eTradingState", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct SystemEvent {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub event_code: eSystemEvent,
-} // SystemEvent
-pub const SYSTEM_EVENT_SIZE : usize = 12;
 
-impl SystemEvent {
-  pub const TYPE : u8 = 'S' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(SystemEvent,usize)> {
-    if bytes.len() < SYSTEM_EVENT_SIZE {
-      return None;
+impl From<eTradingState> for u8 {
+  fn from(value: eTradingState) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eTradingState {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eTradingState::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(std::slice::from_mut(&mut obj.event_code.0)).unwrap();
-    Some((obj, SYSTEM_EVENT_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<SystemEvent> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(std::slice::from_mut(&mut obj.event_code.0))?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eTradingState {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eTradingState '{}'", s))),
+    }
   }
 }
-impl fmt::Display for SystemEvent {
+
+impl fmt::Display for eTradingState {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "SystemEvent(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},event_code:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.event_code)
+    let s = match *self {
+      eTradingState::Halted => "'H' (Halted)",
+      eTradingState::Paused => "'P' (Paused)",
+      eTradingState::Quotation_only => "'Q' (Quotation only)",
+      eTradingState::Trading => "'T' (Trading)",
+      eTradingState::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct StockDirectory {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub market_category: eMarketCategory,
-  pub financial_status_indicator: eFinancialStatusIndicator,
-  pub round_lot_size: u32,
-  pub round_lots_only: eRoundLotsOnly,
-  pub issue_classification: eIssueClassification,
-  pub issue_sub_type: [u8;2],
-  pub authenticity: eAuthenticity,
-  pub short_sale_threshold_indicator: eShortSaleThresholdIndicator,
-  pub ipo_flag: eIPOFlag,
-  pub luld_reference_price_tier: eLULDReferencePriceTier,
-  pub etp_flag: eETPFlag,
-  pub etp_leverage_factor: u32,
-  pub inverse_indicator: eInverseIndicator,
-} // StockDirectory
-pub const STOCK_DIRECTORY_SIZE : usize = 39;
 
-impl StockDirectory {
-  pub const TYPE : u8 = 'R' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(StockDirectory,usize)> {
-    if bytes.len() < STOCK_DIRECTORY_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eRegSHOAction {
+  No_price_test_in_place = b'0',
+  Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect = b'1',
+  Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect = b'2',
+  Unknown(u8) = 0,
+} // eRegSHOAction
+
+impl eRegSHOAction {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'0' => eRegSHOAction::No_price_test_in_place,
+      b'1' => eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect,
+      b'2' => eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect,
+      _ => eRegSHOAction::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_category.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.financial_status_indicator.0)).unwrap();
-    obj.round_lot_size = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.round_lots_only.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.issue_classification.0)).unwrap();
-    rdr.read_exact(&mut obj.issue_sub_type[..2]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.authenticity.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.short_sale_threshold_indicator.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.ipo_flag.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.luld_reference_price_tier.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.etp_flag.0)).unwrap();
-    obj.etp_leverage_factor = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.inverse_indicator.0)).unwrap();
-    Some((obj, STOCK_DIRECTORY_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<StockDirectory> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_category.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.financial_status_indicator.0))?;
-    obj.round_lot_size = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.round_lots_only.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.issue_classification.0))?;
-    rdr.read_exact(&mut obj.issue_sub_type[..2])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.authenticity.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.short_sale_threshold_indicator.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.ipo_flag.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.luld_reference_price_tier.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.etp_flag.0))?;
-    obj.etp_leverage_factor = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.inverse_indicator.0))?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eRegSHOAction::No_price_test_in_place => b'0',
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect => b'1',
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect => b'2',
+      eRegSHOAction::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eRegSHOAction::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eRegSHOAction::No_price_test_in_place => "No_price_test_in_place",
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect => "Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect",
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect => "Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect",
+      eRegSHOAction::Unknown(_) => "Unknown",
+    }
   }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "No_price_test_in_place" => Some(eRegSHOAction::No_price_test_in_place),
+      "Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect" => Some(eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect),
+      "Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect" => Some(eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect),
+      _ => None,
+    }
+  }
+} // eRegSHOAction
+
+impl Default for eRegSHOAction {
+  fn default() -> Self { eRegSHOAction::Unknown(0) }
 }
-impl fmt::Display for StockDirectory {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "StockDirectory(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},market_category:{},financial_status_indicator:{},round_lot_size:{},round_lots_only:{},issue_classification:{},issue_sub_type:{},authenticity:{},short_sale_threshold_indicator:{},ipo_flag:{},luld_reference_price_tier:{},etp_flag:{},etp_leverage_factor:{},inverse_indicator:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.market_category, self.financial_status_indicator, self.round_lot_size, self.round_lots_only, self.issue_classification, String::from_utf8_lossy(&self.issue_sub_type[..]), self.authenticity, self.short_sale_threshold_indicator, self.ipo_flag, self.luld_reference_price_tier, self.etp_flag, self.etp_leverage_factor, self.inverse_indicator)
+
+impl TryFrom<u8> for eRegSHOAction {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eRegSHOAction::Unknown(code) => Err(UnknownCode { enum_name: "eRegSHOAction", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct StockTradingAction {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub trading_state: eTradingState,
-  pub reserved: u8,
-  pub reason: [u8;4],
-} // StockTradingAction
-pub const STOCK_TRADING_ACTION_SIZE : usize = 25;
 
-impl StockTradingAction {
-  pub const TYPE : u8 = 'H' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(StockTradingAction,usize)> {
-    if bytes.len() < STOCK_TRADING_ACTION_SIZE {
-      return None;
+impl From<eRegSHOAction> for u8 {
+  fn from(value: eRegSHOAction) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eRegSHOAction {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eRegSHOAction::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.trading_state.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.reserved)).unwrap();
-    rdr.read_exact(&mut obj.reason[..4]).unwrap();
-    Some((obj, STOCK_TRADING_ACTION_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<StockTradingAction> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.trading_state.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.reserved))?;
-    rdr.read_exact(&mut obj.reason[..4])?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eRegSHOAction {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eRegSHOAction '{}'", s))),
+    }
   }
 }
-impl fmt::Display for StockTradingAction {
+
+impl fmt::Display for eRegSHOAction {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "StockTradingAction(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},trading_state:{},reserved:{},reason:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.trading_state, self.reserved, String::from_utf8_lossy(&self.reason[..]))
+    let s = match *self {
+      eRegSHOAction::No_price_test_in_place => "'0' (No price test in place)",
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_in_effect => "'1' (Reg SHO Short Sale Price Test Restriction in effect)",
+      eRegSHOAction::Reg_SHO_Short_Sale_Price_Test_Restriction_remains_in_effect => "'2' (Reg SHO Short Sale Price Test Restriction remains in effect)",
+      eRegSHOAction::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct RegShoRestriction {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub reg_sho_action: eRegSHOAction,
-} // RegShoRestriction
-pub const REG_SHO_RESTRICTION_SIZE : usize = 20;
 
-impl RegShoRestriction {
-  pub const TYPE : u8 = 'Y' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(RegShoRestriction,usize)> {
-    if bytes.len() < REG_SHO_RESTRICTION_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum ePrimaryMarketMaker {
+  primary_market_maker = b'Y',
+  non_primary_market_maker = b'N',
+  Unknown(u8) = 0,
+} // ePrimaryMarketMaker
+
+impl ePrimaryMarketMaker {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Y' => ePrimaryMarketMaker::primary_market_maker,
+      b'N' => ePrimaryMarketMaker::non_primary_market_maker,
+      _ => ePrimaryMarketMaker::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.reg_sho_action.0)).unwrap();
-    Some((obj, REG_SHO_RESTRICTION_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RegShoRestriction> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.reg_sho_action.0))?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      ePrimaryMarketMaker::primary_market_maker => b'Y',
+      ePrimaryMarketMaker::non_primary_market_maker => b'N',
+      ePrimaryMarketMaker::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, ePrimaryMarketMaker::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      ePrimaryMarketMaker::primary_market_maker => "primary_market_maker",
+      ePrimaryMarketMaker::non_primary_market_maker => "non_primary_market_maker",
+      ePrimaryMarketMaker::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "primary_market_maker" => Some(ePrimaryMarketMaker::primary_market_maker),
+      "non_primary_market_maker" => Some(ePrimaryMarketMaker::non_primary_market_maker),
+      _ => None,
+    }
   }
+} // ePrimaryMarketMaker
+
+impl Default for ePrimaryMarketMaker {
+  fn default() -> Self { ePrimaryMarketMaker::Unknown(0) }
 }
-impl fmt::Display for RegShoRestriction {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "RegShoRestriction(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},reg_sho_action:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.reg_sho_action)
+
+impl TryFrom<u8> for ePrimaryMarketMaker {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      ePrimaryMarketMaker::Unknown(code) => Err(UnknownCode { enum_name: "ePrimaryMarketMaker", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct MarketParticipantPosition {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub mpid: [u8;4],
-  pub stock: [u8;8],
-  pub primary_market_maker: ePrimaryMarketMaker,
-  pub market_maker_mode: eMarketMakerMode,
-  pub market_participant_state: eMarketParticipantState,
-} // MarketParticipantPosition
-pub const MARKET_PARTICIPANT_POSITION_SIZE : usize = 26;
 
-impl MarketParticipantPosition {
-  pub const TYPE : u8 = 'L' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(MarketParticipantPosition,usize)> {
-    if bytes.len() < MARKET_PARTICIPANT_POSITION_SIZE {
-      return None;
+impl From<ePrimaryMarketMaker> for u8 {
+  fn from(value: ePrimaryMarketMaker) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ePrimaryMarketMaker {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      ePrimaryMarketMaker::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.mpid[..4]).unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.primary_market_maker.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_maker_mode.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_participant_state.0)).unwrap();
-    Some((obj, MARKET_PARTICIPANT_POSITION_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MarketParticipantPosition> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.mpid[..4])?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.primary_market_maker.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_maker_mode.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_participant_state.0))?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ePrimaryMarketMaker {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown ePrimaryMarketMaker '{}'", s))),
+    }
   }
 }
-impl fmt::Display for MarketParticipantPosition {
+
+impl fmt::Display for ePrimaryMarketMaker {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "MarketParticipantPosition(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},mpid:{},stock:{},primary_market_maker:{},market_maker_mode:{},market_participant_state:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.mpid[..]), String::from_utf8_lossy(&self.stock[..]), self.primary_market_maker, self.market_maker_mode, self.market_participant_state)
+    let s = match *self {
+      ePrimaryMarketMaker::primary_market_maker => "'Y' (primary market maker)",
+      ePrimaryMarketMaker::non_primary_market_maker => "'N' (non primary market maker)",
+      ePrimaryMarketMaker::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct MwcbDeclineLevel {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub level_1: u64,
-  pub level_2: u64,
-  pub level_3: u64,
-} // MwcbDeclineLevel
-pub const MWCB_DECLINE_LEVEL_SIZE : usize = 35;
 
-impl MwcbDeclineLevel {
-  pub const TYPE : u8 = 'V' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(MwcbDeclineLevel,usize)> {
-    if bytes.len() < MWCB_DECLINE_LEVEL_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eMarketMakerMode {
+  normal = b'N',
+  passive = b'P',
+  syndicate = b'S',
+  pre_syndicate = b'R',
+  penalty = b'L',
+  Unknown(u8) = 0,
+} // eMarketMakerMode
+
+impl eMarketMakerMode {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'N' => eMarketMakerMode::normal,
+      b'P' => eMarketMakerMode::passive,
+      b'S' => eMarketMakerMode::syndicate,
+      b'R' => eMarketMakerMode::pre_syndicate,
+      b'L' => eMarketMakerMode::penalty,
+      _ => eMarketMakerMode::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.level_1 = rdr.read_u64::<BigEndian>().unwrap();
-    obj.level_2 = rdr.read_u64::<BigEndian>().unwrap();
-    obj.level_3 = rdr.read_u64::<BigEndian>().unwrap();
-    Some((obj, MWCB_DECLINE_LEVEL_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MwcbDeclineLevel> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.level_1 = rdr.read_u64::<BigEndian>()?;
-    obj.level_2 = rdr.read_u64::<BigEndian>()?;
-    obj.level_3 = rdr.read_u64::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eMarketMakerMode::normal => b'N',
+      eMarketMakerMode::passive => b'P',
+      eMarketMakerMode::syndicate => b'S',
+      eMarketMakerMode::pre_syndicate => b'R',
+      eMarketMakerMode::penalty => b'L',
+      eMarketMakerMode::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eMarketMakerMode::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eMarketMakerMode::normal => "normal",
+      eMarketMakerMode::passive => "passive",
+      eMarketMakerMode::syndicate => "syndicate",
+      eMarketMakerMode::pre_syndicate => "pre_syndicate",
+      eMarketMakerMode::penalty => "penalty",
+      eMarketMakerMode::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "normal" => Some(eMarketMakerMode::normal),
+      "passive" => Some(eMarketMakerMode::passive),
+      "syndicate" => Some(eMarketMakerMode::syndicate),
+      "pre_syndicate" => Some(eMarketMakerMode::pre_syndicate),
+      "penalty" => Some(eMarketMakerMode::penalty),
+      _ => None,
+    }
   }
+} // eMarketMakerMode
+
+impl Default for eMarketMakerMode {
+  fn default() -> Self { eMarketMakerMode::Unknown(0) }
 }
-impl fmt::Display for MwcbDeclineLevel {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "MwcbDeclineLevel(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},level_1:{},level_2:{},level_3:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.level_1, self.level_2, self.level_3)
+
+impl TryFrom<u8> for eMarketMakerMode {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eMarketMakerMode::Unknown(code) => Err(UnknownCode { enum_name: "eMarketMakerMode", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct MwcbStatus {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub breached_level: eBreachedLevel,
-} // MwcbStatus
-pub const MWCB_STATUS_SIZE : usize = 12;
 
-impl MwcbStatus {
-  pub const TYPE : u8 = 'W' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(MwcbStatus,usize)> {
-    if bytes.len() < MWCB_STATUS_SIZE {
-      return None;
+impl From<eMarketMakerMode> for u8 {
+  fn from(value: eMarketMakerMode) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eMarketMakerMode {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eMarketMakerMode::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(std::slice::from_mut(&mut obj.breached_level.0)).unwrap();
-    Some((obj, MWCB_STATUS_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MwcbStatus> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(std::slice::from_mut(&mut obj.breached_level.0))?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eMarketMakerMode {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eMarketMakerMode '{}'", s))),
+    }
   }
 }
-impl fmt::Display for MwcbStatus {
+
+impl fmt::Display for eMarketMakerMode {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "MwcbStatus(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},breached_level:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.breached_level)
+    let s = match *self {
+      eMarketMakerMode::normal => "'N' (normal)",
+      eMarketMakerMode::passive => "'P' (passive)",
+      eMarketMakerMode::syndicate => "'S' (syndicate)",
+      eMarketMakerMode::pre_syndicate => "'R' (pre syndicate)",
+      eMarketMakerMode::penalty => "'L' (penalty)",
+      eMarketMakerMode::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct IpoQuotingPeriodUpdate {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub ipo_quotation_release_time: u32,
-  pub ipo_quotation_release_qualifier: eIPOQuotationReleaseQualifier,
-  pub ipo_price: u32,
-} // IpoQuotingPeriodUpdate
-pub const IPO_QUOTING_PERIOD_UPDATE_SIZE : usize = 28;
 
-impl IpoQuotingPeriodUpdate {
-  pub const TYPE : u8 = 'K' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(IpoQuotingPeriodUpdate,usize)> {
-    if bytes.len() < IPO_QUOTING_PERIOD_UPDATE_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eMarketParticipantState {
+  Active = b'A',
+  Excused_Withdrawn = b'E',
+  Withdrawn = b'W',
+  Suspended = b'S',
+  Deleted = b'D',
+  Unknown(u8) = 0,
+} // eMarketParticipantState
+
+impl eMarketParticipantState {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'A' => eMarketParticipantState::Active,
+      b'E' => eMarketParticipantState::Excused_Withdrawn,
+      b'W' => eMarketParticipantState::Withdrawn,
+      b'S' => eMarketParticipantState::Suspended,
+      b'D' => eMarketParticipantState::Deleted,
+      _ => eMarketParticipantState::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.ipo_quotation_release_time = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.ipo_quotation_release_qualifier.0)).unwrap();
-    obj.ipo_price = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, IPO_QUOTING_PERIOD_UPDATE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<IpoQuotingPeriodUpdate> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.ipo_quotation_release_time = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.ipo_quotation_release_qualifier.0))?;
-    obj.ipo_price = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eMarketParticipantState::Active => b'A',
+      eMarketParticipantState::Excused_Withdrawn => b'E',
+      eMarketParticipantState::Withdrawn => b'W',
+      eMarketParticipantState::Suspended => b'S',
+      eMarketParticipantState::Deleted => b'D',
+      eMarketParticipantState::Unknown(code) => code,
+    }
   }
-}
-impl fmt::Display for IpoQuotingPeriodUpdate {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "IpoQuotingPeriodUpdate(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},ipo_quotation_release_time:{},ipo_quotation_release_qualifier:{},ipo_price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.ipo_quotation_release_time, self.ipo_quotation_release_qualifier, self.ipo_price)
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eMarketParticipantState::Unknown(_))
   }
-}
-#[derive(Default)]
-pub struct LuldAuctionCollar {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub auction_collar_reference_price: u32,
-  pub upper_auction_collar_price: u32,
-  pub lower_auction_collar_price: u32,
-  pub auction_collar_extension: u32,
-} // LuldAuctionCollar
-pub const LULD_AUCTION_COLLAR_SIZE : usize = 35;
-
-impl LuldAuctionCollar {
-  pub const TYPE : u8 = 'J' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(LuldAuctionCollar,usize)> {
-    if bytes.len() < LULD_AUCTION_COLLAR_SIZE {
-      return None;
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eMarketParticipantState::Active => "Active",
+      eMarketParticipantState::Excused_Withdrawn => "Excused_Withdrawn",
+      eMarketParticipantState::Withdrawn => "Withdrawn",
+      eMarketParticipantState::Suspended => "Suspended",
+      eMarketParticipantState::Deleted => "Deleted",
+      eMarketParticipantState::Unknown(_) => "Unknown",
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.auction_collar_reference_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.upper_auction_collar_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.lower_auction_collar_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.auction_collar_extension = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, LULD_AUCTION_COLLAR_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<LuldAuctionCollar> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.auction_collar_reference_price = rdr.read_u32::<BigEndian>()?;
-    obj.upper_auction_collar_price = rdr.read_u32::<BigEndian>()?;
-    obj.lower_auction_collar_price = rdr.read_u32::<BigEndian>()?;
-    obj.auction_collar_extension = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Active" => Some(eMarketParticipantState::Active),
+      "Excused_Withdrawn" => Some(eMarketParticipantState::Excused_Withdrawn),
+      "Withdrawn" => Some(eMarketParticipantState::Withdrawn),
+      "Suspended" => Some(eMarketParticipantState::Suspended),
+      "Deleted" => Some(eMarketParticipantState::Deleted),
+      _ => None,
+    }
   }
+} // eMarketParticipantState
+
+impl Default for eMarketParticipantState {
+  fn default() -> Self { eMarketParticipantState::Unknown(0) }
 }
-impl fmt::Display for LuldAuctionCollar {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "LuldAuctionCollar(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},auction_collar_reference_price:{},upper_auction_collar_price:{},lower_auction_collar_price:{},auction_collar_extension:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.auction_collar_reference_price, self.upper_auction_collar_price, self.lower_auction_collar_price, self.auction_collar_extension)
+
+impl TryFrom<u8> for eMarketParticipantState {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eMarketParticipantState::Unknown(code) => Err(UnknownCode { enum_name: "eMarketParticipantState", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct OperationalHalt {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub market_code: eMarketCode,
-  pub operational_halt_action: eOperationalHaltAction,
-} // OperationalHalt
-pub const OPERATIONAL_HALT_SIZE : usize = 21;
 
-impl OperationalHalt {
-  pub const TYPE : u8 = 'h' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OperationalHalt,usize)> {
-    if bytes.len() < OPERATIONAL_HALT_SIZE {
-      return None;
+impl From<eMarketParticipantState> for u8 {
+  fn from(value: eMarketParticipantState) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eMarketParticipantState {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eMarketParticipantState::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_code.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.operational_halt_action.0)).unwrap();
-    Some((obj, OPERATIONAL_HALT_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OperationalHalt> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.market_code.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.operational_halt_action.0))?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eMarketParticipantState {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eMarketParticipantState '{}'", s))),
+    }
   }
 }
-impl fmt::Display for OperationalHalt {
+
+impl fmt::Display for eMarketParticipantState {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OperationalHalt(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},market_code:{},operational_halt_action:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.market_code, self.operational_halt_action)
+    let s = match *self {
+      eMarketParticipantState::Active => "'A' (Active)",
+      eMarketParticipantState::Excused_Withdrawn => "'E' (Excused Withdrawn)",
+      eMarketParticipantState::Withdrawn => "'W' (Withdrawn)",
+      eMarketParticipantState::Suspended => "'S' (Suspended)",
+      eMarketParticipantState::Deleted => "'D' (Deleted)",
+      eMarketParticipantState::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct AddOrder {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub buy_sell_indicator: eBuySellIndicator,
-  pub shares: u32,
-  pub stock: [u8;8],
-  pub price: u32,
-} // AddOrder
-pub const ADD_ORDER_SIZE : usize = 36;
 
-impl AddOrder {
-  pub const TYPE : u8 = 'A' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(AddOrder,usize)> {
-    if bytes.len() < ADD_ORDER_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eBreachedLevel {
+  Level_1 = b'1',
+  Level_2 = b'2',
+  Level_3 = b'3',
+  Unknown(u8) = 0,
+} // eBreachedLevel
+
+impl eBreachedLevel {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'1' => eBreachedLevel::Level_1,
+      b'2' => eBreachedLevel::Level_2,
+      b'3' => eBreachedLevel::Level_3,
+      _ => eBreachedLevel::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0)).unwrap();
-    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.price = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, ADD_ORDER_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<AddOrder> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0))?;
-    obj.shares = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.price = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eBreachedLevel::Level_1 => b'1',
+      eBreachedLevel::Level_2 => b'2',
+      eBreachedLevel::Level_3 => b'3',
+      eBreachedLevel::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eBreachedLevel::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eBreachedLevel::Level_1 => "Level_1",
+      eBreachedLevel::Level_2 => "Level_2",
+      eBreachedLevel::Level_3 => "Level_3",
+      eBreachedLevel::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Level_1" => Some(eBreachedLevel::Level_1),
+      "Level_2" => Some(eBreachedLevel::Level_2),
+      "Level_3" => Some(eBreachedLevel::Level_3),
+      _ => None,
+    }
   }
+} // eBreachedLevel
+
+impl Default for eBreachedLevel {
+  fn default() -> Self { eBreachedLevel::Unknown(0) }
 }
-impl fmt::Display for AddOrder {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "AddOrder(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, String::from_utf8_lossy(&self.stock[..]), self.price)
+
+impl TryFrom<u8> for eBreachedLevel {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eBreachedLevel::Unknown(code) => Err(UnknownCode { enum_name: "eBreachedLevel", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct AddOrderWithMpid {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub buy_sell_indicator: eBuySellIndicator,
-  pub shares: u32,
-  pub stock: [u8;8],
-  pub price: u32,
-  pub attribution: [u8;4],
-} // AddOrderWithMpid
-pub const ADD_ORDER_WITH_MPID_SIZE : usize = 40;
 
-impl AddOrderWithMpid {
-  pub const TYPE : u8 = 'F' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(AddOrderWithMpid,usize)> {
-    if bytes.len() < ADD_ORDER_WITH_MPID_SIZE {
-      return None;
+impl From<eBreachedLevel> for u8 {
+  fn from(value: eBreachedLevel) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eBreachedLevel {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eBreachedLevel::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0)).unwrap();
-    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.price = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(&mut obj.attribution[..4]).unwrap();
-    Some((obj, ADD_ORDER_WITH_MPID_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<AddOrderWithMpid> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0))?;
-    obj.shares = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.price = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(&mut obj.attribution[..4])?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eBreachedLevel {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eBreachedLevel '{}'", s))),
+    }
   }
 }
-impl fmt::Display for AddOrderWithMpid {
+
+impl fmt::Display for eBreachedLevel {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "AddOrderWithMpid(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{},attribution:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, String::from_utf8_lossy(&self.stock[..]), self.price, String::from_utf8_lossy(&self.attribution[..]))
+    let s = match *self {
+      eBreachedLevel::Level_1 => "'1' (Level 1)",
+      eBreachedLevel::Level_2 => "'2' (Level 2)",
+      eBreachedLevel::Level_3 => "'3' (Level 3)",
+      eBreachedLevel::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct OrderExecuted {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub executed_shares: u32,
-  pub match_number: u64,
-} // OrderExecuted
-pub const ORDER_EXECUTED_SIZE : usize = 31;
 
-impl OrderExecuted {
-  pub const TYPE : u8 = 'E' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderExecuted,usize)> {
-    if bytes.len() < ORDER_EXECUTED_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eIPOQuotationReleaseQualifier {
+  Anticipated_Quotation_Release_Time = b'A',
+  IPO_Release_Canceled_Postponed = b'C',
+  Unknown(u8) = 0,
+} // eIPOQuotationReleaseQualifier
+
+impl eIPOQuotationReleaseQualifier {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'A' => eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time,
+      b'C' => eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed,
+      _ => eIPOQuotationReleaseQualifier::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.executed_shares = rdr.read_u32::<BigEndian>().unwrap();
-    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
-    Some((obj, ORDER_EXECUTED_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderExecuted> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    obj.executed_shares = rdr.read_u32::<BigEndian>()?;
-    obj.match_number = rdr.read_u64::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time => b'A',
+      eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed => b'C',
+      eIPOQuotationReleaseQualifier::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eIPOQuotationReleaseQualifier::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time => "Anticipated_Quotation_Release_Time",
+      eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed => "IPO_Release_Canceled_Postponed",
+      eIPOQuotationReleaseQualifier::Unknown(_) => "Unknown",
+    }
   }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Anticipated_Quotation_Release_Time" => Some(eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time),
+      "IPO_Release_Canceled_Postponed" => Some(eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed),
+      _ => None,
+    }
+  }
+} // eIPOQuotationReleaseQualifier
+
+impl Default for eIPOQuotationReleaseQualifier {
+  fn default() -> Self { eIPOQuotationReleaseQualifier::Unknown(0) }
 }
-impl fmt::Display for OrderExecuted {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OrderExecuted(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},executed_shares:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.executed_shares, self.match_number)
+
+impl TryFrom<u8> for eIPOQuotationReleaseQualifier {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eIPOQuotationReleaseQualifier::Unknown(code) => Err(UnknownCode { enum_name: "eIPOQuotationReleaseQualifier", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct OrderExecutedWithPrice {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub executed_shares: u32,
-  pub match_number: u64,
-  pub printable: ePrintable,
-  pub execution_price: u32,
-} // OrderExecutedWithPrice
-pub const ORDER_EXECUTED_WITH_PRICE_SIZE : usize = 36;
 
-impl OrderExecutedWithPrice {
-  pub const TYPE : u8 = 'C' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderExecutedWithPrice,usize)> {
-    if bytes.len() < ORDER_EXECUTED_WITH_PRICE_SIZE {
-      return None;
+impl From<eIPOQuotationReleaseQualifier> for u8 {
+  fn from(value: eIPOQuotationReleaseQualifier) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eIPOQuotationReleaseQualifier {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eIPOQuotationReleaseQualifier::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.executed_shares = rdr.read_u32::<BigEndian>().unwrap();
-    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.printable.0)).unwrap();
-    obj.execution_price = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, ORDER_EXECUTED_WITH_PRICE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderExecutedWithPrice> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    obj.executed_shares = rdr.read_u32::<BigEndian>()?;
-    obj.match_number = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.printable.0))?;
-    obj.execution_price = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eIPOQuotationReleaseQualifier {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eIPOQuotationReleaseQualifier '{}'", s))),
+    }
   }
 }
-impl fmt::Display for OrderExecutedWithPrice {
+
+impl fmt::Display for eIPOQuotationReleaseQualifier {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OrderExecutedWithPrice(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},executed_shares:{},match_number:{},printable:{},execution_price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.executed_shares, self.match_number, self.printable, self.execution_price)
+    let s = match *self {
+      eIPOQuotationReleaseQualifier::Anticipated_Quotation_Release_Time => "'A' (Anticipated Quotation Release Time)",
+      eIPOQuotationReleaseQualifier::IPO_Release_Canceled_Postponed => "'C' (IPO Release Canceled Postponed)",
+      eIPOQuotationReleaseQualifier::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct OrderCancel {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub cancelled_shares: u32,
-} // OrderCancel
-pub const ORDER_CANCEL_SIZE : usize = 23;
 
-impl OrderCancel {
-  pub const TYPE : u8 = 'X' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderCancel,usize)> {
-    if bytes.len() < ORDER_CANCEL_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eMarketCode {
+  Nasdaq = b'Q',
+  BX = b'B',
+  PSX = b'X',
+  Unknown(u8) = 0,
+} // eMarketCode
+
+impl eMarketCode {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'Q' => eMarketCode::Nasdaq,
+      b'B' => eMarketCode::BX,
+      b'X' => eMarketCode::PSX,
+      _ => eMarketCode::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.cancelled_shares = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, ORDER_CANCEL_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderCancel> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    obj.cancelled_shares = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eMarketCode::Nasdaq => b'Q',
+      eMarketCode::BX => b'B',
+      eMarketCode::PSX => b'X',
+      eMarketCode::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eMarketCode::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eMarketCode::Nasdaq => "Nasdaq",
+      eMarketCode::BX => "BX",
+      eMarketCode::PSX => "PSX",
+      eMarketCode::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Nasdaq" => Some(eMarketCode::Nasdaq),
+      "BX" => Some(eMarketCode::BX),
+      "PSX" => Some(eMarketCode::PSX),
+      _ => None,
+    }
   }
+} // eMarketCode
+
+impl Default for eMarketCode {
+  fn default() -> Self { eMarketCode::Unknown(0) }
 }
-impl fmt::Display for OrderCancel {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OrderCancel(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},cancelled_shares:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.cancelled_shares)
+
+impl TryFrom<u8> for eMarketCode {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eMarketCode::Unknown(code) => Err(UnknownCode { enum_name: "eMarketCode", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct OrderDelete {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-} // OrderDelete
-pub const ORDER_DELETE_SIZE : usize = 19;
 
-impl OrderDelete {
-  pub const TYPE : u8 = 'D' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderDelete,usize)> {
-    if bytes.len() < ORDER_DELETE_SIZE {
-      return None;
+impl From<eMarketCode> for u8 {
+  fn from(value: eMarketCode) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eMarketCode {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eMarketCode::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    Some((obj, ORDER_DELETE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderDelete> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eMarketCode {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eMarketCode '{}'", s))),
+    }
   }
 }
-impl fmt::Display for OrderDelete {
+
+impl fmt::Display for eMarketCode {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OrderDelete(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number)
+    let s = match *self {
+      eMarketCode::Nasdaq => "'Q' (Nasdaq)",
+      eMarketCode::BX => "'B' (BX)",
+      eMarketCode::PSX => "'X' (PSX)",
+      eMarketCode::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct OrderReplace {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub original_order_reference_number: u64,
-  pub new_order_reference_number: u64,
-  pub shares: u32,
-  pub price: u32,
-} // OrderReplace
-pub const ORDER_REPLACE_SIZE : usize = 35;
 
-impl OrderReplace {
-  pub const TYPE : u8 = 'U' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderReplace,usize)> {
-    if bytes.len() < ORDER_REPLACE_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eOperationalHaltAction {
+  Halted = b'H',
+  Trading = b'T',
+  Unknown(u8) = 0,
+} // eOperationalHaltAction
+
+impl eOperationalHaltAction {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'H' => eOperationalHaltAction::Halted,
+      b'T' => eOperationalHaltAction::Trading,
+      _ => eOperationalHaltAction::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.original_order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.new_order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
-    obj.price = rdr.read_u32::<BigEndian>().unwrap();
-    Some((obj, ORDER_REPLACE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderReplace> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.original_order_reference_number = rdr.read_u64::<BigEndian>()?;
-    obj.new_order_reference_number = rdr.read_u64::<BigEndian>()?;
-    obj.shares = rdr.read_u32::<BigEndian>()?;
-    obj.price = rdr.read_u32::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eOperationalHaltAction::Halted => b'H',
+      eOperationalHaltAction::Trading => b'T',
+      eOperationalHaltAction::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eOperationalHaltAction::Unknown(_))
   }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eOperationalHaltAction::Halted => "Halted",
+      eOperationalHaltAction::Trading => "Trading",
+      eOperationalHaltAction::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Halted" => Some(eOperationalHaltAction::Halted),
+      "Trading" => Some(eOperationalHaltAction::Trading),
+      _ => None,
+    }
+  }
+} // eOperationalHaltAction
+
+impl Default for eOperationalHaltAction {
+  fn default() -> Self { eOperationalHaltAction::Unknown(0) }
 }
-impl fmt::Display for OrderReplace {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "OrderReplace(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},original_order_reference_number:{},new_order_reference_number:{},shares:{},price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.original_order_reference_number, self.new_order_reference_number, self.shares, self.price)
+
+impl TryFrom<u8> for eOperationalHaltAction {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eOperationalHaltAction::Unknown(code) => Err(UnknownCode { enum_name: "eOperationalHaltAction", code }),
+      known => Ok(known),
+    }
   }
 }
-#[derive(Default)]
-pub struct Trade {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub order_reference_number: u64,
-  pub buy_sell_indicator: eBuySellIndicator,
-  pub shares: u32,
-  pub stock: [u8;8],
-  pub price: u32,
-  pub match_number: u64,
-} // Trade
-pub const TRADE_SIZE : usize = 44;
 
-impl Trade {
-  pub const TYPE : u8 = 'P' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(Trade,usize)> {
-    if bytes.len() < TRADE_SIZE {
-      return None;
+impl From<eOperationalHaltAction> for u8 {
+  fn from(value: eOperationalHaltAction) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eOperationalHaltAction {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eOperationalHaltAction::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0)).unwrap();
-    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
-    Some((obj, TRADE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<Trade> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.buy_sell_indicator.0))?;
-    obj.shares = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.price = rdr.read_u32::<BigEndian>()?;
-    obj.match_number = rdr.read_u64::<BigEndian>()?;
-    Ok(obj)
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eOperationalHaltAction {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eOperationalHaltAction '{}'", s))),
+    }
   }
 }
-impl fmt::Display for Trade {
+
+impl fmt::Display for eOperationalHaltAction {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "Trade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, String::from_utf8_lossy(&self.stock[..]), self.price, self.match_number)
+    let s = match *self {
+      eOperationalHaltAction::Halted => "'H' (Halted)",
+      eOperationalHaltAction::Trading => "'T' (Trading)",
+      eOperationalHaltAction::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct CrossTrade {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub shares: u64,
-  pub stock: [u8;8],
-  pub cross_price: u32,
-  pub match_number: u64,
-  pub cross_type: eCrossType,
-} // CrossTrade
-pub const CROSS_TRADE_SIZE : usize = 40;
 
-impl CrossTrade {
-  pub const TYPE : u8 = 'Q' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(CrossTrade,usize)> {
-    if bytes.len() < CROSS_TRADE_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eBuySellIndicator {
+  Buy_Order = b'B',
+  Sell_Order = b'S',
+  Unknown(u8) = 0,
+} // eBuySellIndicator
+
+impl eBuySellIndicator {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'B' => eBuySellIndicator::Buy_Order,
+      b'S' => eBuySellIndicator::Sell_Order,
+      _ => eBuySellIndicator::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.shares = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.cross_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.cross_type.0)).unwrap();
-    Some((obj, CROSS_TRADE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<CrossTrade> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.shares = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.cross_price = rdr.read_u32::<BigEndian>()?;
-    obj.match_number = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.cross_type.0))?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      eBuySellIndicator::Buy_Order => b'B',
+      eBuySellIndicator::Sell_Order => b'S',
+      eBuySellIndicator::Unknown(code) => code,
+    }
   }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eBuySellIndicator::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eBuySellIndicator::Buy_Order => "Buy_Order",
+      eBuySellIndicator::Sell_Order => "Sell_Order",
+      eBuySellIndicator::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Buy_Order" => Some(eBuySellIndicator::Buy_Order),
+      "Sell_Order" => Some(eBuySellIndicator::Sell_Order),
+      _ => None,
+    }
+  }
+} // eBuySellIndicator
+
+impl Default for eBuySellIndicator {
+  fn default() -> Self { eBuySellIndicator::Unknown(0) }
 }
-impl fmt::Display for CrossTrade {
+
+impl TryFrom<u8> for eBuySellIndicator {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eBuySellIndicator::Unknown(code) => Err(UnknownCode { enum_name: "eBuySellIndicator", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eBuySellIndicator> for u8 {
+  fn from(value: eBuySellIndicator) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eBuySellIndicator {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eBuySellIndicator::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eBuySellIndicator {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eBuySellIndicator '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eBuySellIndicator {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "CrossTrade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},shares:{},stock:{},cross_price:{},match_number:{},cross_type:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.shares, String::from_utf8_lossy(&self.stock[..]), self.cross_price, self.match_number, self.cross_type)
+    let s = match *self {
+      eBuySellIndicator::Buy_Order => "'B' (Buy Order)",
+      eBuySellIndicator::Sell_Order => "'S' (Sell Order)",
+      eBuySellIndicator::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
   }
 }
-#[derive(Default)]
-pub struct BrokenTrade {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub match_number: u64,
-} // BrokenTrade
-pub const BROKEN_TRADE_SIZE : usize = 19;
 
-impl BrokenTrade {
-  pub const TYPE : u8 = 'B' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(BrokenTrade,usize)> {
-    if bytes.len() < BROKEN_TRADE_SIZE {
-      return None;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum ePrintable {
+  Non_Printable = b'N',
+  Printable = b'Y',
+  Unknown(u8) = 0,
+} // ePrintable
+
+impl ePrintable {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'N' => ePrintable::Non_Printable,
+      b'Y' => ePrintable::Printable,
+      _ => ePrintable::Unknown(code),
     }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
-    Some((obj, BROKEN_TRADE_SIZE))
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<BrokenTrade> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.match_number = rdr.read_u64::<BigEndian>()?;
-    Ok(obj)
+  pub fn code(&self) -> u8 {
+    match *self {
+      ePrintable::Non_Printable => b'N',
+      ePrintable::Printable => b'Y',
+      ePrintable::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, ePrintable::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      ePrintable::Non_Printable => "Non_Printable",
+      ePrintable::Printable => "Printable",
+      ePrintable::Unknown(_) => "Unknown",
+    }
   }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Non_Printable" => Some(ePrintable::Non_Printable),
+      "Printable" => Some(ePrintable::Printable),
+      _ => None,
+    }
+  }
+} // ePrintable
+
+impl Default for ePrintable {
+  fn default() -> Self { ePrintable::Unknown(0) }
 }
-impl fmt::Display for BrokenTrade {
+
+impl TryFrom<u8> for ePrintable {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      ePrintable::Unknown(code) => Err(UnknownCode { enum_name: "ePrintable", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<ePrintable> for u8 {
+  fn from(value: ePrintable) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ePrintable {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      ePrintable::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ePrintable {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown ePrintable '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for ePrintable {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "BrokenTrade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.match_number)
+    let s = match *self {
+      ePrintable::Non_Printable => "'N' (Non Printable)",
+      ePrintable::Printable => "'Y' (Printable)",
+      ePrintable::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eCrossType {
+  Nasdaq_Opening_Cross = b'O',
+  Nasdaq_Closing_Cross = b'C',
+  Cross_for_IPO_and_halted_paused_securities = b'H',
+  Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross = b'I',
+  Unknown(u8) = 0,
+} // eCrossType
+
+impl eCrossType {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'O' => eCrossType::Nasdaq_Opening_Cross,
+      b'C' => eCrossType::Nasdaq_Closing_Cross,
+      b'H' => eCrossType::Cross_for_IPO_and_halted_paused_securities,
+      b'I' => eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross,
+      _ => eCrossType::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eCrossType::Nasdaq_Opening_Cross => b'O',
+      eCrossType::Nasdaq_Closing_Cross => b'C',
+      eCrossType::Cross_for_IPO_and_halted_paused_securities => b'H',
+      eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross => b'I',
+      eCrossType::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eCrossType::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eCrossType::Nasdaq_Opening_Cross => "Nasdaq_Opening_Cross",
+      eCrossType::Nasdaq_Closing_Cross => "Nasdaq_Closing_Cross",
+      eCrossType::Cross_for_IPO_and_halted_paused_securities => "Cross_for_IPO_and_halted_paused_securities",
+      eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross => "Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross",
+      eCrossType::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "Nasdaq_Opening_Cross" => Some(eCrossType::Nasdaq_Opening_Cross),
+      "Nasdaq_Closing_Cross" => Some(eCrossType::Nasdaq_Closing_Cross),
+      "Cross_for_IPO_and_halted_paused_securities" => Some(eCrossType::Cross_for_IPO_and_halted_paused_securities),
+      "Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross" => Some(eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross),
+      _ => None,
+    }
+  }
+} // eCrossType
+
+impl Default for eCrossType {
+  fn default() -> Self { eCrossType::Unknown(0) }
+}
+
+impl TryFrom<u8> for eCrossType {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eCrossType::Unknown(code) => Err(UnknownCode { enum_name: "eCrossType", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eCrossType> for u8 {
+  fn from(value: eCrossType) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eCrossType {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eCrossType::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eCrossType {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eCrossType '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eCrossType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      eCrossType::Nasdaq_Opening_Cross => "'O' (Nasdaq Opening Cross)",
+      eCrossType::Nasdaq_Closing_Cross => "'C' (Nasdaq Closing Cross)",
+      eCrossType::Cross_for_IPO_and_halted_paused_securities => "'H' (Cross for IPO and halted paused securities)",
+      eCrossType::Nasdaq_Cross_Network_Intraday_Cross_and_Post_Close_Cross => "'I' (Nasdaq Cross Network Intraday Cross and Post Close Cross)",
+      eCrossType::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eImbalanceDirection {
+  buy_imbalance = b'B',
+  sell_imbalance = b'S',
+  no_imbalance = b'N',
+  Insufficient_orders_to_calculate = b'O',
+  Unknown(u8) = 0,
+} // eImbalanceDirection
+
+impl eImbalanceDirection {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'B' => eImbalanceDirection::buy_imbalance,
+      b'S' => eImbalanceDirection::sell_imbalance,
+      b'N' => eImbalanceDirection::no_imbalance,
+      b'O' => eImbalanceDirection::Insufficient_orders_to_calculate,
+      _ => eImbalanceDirection::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eImbalanceDirection::buy_imbalance => b'B',
+      eImbalanceDirection::sell_imbalance => b'S',
+      eImbalanceDirection::no_imbalance => b'N',
+      eImbalanceDirection::Insufficient_orders_to_calculate => b'O',
+      eImbalanceDirection::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eImbalanceDirection::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eImbalanceDirection::buy_imbalance => "buy_imbalance",
+      eImbalanceDirection::sell_imbalance => "sell_imbalance",
+      eImbalanceDirection::no_imbalance => "no_imbalance",
+      eImbalanceDirection::Insufficient_orders_to_calculate => "Insufficient_orders_to_calculate",
+      eImbalanceDirection::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "buy_imbalance" => Some(eImbalanceDirection::buy_imbalance),
+      "sell_imbalance" => Some(eImbalanceDirection::sell_imbalance),
+      "no_imbalance" => Some(eImbalanceDirection::no_imbalance),
+      "Insufficient_orders_to_calculate" => Some(eImbalanceDirection::Insufficient_orders_to_calculate),
+      _ => None,
+    }
+  }
+} // eImbalanceDirection
+
+impl Default for eImbalanceDirection {
+  fn default() -> Self { eImbalanceDirection::Unknown(0) }
+}
+
+impl TryFrom<u8> for eImbalanceDirection {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eImbalanceDirection::Unknown(code) => Err(UnknownCode { enum_name: "eImbalanceDirection", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eImbalanceDirection> for u8 {
+  fn from(value: eImbalanceDirection) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eImbalanceDirection {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eImbalanceDirection::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eImbalanceDirection {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eImbalanceDirection '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eImbalanceDirection {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      eImbalanceDirection::buy_imbalance => "'B' (buy imbalance)",
+      eImbalanceDirection::sell_imbalance => "'S' (sell imbalance)",
+      eImbalanceDirection::no_imbalance => "'N' (no imbalance)",
+      eImbalanceDirection::Insufficient_orders_to_calculate => "'O' (Insufficient orders to calculate)",
+      eImbalanceDirection::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum ePriceVariationIndicator {
+  less_than_1_pct = b'L',
+  deviation_1_to_2_pct = b'1',
+  deviation_2_to_3_pct = b'2',
+  deviation_3_to_4_pct = b'3',
+  deviation_4_to_5_pct = b'4',
+  deviation_5_to_6_pct = b'5',
+  deviation_6_to_7_pct = b'6',
+  deviation_7_to_8_pct = b'7',
+  deviation_8_to_9_pct = b'8',
+  deviation_9_to_10_pct = b'9',
+  deviation_10_to_20_pct = b'A',
+  deviation_20_to_30_pct = b'B',
+  deviation_30_pct_or_greater = b'C',
+  Cannot_be_calculated = b' ',
+  Unknown(u8) = 0,
+} // ePriceVariationIndicator
+
+impl ePriceVariationIndicator {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'L' => ePriceVariationIndicator::less_than_1_pct,
+      b'1' => ePriceVariationIndicator::deviation_1_to_2_pct,
+      b'2' => ePriceVariationIndicator::deviation_2_to_3_pct,
+      b'3' => ePriceVariationIndicator::deviation_3_to_4_pct,
+      b'4' => ePriceVariationIndicator::deviation_4_to_5_pct,
+      b'5' => ePriceVariationIndicator::deviation_5_to_6_pct,
+      b'6' => ePriceVariationIndicator::deviation_6_to_7_pct,
+      b'7' => ePriceVariationIndicator::deviation_7_to_8_pct,
+      b'8' => ePriceVariationIndicator::deviation_8_to_9_pct,
+      b'9' => ePriceVariationIndicator::deviation_9_to_10_pct,
+      b'A' => ePriceVariationIndicator::deviation_10_to_20_pct,
+      b'B' => ePriceVariationIndicator::deviation_20_to_30_pct,
+      b'C' => ePriceVariationIndicator::deviation_30_pct_or_greater,
+      b' ' => ePriceVariationIndicator::Cannot_be_calculated,
+      _ => ePriceVariationIndicator::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      ePriceVariationIndicator::less_than_1_pct => b'L',
+      ePriceVariationIndicator::deviation_1_to_2_pct => b'1',
+      ePriceVariationIndicator::deviation_2_to_3_pct => b'2',
+      ePriceVariationIndicator::deviation_3_to_4_pct => b'3',
+      ePriceVariationIndicator::deviation_4_to_5_pct => b'4',
+      ePriceVariationIndicator::deviation_5_to_6_pct => b'5',
+      ePriceVariationIndicator::deviation_6_to_7_pct => b'6',
+      ePriceVariationIndicator::deviation_7_to_8_pct => b'7',
+      ePriceVariationIndicator::deviation_8_to_9_pct => b'8',
+      ePriceVariationIndicator::deviation_9_to_10_pct => b'9',
+      ePriceVariationIndicator::deviation_10_to_20_pct => b'A',
+      ePriceVariationIndicator::deviation_20_to_30_pct => b'B',
+      ePriceVariationIndicator::deviation_30_pct_or_greater => b'C',
+      ePriceVariationIndicator::Cannot_be_calculated => b' ',
+      ePriceVariationIndicator::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, ePriceVariationIndicator::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      ePriceVariationIndicator::less_than_1_pct => "less_than_1_pct",
+      ePriceVariationIndicator::deviation_1_to_2_pct => "deviation_1_to_2_pct",
+      ePriceVariationIndicator::deviation_2_to_3_pct => "deviation_2_to_3_pct",
+      ePriceVariationIndicator::deviation_3_to_4_pct => "deviation_3_to_4_pct",
+      ePriceVariationIndicator::deviation_4_to_5_pct => "deviation_4_to_5_pct",
+      ePriceVariationIndicator::deviation_5_to_6_pct => "deviation_5_to_6_pct",
+      ePriceVariationIndicator::deviation_6_to_7_pct => "deviation_6_to_7_pct",
+      ePriceVariationIndicator::deviation_7_to_8_pct => "deviation_7_to_8_pct",
+      ePriceVariationIndicator::deviation_8_to_9_pct => "deviation_8_to_9_pct",
+      ePriceVariationIndicator::deviation_9_to_10_pct => "deviation_9_to_10_pct",
+      ePriceVariationIndicator::deviation_10_to_20_pct => "deviation_10_to_20_pct",
+      ePriceVariationIndicator::deviation_20_to_30_pct => "deviation_20_to_30_pct",
+      ePriceVariationIndicator::deviation_30_pct_or_greater => "deviation_30_pct_or_greater",
+      ePriceVariationIndicator::Cannot_be_calculated => "Cannot_be_calculated",
+      ePriceVariationIndicator::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "less_than_1_pct" => Some(ePriceVariationIndicator::less_than_1_pct),
+      "deviation_1_to_2_pct" => Some(ePriceVariationIndicator::deviation_1_to_2_pct),
+      "deviation_2_to_3_pct" => Some(ePriceVariationIndicator::deviation_2_to_3_pct),
+      "deviation_3_to_4_pct" => Some(ePriceVariationIndicator::deviation_3_to_4_pct),
+      "deviation_4_to_5_pct" => Some(ePriceVariationIndicator::deviation_4_to_5_pct),
+      "deviation_5_to_6_pct" => Some(ePriceVariationIndicator::deviation_5_to_6_pct),
+      "deviation_6_to_7_pct" => Some(ePriceVariationIndicator::deviation_6_to_7_pct),
+      "deviation_7_to_8_pct" => Some(ePriceVariationIndicator::deviation_7_to_8_pct),
+      "deviation_8_to_9_pct" => Some(ePriceVariationIndicator::deviation_8_to_9_pct),
+      "deviation_9_to_10_pct" => Some(ePriceVariationIndicator::deviation_9_to_10_pct),
+      "deviation_10_to_20_pct" => Some(ePriceVariationIndicator::deviation_10_to_20_pct),
+      "deviation_20_to_30_pct" => Some(ePriceVariationIndicator::deviation_20_to_30_pct),
+      "deviation_30_pct_or_greater" => Some(ePriceVariationIndicator::deviation_30_pct_or_greater),
+      "Cannot_be_calculated" => Some(ePriceVariationIndicator::Cannot_be_calculated),
+      _ => None,
+    }
+  }
+} // ePriceVariationIndicator
+
+impl Default for ePriceVariationIndicator {
+  fn default() -> Self { ePriceVariationIndicator::Unknown(0) }
+}
+
+impl TryFrom<u8> for ePriceVariationIndicator {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      ePriceVariationIndicator::Unknown(code) => Err(UnknownCode { enum_name: "ePriceVariationIndicator", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<ePriceVariationIndicator> for u8 {
+  fn from(value: ePriceVariationIndicator) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ePriceVariationIndicator {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      ePriceVariationIndicator::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ePriceVariationIndicator {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown ePriceVariationIndicator '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for ePriceVariationIndicator {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      ePriceVariationIndicator::less_than_1_pct => "'L' (less than 1 pct)",
+      ePriceVariationIndicator::deviation_1_to_2_pct => "'1' (deviation 1 to 2 pct)",
+      ePriceVariationIndicator::deviation_2_to_3_pct => "'2' (deviation 2 to 3 pct)",
+      ePriceVariationIndicator::deviation_3_to_4_pct => "'3' (deviation 3 to 4 pct)",
+      ePriceVariationIndicator::deviation_4_to_5_pct => "'4' (deviation 4 to 5 pct)",
+      ePriceVariationIndicator::deviation_5_to_6_pct => "'5' (deviation 5 to 6 pct)",
+      ePriceVariationIndicator::deviation_6_to_7_pct => "'6' (deviation 6 to 7 pct)",
+      ePriceVariationIndicator::deviation_7_to_8_pct => "'7' (deviation 7 to 8 pct)",
+      ePriceVariationIndicator::deviation_8_to_9_pct => "'8' (deviation 8 to 9 pct)",
+      ePriceVariationIndicator::deviation_9_to_10_pct => "'9' (deviation 9 to 10 pct)",
+      ePriceVariationIndicator::deviation_10_to_20_pct => "'A' (deviation 10 to 20 pct)",
+      ePriceVariationIndicator::deviation_20_to_30_pct => "'B' (deviation 20 to 30 pct)",
+      ePriceVariationIndicator::deviation_30_pct_or_greater => "'C' (deviation 30 pct or greater)",
+      ePriceVariationIndicator::Cannot_be_calculated => "' ' (Cannot be calculated)",
+      ePriceVariationIndicator::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eInterestFlag {
+  RPI_orders_available_on_the_buy_side = b'B',
+  RPI_orders_available_on_the_sell_side = b'S',
+  RPI_orders_available_on_both_sides = b'A',
+  No_RPI_orders_available = b'N',
+  Unknown(u8) = 0,
+} // eInterestFlag
+
+impl eInterestFlag {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'B' => eInterestFlag::RPI_orders_available_on_the_buy_side,
+      b'S' => eInterestFlag::RPI_orders_available_on_the_sell_side,
+      b'A' => eInterestFlag::RPI_orders_available_on_both_sides,
+      b'N' => eInterestFlag::No_RPI_orders_available,
+      _ => eInterestFlag::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eInterestFlag::RPI_orders_available_on_the_buy_side => b'B',
+      eInterestFlag::RPI_orders_available_on_the_sell_side => b'S',
+      eInterestFlag::RPI_orders_available_on_both_sides => b'A',
+      eInterestFlag::No_RPI_orders_available => b'N',
+      eInterestFlag::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eInterestFlag::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eInterestFlag::RPI_orders_available_on_the_buy_side => "RPI_orders_available_on_the_buy_side",
+      eInterestFlag::RPI_orders_available_on_the_sell_side => "RPI_orders_available_on_the_sell_side",
+      eInterestFlag::RPI_orders_available_on_both_sides => "RPI_orders_available_on_both_sides",
+      eInterestFlag::No_RPI_orders_available => "No_RPI_orders_available",
+      eInterestFlag::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "RPI_orders_available_on_the_buy_side" => Some(eInterestFlag::RPI_orders_available_on_the_buy_side),
+      "RPI_orders_available_on_the_sell_side" => Some(eInterestFlag::RPI_orders_available_on_the_sell_side),
+      "RPI_orders_available_on_both_sides" => Some(eInterestFlag::RPI_orders_available_on_both_sides),
+      "No_RPI_orders_available" => Some(eInterestFlag::No_RPI_orders_available),
+      _ => None,
+    }
+  }
+} // eInterestFlag
+
+impl Default for eInterestFlag {
+  fn default() -> Self { eInterestFlag::Unknown(0) }
+}
+
+impl TryFrom<u8> for eInterestFlag {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eInterestFlag::Unknown(code) => Err(UnknownCode { enum_name: "eInterestFlag", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eInterestFlag> for u8 {
+  fn from(value: eInterestFlag) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eInterestFlag {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eInterestFlag::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eInterestFlag {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eInterestFlag '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eInterestFlag {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      eInterestFlag::RPI_orders_available_on_the_buy_side => "'B' (RPI orders available on the buy side)",
+      eInterestFlag::RPI_orders_available_on_the_sell_side => "'S' (RPI orders available on the sell side)",
+      eInterestFlag::RPI_orders_available_on_both_sides => "'A' (RPI orders available on both sides)",
+      eInterestFlag::No_RPI_orders_available => "'N' (No RPI orders available)",
+      eInterestFlag::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum eIssueClassification {
+  American_Depositary_Share = b'A',
+  Bond = b'B',
+  Common_Stock = b'C',
+  Depository_Receipt = b'F',
+  Rule_144A = b'I',
+  Limited_Partnership = b'L',
+  Notes = b'N',
+  Ordinary_Share = b'O',
+  Preferred_Stock = b'P',
+  Other_Securities = b'Q',
+  Right = b'R',
+  Shares_of_Beneficial_Interest = b'S',
+  Convertible_Debenture = b'T',
+  Unit = b'U',
+  Units_Benif_Int = b'V',
+  Warrant = b'W',
+  Unknown(u8) = 0,
+} // eIssueClassification
+
+impl eIssueClassification {
+  pub fn from_code(code: u8) -> Self {
+    match code {
+      b'A' => eIssueClassification::American_Depositary_Share,
+      b'B' => eIssueClassification::Bond,
+      b'C' => eIssueClassification::Common_Stock,
+      b'F' => eIssueClassification::Depository_Receipt,
+      b'I' => eIssueClassification::Rule_144A,
+      b'L' => eIssueClassification::Limited_Partnership,
+      b'N' => eIssueClassification::Notes,
+      b'O' => eIssueClassification::Ordinary_Share,
+      b'P' => eIssueClassification::Preferred_Stock,
+      b'Q' => eIssueClassification::Other_Securities,
+      b'R' => eIssueClassification::Right,
+      b'S' => eIssueClassification::Shares_of_Beneficial_Interest,
+      b'T' => eIssueClassification::Convertible_Debenture,
+      b'U' => eIssueClassification::Unit,
+      b'V' => eIssueClassification::Units_Benif_Int,
+      b'W' => eIssueClassification::Warrant,
+      _ => eIssueClassification::Unknown(code),
+    }
+  }
+  pub fn code(&self) -> u8 {
+    match *self {
+      eIssueClassification::American_Depositary_Share => b'A',
+      eIssueClassification::Bond => b'B',
+      eIssueClassification::Common_Stock => b'C',
+      eIssueClassification::Depository_Receipt => b'F',
+      eIssueClassification::Rule_144A => b'I',
+      eIssueClassification::Limited_Partnership => b'L',
+      eIssueClassification::Notes => b'N',
+      eIssueClassification::Ordinary_Share => b'O',
+      eIssueClassification::Preferred_Stock => b'P',
+      eIssueClassification::Other_Securities => b'Q',
+      eIssueClassification::Right => b'R',
+      eIssueClassification::Shares_of_Beneficial_Interest => b'S',
+      eIssueClassification::Convertible_Debenture => b'T',
+      eIssueClassification::Unit => b'U',
+      eIssueClassification::Units_Benif_Int => b'V',
+      eIssueClassification::Warrant => b'W',
+      eIssueClassification::Unknown(code) => code,
+    }
+  }
+  pub fn is_known(&self) -> bool {
+    !matches!(*self, eIssueClassification::Unknown(_))
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      eIssueClassification::American_Depositary_Share => "American_Depositary_Share",
+      eIssueClassification::Bond => "Bond",
+      eIssueClassification::Common_Stock => "Common_Stock",
+      eIssueClassification::Depository_Receipt => "Depository_Receipt",
+      eIssueClassification::Rule_144A => "Rule_144A",
+      eIssueClassification::Limited_Partnership => "Limited_Partnership",
+      eIssueClassification::Notes => "Notes",
+      eIssueClassification::Ordinary_Share => "Ordinary_Share",
+      eIssueClassification::Preferred_Stock => "Preferred_Stock",
+      eIssueClassification::Other_Securities => "Other_Securities",
+      eIssueClassification::Right => "Right",
+      eIssueClassification::Shares_of_Beneficial_Interest => "Shares_of_Beneficial_Interest",
+      eIssueClassification::Convertible_Debenture => "Convertible_Debenture",
+      eIssueClassification::Unit => "Unit",
+      eIssueClassification::Units_Benif_Int => "Units_Benif_Int",
+      eIssueClassification::Warrant => "Warrant",
+      eIssueClassification::Unknown(_) => "Unknown",
+    }
+  }
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "American_Depositary_Share" => Some(eIssueClassification::American_Depositary_Share),
+      "Bond" => Some(eIssueClassification::Bond),
+      "Common_Stock" => Some(eIssueClassification::Common_Stock),
+      "Depository_Receipt" => Some(eIssueClassification::Depository_Receipt),
+      "Rule_144A" => Some(eIssueClassification::Rule_144A),
+      "Limited_Partnership" => Some(eIssueClassification::Limited_Partnership),
+      "Notes" => Some(eIssueClassification::Notes),
+      "Ordinary_Share" => Some(eIssueClassification::Ordinary_Share),
+      "Preferred_Stock" => Some(eIssueClassification::Preferred_Stock),
+      "Other_Securities" => Some(eIssueClassification::Other_Securities),
+      "Right" => Some(eIssueClassification::Right),
+      "Shares_of_Beneficial_Interest" => Some(eIssueClassification::Shares_of_Beneficial_Interest),
+      "Convertible_Debenture" => Some(eIssueClassification::Convertible_Debenture),
+      "Unit" => Some(eIssueClassification::Unit),
+      "Units_Benif_Int" => Some(eIssueClassification::Units_Benif_Int),
+      "Warrant" => Some(eIssueClassification::Warrant),
+      _ => None,
+    }
+  }
+} // eIssueClassification
+
+impl Default for eIssueClassification {
+  fn default() -> Self { eIssueClassification::Unknown(0) }
+}
+
+impl TryFrom<u8> for eIssueClassification {
+  type Error = UnknownCode;
+  fn try_from(code: u8) -> Result<Self, UnknownCode> {
+    match Self::from_code(code) {
+      eIssueClassification::Unknown(code) => Err(UnknownCode { enum_name: "eIssueClassification", code }),
+      known => Ok(known),
+    }
+  }
+}
+
+impl From<eIssueClassification> for u8 {
+  fn from(value: eIssueClassification) -> u8 { value.code() }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for eIssueClassification {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    match *self {
+      eIssueClassification::Unknown(code) => serializer.serialize_str(&(code as char).to_string()),
+      known => serializer.serialize_str(known.name()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for eIssueClassification {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if let Some(value) = Self::from_name(&s) {
+      return Ok(value);
+    }
+    match s.as_bytes() {
+      [code] => Ok(Self::from_code(*code)),
+      _ => Err(serde::de::Error::custom(format!("unknown eIssueClassification '{}'", s))),
+    }
+  }
+}
+
+impl fmt::Display for eIssueClassification {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      eIssueClassification::American_Depositary_Share => "'A' (American Depositary Share)",
+      eIssueClassification::Bond => "'B' (Bond)",
+      eIssueClassification::Common_Stock => "'C' (Common Stock)",
+      eIssueClassification::Depository_Receipt => "'F' (Depository Receipt)",
+      eIssueClassification::Rule_144A => "'I' (Rule 144A)",
+      eIssueClassification::Limited_Partnership => "'L' (Limited Partnership)",
+      eIssueClassification::Notes => "'N' (Notes)",
+      eIssueClassification::Ordinary_Share => "'O' (Ordinary Share)",
+      eIssueClassification::Preferred_Stock => "'P' (Preferred Stock)",
+      eIssueClassification::Other_Securities => "'Q' (Other Securities)",
+      eIssueClassification::Right => "'R' (Right)",
+      eIssueClassification::Shares_of_Beneficial_Interest => "'S' (Shares of Beneficial Interest)",
+      eIssueClassification::Convertible_Debenture => "'T' (Convertible Debenture)",
+      eIssueClassification::Unit => "'U' (Unit)",
+      eIssueClassification::Units_Benif_Int => "'V' (Units Benif Int)",
+      eIssueClassification::Warrant => "'W' (Warrant)",
+      eIssueClassification::Unknown(_) => "Unknown",
+    };
+    write!(f, "{}", s)
+  }
+}
+
+// Structs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PacketHeader {
+  pub session: Session,
+  pub sequence_number: u64,
+  pub message_count: u16,
+} // PacketHeader
+pub const PACKET_HEADER_SIZE : usize = 20;
+
+impl PacketHeader {
+  pub fn from_bytes(bytes: &[u8]) -> Option<(PacketHeader,usize)> {
+    if bytes.len() < PACKET_HEADER_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(&mut obj.session.0[..10]).unwrap();
+    obj.sequence_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.message_count = rdr.read_u16::<BigEndian>().unwrap();
+    Some((obj, PACKET_HEADER_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<PacketHeader> {
+    let mut obj = Self::default();
+    rdr.read_exact(&mut obj.session.0[..10])?;
+    obj.sequence_number = rdr.read_u64::<BigEndian>()?;
+    obj.message_count = rdr.read_u16::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<PacketHeader, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: PACKET_HEADER_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for PacketHeader {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "PacketHeader(session:{},sequence_number:{},message_count:{})", self.session, self.sequence_number, self.message_count)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageBlock {
+  pub message_length: u16,
+} // MessageBlock
+pub const MESSAGE_BLOCK_SIZE : usize = 2;
+
+impl MessageBlock {
+  #[allow(clippy::field_reassign_with_default)]
+  pub fn from_bytes(bytes: &[u8]) -> Option<(MessageBlock,usize)> {
+    if bytes.len() < MESSAGE_BLOCK_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    obj.message_length = rdr.read_u16::<BigEndian>().unwrap();
+    Some((obj, MESSAGE_BLOCK_SIZE))
+  }
+  #[allow(clippy::field_reassign_with_default)]
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MessageBlock> {
+    let mut obj = Self::default();
+    obj.message_length = rdr.read_u16::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<MessageBlock, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: MESSAGE_BLOCK_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for MessageBlock {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "MessageBlock(message_length:{})", self.message_length)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequestPacket {
+  pub session: Session,
+  pub sequence_number: u64,
+  pub requested_message_count: u16,
+} // RequestPacket
+pub const REQUEST_PACKET_SIZE : usize = 20;
+
+impl RequestPacket {
+  pub fn from_bytes(bytes: &[u8]) -> Option<(RequestPacket,usize)> {
+    if bytes.len() < REQUEST_PACKET_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(&mut obj.session.0[..10]).unwrap();
+    obj.sequence_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.requested_message_count = rdr.read_u16::<BigEndian>().unwrap();
+    Some((obj, REQUEST_PACKET_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RequestPacket> {
+    let mut obj = Self::default();
+    rdr.read_exact(&mut obj.session.0[..10])?;
+    obj.sequence_number = rdr.read_u64::<BigEndian>()?;
+    obj.requested_message_count = rdr.read_u16::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<RequestPacket, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: REQUEST_PACKET_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for RequestPacket {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "RequestPacket(session:{},sequence_number:{},requested_message_count:{})", self.session, self.sequence_number, self.requested_message_count)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemEvent {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub event_code: eSystemEvent,
+} // SystemEvent
+pub const SYSTEM_EVENT_SIZE : usize = 12;
+
+impl SystemEvent {
+  pub const TYPE : u8 = b'S';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(SystemEvent,usize)> {
+    if bytes.len() < SYSTEM_EVENT_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.event_code = eSystemEvent::from_code(rdr.read_u8().unwrap());
+    Some((obj, SYSTEM_EVENT_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<SystemEvent> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.event_code = eSystemEvent::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<SystemEvent, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: SYSTEM_EVENT_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for SystemEvent {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "SystemEvent(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},event_code:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.event_code)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StockDirectory {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub market_category: eMarketCategory,
+  pub financial_status_indicator: eFinancialStatusIndicator,
+  pub round_lot_size: u32,
+  pub round_lots_only: eRoundLotsOnly,
+  pub issue_classification: eIssueClassification,
+  pub issue_sub_type: IssueSubType,
+  pub authenticity: eAuthenticity,
+  pub short_sale_threshold_indicator: eShortSaleThresholdIndicator,
+  pub ipo_flag: eIPOFlag,
+  pub luld_reference_price_tier: eLULDReferencePriceTier,
+  pub etp_flag: eETPFlag,
+  pub etp_leverage_factor: u32,
+  pub inverse_indicator: eInverseIndicator,
+} // StockDirectory
+pub const STOCK_DIRECTORY_SIZE : usize = 39;
+
+impl StockDirectory {
+  pub const TYPE : u8 = b'R';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(StockDirectory,usize)> {
+    if bytes.len() < STOCK_DIRECTORY_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.market_category = eMarketCategory::from_code(rdr.read_u8().unwrap());
+    obj.financial_status_indicator = eFinancialStatusIndicator::from_code(rdr.read_u8().unwrap());
+    obj.round_lot_size = rdr.read_u32::<BigEndian>().unwrap();
+    obj.round_lots_only = eRoundLotsOnly::from_code(rdr.read_u8().unwrap());
+    obj.issue_classification = eIssueClassification::from_code(rdr.read_u8().unwrap());
+    rdr.read_exact(&mut obj.issue_sub_type.0[..2]).unwrap();
+    obj.authenticity = eAuthenticity::from_code(rdr.read_u8().unwrap());
+    obj.short_sale_threshold_indicator = eShortSaleThresholdIndicator::from_code(rdr.read_u8().unwrap());
+    obj.ipo_flag = eIPOFlag::from_code(rdr.read_u8().unwrap());
+    obj.luld_reference_price_tier = eLULDReferencePriceTier::from_code(rdr.read_u8().unwrap());
+    obj.etp_flag = eETPFlag::from_code(rdr.read_u8().unwrap());
+    obj.etp_leverage_factor = rdr.read_u32::<BigEndian>().unwrap();
+    obj.inverse_indicator = eInverseIndicator::from_code(rdr.read_u8().unwrap());
+    Some((obj, STOCK_DIRECTORY_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<StockDirectory> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.market_category = eMarketCategory::from_code(rdr.read_u8()?);
+    obj.financial_status_indicator = eFinancialStatusIndicator::from_code(rdr.read_u8()?);
+    obj.round_lot_size = rdr.read_u32::<BigEndian>()?;
+    obj.round_lots_only = eRoundLotsOnly::from_code(rdr.read_u8()?);
+    obj.issue_classification = eIssueClassification::from_code(rdr.read_u8()?);
+    rdr.read_exact(&mut obj.issue_sub_type.0[..2])?;
+    obj.authenticity = eAuthenticity::from_code(rdr.read_u8()?);
+    obj.short_sale_threshold_indicator = eShortSaleThresholdIndicator::from_code(rdr.read_u8()?);
+    obj.ipo_flag = eIPOFlag::from_code(rdr.read_u8()?);
+    obj.luld_reference_price_tier = eLULDReferencePriceTier::from_code(rdr.read_u8()?);
+    obj.etp_flag = eETPFlag::from_code(rdr.read_u8()?);
+    obj.etp_leverage_factor = rdr.read_u32::<BigEndian>()?;
+    obj.inverse_indicator = eInverseIndicator::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<StockDirectory, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: STOCK_DIRECTORY_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for StockDirectory {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "StockDirectory(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},market_category:{},financial_status_indicator:{},round_lot_size:{},round_lots_only:{},issue_classification:{},issue_sub_type:{},authenticity:{},short_sale_threshold_indicator:{},ipo_flag:{},luld_reference_price_tier:{},etp_flag:{},etp_leverage_factor:{},inverse_indicator:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.market_category, self.financial_status_indicator, self.round_lot_size, self.round_lots_only, self.issue_classification, self.issue_sub_type, self.authenticity, self.short_sale_threshold_indicator, self.ipo_flag, self.luld_reference_price_tier, self.etp_flag, self.etp_leverage_factor, self.inverse_indicator)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StockTradingAction {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub trading_state: eTradingState,
+  pub reserved: u8,
+  pub reason: [u8;4],
+} // StockTradingAction
+pub const STOCK_TRADING_ACTION_SIZE : usize = 25;
+
+impl StockTradingAction {
+  pub const TYPE : u8 = b'H';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(StockTradingAction,usize)> {
+    if bytes.len() < STOCK_TRADING_ACTION_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.trading_state = eTradingState::from_code(rdr.read_u8().unwrap());
+    rdr.read_exact(std::slice::from_mut(&mut obj.reserved)).unwrap();
+    rdr.read_exact(&mut obj.reason[..4]).unwrap();
+    Some((obj, STOCK_TRADING_ACTION_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<StockTradingAction> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.trading_state = eTradingState::from_code(rdr.read_u8()?);
+    rdr.read_exact(std::slice::from_mut(&mut obj.reserved))?;
+    rdr.read_exact(&mut obj.reason[..4])?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<StockTradingAction, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: STOCK_TRADING_ACTION_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for StockTradingAction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "StockTradingAction(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},trading_state:{},reserved:{},reason:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.trading_state, self.reserved, String::from_utf8_lossy(&self.reason[..]))
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegShoRestriction {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub reg_sho_action: eRegSHOAction,
+} // RegShoRestriction
+pub const REG_SHO_RESTRICTION_SIZE : usize = 20;
+
+impl RegShoRestriction {
+  pub const TYPE : u8 = b'Y';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(RegShoRestriction,usize)> {
+    if bytes.len() < REG_SHO_RESTRICTION_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.reg_sho_action = eRegSHOAction::from_code(rdr.read_u8().unwrap());
+    Some((obj, REG_SHO_RESTRICTION_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RegShoRestriction> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.reg_sho_action = eRegSHOAction::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<RegShoRestriction, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: REG_SHO_RESTRICTION_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for RegShoRestriction {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "RegShoRestriction(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},reg_sho_action:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.reg_sho_action)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketParticipantPosition {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub mpid: Mpid,
+  pub stock: Symbol,
+  pub primary_market_maker: ePrimaryMarketMaker,
+  pub market_maker_mode: eMarketMakerMode,
+  pub market_participant_state: eMarketParticipantState,
+} // MarketParticipantPosition
+pub const MARKET_PARTICIPANT_POSITION_SIZE : usize = 26;
+
+impl MarketParticipantPosition {
+  pub const TYPE : u8 = b'L';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(MarketParticipantPosition,usize)> {
+    if bytes.len() < MARKET_PARTICIPANT_POSITION_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.mpid.0[..4]).unwrap();
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.primary_market_maker = ePrimaryMarketMaker::from_code(rdr.read_u8().unwrap());
+    obj.market_maker_mode = eMarketMakerMode::from_code(rdr.read_u8().unwrap());
+    obj.market_participant_state = eMarketParticipantState::from_code(rdr.read_u8().unwrap());
+    Some((obj, MARKET_PARTICIPANT_POSITION_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MarketParticipantPosition> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.mpid.0[..4])?;
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.primary_market_maker = ePrimaryMarketMaker::from_code(rdr.read_u8()?);
+    obj.market_maker_mode = eMarketMakerMode::from_code(rdr.read_u8()?);
+    obj.market_participant_state = eMarketParticipantState::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<MarketParticipantPosition, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: MARKET_PARTICIPANT_POSITION_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for MarketParticipantPosition {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "MarketParticipantPosition(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},mpid:{},stock:{},primary_market_maker:{},market_maker_mode:{},market_participant_state:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.mpid, self.stock, self.primary_market_maker, self.market_maker_mode, self.market_participant_state)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MwcbDeclineLevel {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub level_1: Price8,
+  pub level_2: Price8,
+  pub level_3: Price8,
+} // MwcbDeclineLevel
+pub const MWCB_DECLINE_LEVEL_SIZE : usize = 35;
+
+impl MwcbDeclineLevel {
+  pub const TYPE : u8 = b'V';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(MwcbDeclineLevel,usize)> {
+    if bytes.len() < MWCB_DECLINE_LEVEL_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.level_1 = Price8(rdr.read_u64::<BigEndian>().unwrap());
+    obj.level_2 = Price8(rdr.read_u64::<BigEndian>().unwrap());
+    obj.level_3 = Price8(rdr.read_u64::<BigEndian>().unwrap());
+    Some((obj, MWCB_DECLINE_LEVEL_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MwcbDeclineLevel> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.level_1 = Price8(rdr.read_u64::<BigEndian>()?);
+    obj.level_2 = Price8(rdr.read_u64::<BigEndian>()?);
+    obj.level_3 = Price8(rdr.read_u64::<BigEndian>()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<MwcbDeclineLevel, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: MWCB_DECLINE_LEVEL_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for MwcbDeclineLevel {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "MwcbDeclineLevel(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},level_1:{},level_2:{},level_3:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.level_1, self.level_2, self.level_3)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MwcbStatus {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub breached_level: eBreachedLevel,
+} // MwcbStatus
+pub const MWCB_STATUS_SIZE : usize = 12;
+
+impl MwcbStatus {
+  pub const TYPE : u8 = b'W';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(MwcbStatus,usize)> {
+    if bytes.len() < MWCB_STATUS_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.breached_level = eBreachedLevel::from_code(rdr.read_u8().unwrap());
+    Some((obj, MWCB_STATUS_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<MwcbStatus> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.breached_level = eBreachedLevel::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<MwcbStatus, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: MWCB_STATUS_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for MwcbStatus {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "MwcbStatus(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},breached_level:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.breached_level)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpoQuotingPeriodUpdate {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub ipo_quotation_release_time: u32,
+  pub ipo_quotation_release_qualifier: eIPOQuotationReleaseQualifier,
+  pub ipo_price: Price4,
+} // IpoQuotingPeriodUpdate
+pub const IPO_QUOTING_PERIOD_UPDATE_SIZE : usize = 28;
+
+impl IpoQuotingPeriodUpdate {
+  pub const TYPE : u8 = b'K';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(IpoQuotingPeriodUpdate,usize)> {
+    if bytes.len() < IPO_QUOTING_PERIOD_UPDATE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.ipo_quotation_release_time = rdr.read_u32::<BigEndian>().unwrap();
+    obj.ipo_quotation_release_qualifier = eIPOQuotationReleaseQualifier::from_code(rdr.read_u8().unwrap());
+    obj.ipo_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    Some((obj, IPO_QUOTING_PERIOD_UPDATE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<IpoQuotingPeriodUpdate> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.ipo_quotation_release_time = rdr.read_u32::<BigEndian>()?;
+    obj.ipo_quotation_release_qualifier = eIPOQuotationReleaseQualifier::from_code(rdr.read_u8()?);
+    obj.ipo_price = Price4(rdr.read_u32::<BigEndian>()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<IpoQuotingPeriodUpdate, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: IPO_QUOTING_PERIOD_UPDATE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for IpoQuotingPeriodUpdate {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "IpoQuotingPeriodUpdate(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},ipo_quotation_release_time:{},ipo_quotation_release_qualifier:{},ipo_price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.ipo_quotation_release_time, self.ipo_quotation_release_qualifier, self.ipo_price)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuldAuctionCollar {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub auction_collar_reference_price: Price4,
+  pub upper_auction_collar_price: Price4,
+  pub lower_auction_collar_price: Price4,
+  pub auction_collar_extension: u32,
+} // LuldAuctionCollar
+pub const LULD_AUCTION_COLLAR_SIZE : usize = 35;
+
+impl LuldAuctionCollar {
+  pub const TYPE : u8 = b'J';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(LuldAuctionCollar,usize)> {
+    if bytes.len() < LULD_AUCTION_COLLAR_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.auction_collar_reference_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.upper_auction_collar_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.lower_auction_collar_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.auction_collar_extension = rdr.read_u32::<BigEndian>().unwrap();
+    Some((obj, LULD_AUCTION_COLLAR_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<LuldAuctionCollar> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.auction_collar_reference_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.upper_auction_collar_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.lower_auction_collar_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.auction_collar_extension = rdr.read_u32::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<LuldAuctionCollar, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: LULD_AUCTION_COLLAR_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for LuldAuctionCollar {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "LuldAuctionCollar(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},auction_collar_reference_price:{},upper_auction_collar_price:{},lower_auction_collar_price:{},auction_collar_extension:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.auction_collar_reference_price, self.upper_auction_collar_price, self.lower_auction_collar_price, self.auction_collar_extension)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationalHalt {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub market_code: eMarketCode,
+  pub operational_halt_action: eOperationalHaltAction,
+} // OperationalHalt
+pub const OPERATIONAL_HALT_SIZE : usize = 21;
+
+impl OperationalHalt {
+  pub const TYPE : u8 = b'h';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OperationalHalt,usize)> {
+    if bytes.len() < OPERATIONAL_HALT_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.market_code = eMarketCode::from_code(rdr.read_u8().unwrap());
+    obj.operational_halt_action = eOperationalHaltAction::from_code(rdr.read_u8().unwrap());
+    Some((obj, OPERATIONAL_HALT_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OperationalHalt> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.market_code = eMarketCode::from_code(rdr.read_u8()?);
+    obj.operational_halt_action = eOperationalHaltAction::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OperationalHalt, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: OPERATIONAL_HALT_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OperationalHalt {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OperationalHalt(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},market_code:{},operational_halt_action:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.market_code, self.operational_halt_action)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddOrder {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub buy_sell_indicator: eBuySellIndicator,
+  pub shares: u32,
+  pub stock: Symbol,
+  pub price: Price4,
+} // AddOrder
+pub const ADD_ORDER_SIZE : usize = 36;
+
+impl AddOrder {
+  pub const TYPE : u8 = b'A';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(AddOrder,usize)> {
+    if bytes.len() < ADD_ORDER_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8().unwrap());
+    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    Some((obj, ADD_ORDER_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<AddOrder> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8()?);
+    obj.shares = rdr.read_u32::<BigEndian>()?;
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.price = Price4(rdr.read_u32::<BigEndian>()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<AddOrder, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ADD_ORDER_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for AddOrder {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "AddOrder(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, self.stock, self.price)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddOrderWithMpid {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub buy_sell_indicator: eBuySellIndicator,
+  pub shares: u32,
+  pub stock: Symbol,
+  pub price: Price4,
+  pub attribution: Mpid,
+} // AddOrderWithMpid
+pub const ADD_ORDER_WITH_MPID_SIZE : usize = 40;
+
+impl AddOrderWithMpid {
+  pub const TYPE : u8 = b'F';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(AddOrderWithMpid,usize)> {
+    if bytes.len() < ADD_ORDER_WITH_MPID_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8().unwrap());
+    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    rdr.read_exact(&mut obj.attribution.0[..4]).unwrap();
+    Some((obj, ADD_ORDER_WITH_MPID_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<AddOrderWithMpid> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8()?);
+    obj.shares = rdr.read_u32::<BigEndian>()?;
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.price = Price4(rdr.read_u32::<BigEndian>()?);
+    rdr.read_exact(&mut obj.attribution.0[..4])?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<AddOrderWithMpid, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ADD_ORDER_WITH_MPID_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for AddOrderWithMpid {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "AddOrderWithMpid(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{},attribution:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, self.stock, self.price, self.attribution)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExecuted {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub executed_shares: u32,
+  pub match_number: u64,
+} // OrderExecuted
+pub const ORDER_EXECUTED_SIZE : usize = 31;
+
+impl OrderExecuted {
+  pub const TYPE : u8 = b'E';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderExecuted,usize)> {
+    if bytes.len() < ORDER_EXECUTED_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.executed_shares = rdr.read_u32::<BigEndian>().unwrap();
+    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
+    Some((obj, ORDER_EXECUTED_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderExecuted> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.executed_shares = rdr.read_u32::<BigEndian>()?;
+    obj.match_number = rdr.read_u64::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OrderExecuted, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ORDER_EXECUTED_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OrderExecuted {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OrderExecuted(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},executed_shares:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.executed_shares, self.match_number)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExecutedWithPrice {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub executed_shares: u32,
+  pub match_number: u64,
+  pub printable: ePrintable,
+  pub execution_price: Price4,
+} // OrderExecutedWithPrice
+pub const ORDER_EXECUTED_WITH_PRICE_SIZE : usize = 36;
+
+impl OrderExecutedWithPrice {
+  pub const TYPE : u8 = b'C';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderExecutedWithPrice,usize)> {
+    if bytes.len() < ORDER_EXECUTED_WITH_PRICE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.executed_shares = rdr.read_u32::<BigEndian>().unwrap();
+    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.printable = ePrintable::from_code(rdr.read_u8().unwrap());
+    obj.execution_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    Some((obj, ORDER_EXECUTED_WITH_PRICE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderExecutedWithPrice> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.executed_shares = rdr.read_u32::<BigEndian>()?;
+    obj.match_number = rdr.read_u64::<BigEndian>()?;
+    obj.printable = ePrintable::from_code(rdr.read_u8()?);
+    obj.execution_price = Price4(rdr.read_u32::<BigEndian>()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OrderExecutedWithPrice, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ORDER_EXECUTED_WITH_PRICE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OrderExecutedWithPrice {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OrderExecutedWithPrice(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},executed_shares:{},match_number:{},printable:{},execution_price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.executed_shares, self.match_number, self.printable, self.execution_price)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderCancel {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub cancelled_shares: u32,
+} // OrderCancel
+pub const ORDER_CANCEL_SIZE : usize = 23;
+
+impl OrderCancel {
+  pub const TYPE : u8 = b'X';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderCancel,usize)> {
+    if bytes.len() < ORDER_CANCEL_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.cancelled_shares = rdr.read_u32::<BigEndian>().unwrap();
+    Some((obj, ORDER_CANCEL_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderCancel> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.cancelled_shares = rdr.read_u32::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OrderCancel, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ORDER_CANCEL_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OrderCancel {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OrderCancel(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},cancelled_shares:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.cancelled_shares)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderDelete {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+} // OrderDelete
+pub const ORDER_DELETE_SIZE : usize = 19;
+
+impl OrderDelete {
+  pub const TYPE : u8 = b'D';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderDelete,usize)> {
+    if bytes.len() < ORDER_DELETE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    Some((obj, ORDER_DELETE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderDelete> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OrderDelete, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ORDER_DELETE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OrderDelete {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OrderDelete(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderReplace {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub original_order_reference_number: u64,
+  pub new_order_reference_number: u64,
+  pub shares: u32,
+  pub price: Price4,
+} // OrderReplace
+pub const ORDER_REPLACE_SIZE : usize = 35;
+
+impl OrderReplace {
+  pub const TYPE : u8 = b'U';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(OrderReplace,usize)> {
+    if bytes.len() < ORDER_REPLACE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.original_order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.new_order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
+    obj.price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    Some((obj, ORDER_REPLACE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<OrderReplace> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.original_order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.new_order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.shares = rdr.read_u32::<BigEndian>()?;
+    obj.price = Price4(rdr.read_u32::<BigEndian>()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<OrderReplace, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: ORDER_REPLACE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for OrderReplace {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "OrderReplace(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},original_order_reference_number:{},new_order_reference_number:{},shares:{},price:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.original_order_reference_number, self.new_order_reference_number, self.shares, self.price)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trade {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub order_reference_number: u64,
+  pub buy_sell_indicator: eBuySellIndicator,
+  pub shares: u32,
+  pub stock: Symbol,
+  pub price: Price4,
+  pub match_number: u64,
+} // Trade
+pub const TRADE_SIZE : usize = 44;
+
+impl Trade {
+  pub const TYPE : u8 = b'P';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(Trade,usize)> {
+    if bytes.len() < TRADE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8().unwrap());
+    obj.shares = rdr.read_u32::<BigEndian>().unwrap();
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
+    Some((obj, TRADE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<Trade> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.order_reference_number = rdr.read_u64::<BigEndian>()?;
+    obj.buy_sell_indicator = eBuySellIndicator::from_code(rdr.read_u8()?);
+    obj.shares = rdr.read_u32::<BigEndian>()?;
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.match_number = rdr.read_u64::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<Trade, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: TRADE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for Trade {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "Trade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},order_reference_number:{},buy_sell_indicator:{},shares:{},stock:{},price:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.order_reference_number, self.buy_sell_indicator, self.shares, self.stock, self.price, self.match_number)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrossTrade {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub shares: u64,
+  pub stock: Symbol,
+  pub cross_price: Price4,
+  pub match_number: u64,
+  pub cross_type: eCrossType,
+} // CrossTrade
+pub const CROSS_TRADE_SIZE : usize = 40;
+
+impl CrossTrade {
+  pub const TYPE : u8 = b'Q';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(CrossTrade,usize)> {
+    if bytes.len() < CROSS_TRADE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.shares = rdr.read_u64::<BigEndian>().unwrap();
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.cross_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
+    obj.cross_type = eCrossType::from_code(rdr.read_u8().unwrap());
+    Some((obj, CROSS_TRADE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<CrossTrade> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.shares = rdr.read_u64::<BigEndian>()?;
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.cross_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.match_number = rdr.read_u64::<BigEndian>()?;
+    obj.cross_type = eCrossType::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<CrossTrade, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: CROSS_TRADE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for CrossTrade {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "CrossTrade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},shares:{},stock:{},cross_price:{},match_number:{},cross_type:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.shares, self.stock, self.cross_price, self.match_number, self.cross_type)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrokenTrade {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub match_number: u64,
+} // BrokenTrade
+pub const BROKEN_TRADE_SIZE : usize = 19;
+
+impl BrokenTrade {
+  pub const TYPE : u8 = b'B';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(BrokenTrade,usize)> {
+    if bytes.len() < BROKEN_TRADE_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.match_number = rdr.read_u64::<BigEndian>().unwrap();
+    Some((obj, BROKEN_TRADE_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<BrokenTrade> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.match_number = rdr.read_u64::<BigEndian>()?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<BrokenTrade, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: BROKEN_TRADE_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for BrokenTrade {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "BrokenTrade(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},match_number:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.match_number)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetOrderImbalanceIndicator {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub paired_shares: u64,
+  pub imbalance_shares: u64,
+  pub imbalance_direction: eImbalanceDirection,
+  pub stock: Symbol,
+  pub far_price: Price4,
+  pub near_price: Price4,
+  pub current_reference_price: Price4,
+  pub cross_type: eCrossType,
+  pub price_variation_indicator: ePriceVariationIndicator,
+} // NetOrderImbalanceIndicator
+pub const NET_ORDER_IMBALANCE_INDICATOR_SIZE : usize = 50;
+
+impl NetOrderImbalanceIndicator {
+  pub const TYPE : u8 = b'I';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(NetOrderImbalanceIndicator,usize)> {
+    if bytes.len() < NET_ORDER_IMBALANCE_INDICATOR_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.paired_shares = rdr.read_u64::<BigEndian>().unwrap();
+    obj.imbalance_shares = rdr.read_u64::<BigEndian>().unwrap();
+    obj.imbalance_direction = eImbalanceDirection::from_code(rdr.read_u8().unwrap());
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.far_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.near_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.current_reference_price = Price4(rdr.read_u32::<BigEndian>().unwrap());
+    obj.cross_type = eCrossType::from_code(rdr.read_u8().unwrap());
+    obj.price_variation_indicator = ePriceVariationIndicator::from_code(rdr.read_u8().unwrap());
+    Some((obj, NET_ORDER_IMBALANCE_INDICATOR_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<NetOrderImbalanceIndicator> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    obj.paired_shares = rdr.read_u64::<BigEndian>()?;
+    obj.imbalance_shares = rdr.read_u64::<BigEndian>()?;
+    obj.imbalance_direction = eImbalanceDirection::from_code(rdr.read_u8()?);
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.far_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.near_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.current_reference_price = Price4(rdr.read_u32::<BigEndian>()?);
+    obj.cross_type = eCrossType::from_code(rdr.read_u8()?);
+    obj.price_variation_indicator = ePriceVariationIndicator::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<NetOrderImbalanceIndicator, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: NET_ORDER_IMBALANCE_INDICATOR_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for NetOrderImbalanceIndicator {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "NetOrderImbalanceIndicator(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},paired_shares:{},imbalance_shares:{},imbalance_direction:{},stock:{},far_price:{},near_price:{},current_reference_price:{},cross_type:{},price_variation_indicator:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.paired_shares, self.imbalance_shares, self.imbalance_direction, self.stock, self.far_price, self.near_price, self.current_reference_price, self.cross_type, self.price_variation_indicator)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetailPriceImprovementIndicator {
+  pub message_type: u8,
+  pub stock_locate: u16,
+  pub tracking_number: u16,
+  pub timestamp: ItchTimestamp,
+  pub stock: Symbol,
+  pub interest_flag: eInterestFlag,
+} // RetailPriceImprovementIndicator
+pub const RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE : usize = 20;
+
+impl RetailPriceImprovementIndicator {
+  pub const TYPE : u8 = b'N';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(RetailPriceImprovementIndicator,usize)> {
+    if bytes.len() < RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
+    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6]).unwrap();
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8]).unwrap();
+    obj.interest_flag = eInterestFlag::from_code(rdr.read_u8().unwrap());
+    Some((obj, RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RetailPriceImprovementIndicator> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
+    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
+    let mut the_u48 = [0u8; 6];
+    rdr.read_exact(&mut the_u48[..6])?;
+    obj.timestamp = ItchTimestamp(u48_to_u64(&the_u48));
+    rdr.read_exact(&mut obj.stock.0[..8])?;
+    obj.interest_flag = eInterestFlag::from_code(rdr.read_u8()?);
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<RetailPriceImprovementIndicator, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for RetailPriceImprovementIndicator {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "RetailPriceImprovementIndicator(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},interest_flag:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.stock, self.interest_flag)
+  }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EndOfSnapshot {
+  pub message_type: u8,
+  pub sequence_number: [u8;20],
+} // EndOfSnapshot
+pub const END_OF_SNAPSHOT_SIZE : usize = 21;
+
+impl EndOfSnapshot {
+  pub const TYPE : u8 = b'G';
+  pub fn from_bytes(bytes: &[u8]) -> Option<(EndOfSnapshot,usize)> {
+    if bytes.len() < END_OF_SNAPSHOT_SIZE {
+      return None;
+    }
+    let mut rdr = Cursor::new(bytes);
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
+    rdr.read_exact(&mut obj.sequence_number[..20]).unwrap();
+    Some((obj, END_OF_SNAPSHOT_SIZE))
+  }
+  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<EndOfSnapshot> {
+    let mut obj = Self::default();
+    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
+    rdr.read_exact(&mut obj.sequence_number[..20])?;
+    Ok(obj)
+  }
+  pub fn decode(bytes: &[u8]) -> Result<EndOfSnapshot, DecodeError> {
+    if bytes.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if bytes[0] != Self::TYPE {
+      return Err(DecodeError::WrongType { type_byte: bytes[0], expected: Self::TYPE, offset: 0 });
+    }
+    match Self::from_bytes(bytes) {
+      Some((obj, _)) => Ok(obj),
+      None => Err(DecodeError::Truncated { type_byte: bytes[0], expected: END_OF_SNAPSHOT_SIZE, actual: bytes.len(), offset: 0 }),
+    }
+  }
+}
+impl fmt::Display for EndOfSnapshot {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "EndOfSnapshot(message_type:{},sequence_number:{})", self.message_type, String::from_utf8_lossy(&self.sequence_number[..]))
+  }
+}
+// Views
+#[derive(Clone, Copy)]
+pub struct PacketHeaderView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> PacketHeaderView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<PacketHeaderView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data.len() < PACKET_HEADER_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: PACKET_HEADER_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..PACKET_HEADER_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[PACKET_HEADER_SIZE..]
+  }
+  pub fn session(&self) -> Session {
+    Session::from_bytes(self.data[0..10].try_into().unwrap())
+  }
+  pub fn sequence_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[10..18].try_into().unwrap())
+  }
+  pub fn message_count(&self) -> u16 {
+    u16::from_be_bytes(self.data[18..20].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> PacketHeader {
+    PacketHeader {
+      session: self.session(),
+      sequence_number: self.sequence_number(),
+      message_count: self.message_count(),
+    }
+  }
+} // PacketHeaderView
+
+#[derive(Clone, Copy)]
+pub struct MessageBlockView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> MessageBlockView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<MessageBlockView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data.len() < MESSAGE_BLOCK_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: MESSAGE_BLOCK_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..MESSAGE_BLOCK_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[MESSAGE_BLOCK_SIZE..]
+  }
+  pub fn message_length(&self) -> u16 {
+    u16::from_be_bytes(self.data[0..2].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> MessageBlock {
+    MessageBlock {
+      message_length: self.message_length(),
+    }
+  }
+} // MessageBlockView
+
+#[derive(Clone, Copy)]
+pub struct RequestPacketView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> RequestPacketView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<RequestPacketView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data.len() < REQUEST_PACKET_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: REQUEST_PACKET_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..REQUEST_PACKET_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[REQUEST_PACKET_SIZE..]
+  }
+  pub fn session(&self) -> Session {
+    Session::from_bytes(self.data[0..10].try_into().unwrap())
+  }
+  pub fn sequence_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[10..18].try_into().unwrap())
+  }
+  pub fn requested_message_count(&self) -> u16 {
+    u16::from_be_bytes(self.data[18..20].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> RequestPacket {
+    RequestPacket {
+      session: self.session(),
+      sequence_number: self.sequence_number(),
+      requested_message_count: self.requested_message_count(),
+    }
+  }
+} // RequestPacketView
+
+#[derive(Clone, Copy)]
+pub struct SystemEventView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> SystemEventView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<SystemEventView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != SystemEvent::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: SystemEvent::TYPE, offset: 0 });
+    }
+    if data.len() < SYSTEM_EVENT_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: SYSTEM_EVENT_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..SYSTEM_EVENT_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[SYSTEM_EVENT_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn event_code(&self) -> eSystemEvent {
+    eSystemEvent::from_code(self.data[11])
+  }
+  pub fn to_message(&self) -> SystemEvent {
+    SystemEvent {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      event_code: self.event_code(),
+    }
+  }
+} // SystemEventView
+
+#[derive(Clone, Copy)]
+pub struct StockDirectoryView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> StockDirectoryView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<StockDirectoryView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != StockDirectory::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: StockDirectory::TYPE, offset: 0 });
+    }
+    if data.len() < STOCK_DIRECTORY_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: STOCK_DIRECTORY_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..STOCK_DIRECTORY_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[STOCK_DIRECTORY_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn market_category(&self) -> eMarketCategory {
+    eMarketCategory::from_code(self.data[19])
+  }
+  pub fn financial_status_indicator(&self) -> eFinancialStatusIndicator {
+    eFinancialStatusIndicator::from_code(self.data[20])
+  }
+  pub fn round_lot_size(&self) -> u32 {
+    u32::from_be_bytes(self.data[21..25].try_into().unwrap())
+  }
+  pub fn round_lots_only(&self) -> eRoundLotsOnly {
+    eRoundLotsOnly::from_code(self.data[25])
+  }
+  pub fn issue_classification(&self) -> eIssueClassification {
+    eIssueClassification::from_code(self.data[26])
+  }
+  pub fn issue_sub_type(&self) -> IssueSubType {
+    IssueSubType::from_bytes(self.data[27..29].try_into().unwrap())
+  }
+  pub fn authenticity(&self) -> eAuthenticity {
+    eAuthenticity::from_code(self.data[29])
+  }
+  pub fn short_sale_threshold_indicator(&self) -> eShortSaleThresholdIndicator {
+    eShortSaleThresholdIndicator::from_code(self.data[30])
+  }
+  pub fn ipo_flag(&self) -> eIPOFlag {
+    eIPOFlag::from_code(self.data[31])
+  }
+  pub fn luld_reference_price_tier(&self) -> eLULDReferencePriceTier {
+    eLULDReferencePriceTier::from_code(self.data[32])
+  }
+  pub fn etp_flag(&self) -> eETPFlag {
+    eETPFlag::from_code(self.data[33])
+  }
+  pub fn etp_leverage_factor(&self) -> u32 {
+    u32::from_be_bytes(self.data[34..38].try_into().unwrap())
+  }
+  pub fn inverse_indicator(&self) -> eInverseIndicator {
+    eInverseIndicator::from_code(self.data[38])
+  }
+  pub fn to_message(&self) -> StockDirectory {
+    StockDirectory {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      market_category: self.market_category(),
+      financial_status_indicator: self.financial_status_indicator(),
+      round_lot_size: self.round_lot_size(),
+      round_lots_only: self.round_lots_only(),
+      issue_classification: self.issue_classification(),
+      issue_sub_type: self.issue_sub_type(),
+      authenticity: self.authenticity(),
+      short_sale_threshold_indicator: self.short_sale_threshold_indicator(),
+      ipo_flag: self.ipo_flag(),
+      luld_reference_price_tier: self.luld_reference_price_tier(),
+      etp_flag: self.etp_flag(),
+      etp_leverage_factor: self.etp_leverage_factor(),
+      inverse_indicator: self.inverse_indicator(),
+    }
+  }
+} // StockDirectoryView
+
+#[derive(Clone, Copy)]
+pub struct StockTradingActionView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> StockTradingActionView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<StockTradingActionView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != StockTradingAction::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: StockTradingAction::TYPE, offset: 0 });
+    }
+    if data.len() < STOCK_TRADING_ACTION_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: STOCK_TRADING_ACTION_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..STOCK_TRADING_ACTION_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[STOCK_TRADING_ACTION_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn trading_state(&self) -> eTradingState {
+    eTradingState::from_code(self.data[19])
+  }
+  pub fn reserved(&self) -> u8 {
+    self.data[20]
+  }
+  pub fn reason(&self) -> &'a [u8;4] {
+    self.data[21..25].try_into().unwrap()
+  }
+  pub fn to_message(&self) -> StockTradingAction {
+    StockTradingAction {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      trading_state: self.trading_state(),
+      reserved: self.reserved(),
+      reason: *self.reason(),
+    }
+  }
+} // StockTradingActionView
+
+#[derive(Clone, Copy)]
+pub struct RegShoRestrictionView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> RegShoRestrictionView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<RegShoRestrictionView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != RegShoRestriction::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: RegShoRestriction::TYPE, offset: 0 });
+    }
+    if data.len() < REG_SHO_RESTRICTION_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: REG_SHO_RESTRICTION_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..REG_SHO_RESTRICTION_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[REG_SHO_RESTRICTION_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn reg_sho_action(&self) -> eRegSHOAction {
+    eRegSHOAction::from_code(self.data[19])
+  }
+  pub fn to_message(&self) -> RegShoRestriction {
+    RegShoRestriction {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      reg_sho_action: self.reg_sho_action(),
+    }
+  }
+} // RegShoRestrictionView
+
+#[derive(Clone, Copy)]
+pub struct MarketParticipantPositionView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> MarketParticipantPositionView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<MarketParticipantPositionView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != MarketParticipantPosition::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: MarketParticipantPosition::TYPE, offset: 0 });
+    }
+    if data.len() < MARKET_PARTICIPANT_POSITION_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: MARKET_PARTICIPANT_POSITION_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..MARKET_PARTICIPANT_POSITION_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[MARKET_PARTICIPANT_POSITION_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn mpid(&self) -> Mpid {
+    Mpid::from_bytes(self.data[11..15].try_into().unwrap())
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[15..23].try_into().unwrap())
+  }
+  pub fn primary_market_maker(&self) -> ePrimaryMarketMaker {
+    ePrimaryMarketMaker::from_code(self.data[23])
+  }
+  pub fn market_maker_mode(&self) -> eMarketMakerMode {
+    eMarketMakerMode::from_code(self.data[24])
+  }
+  pub fn market_participant_state(&self) -> eMarketParticipantState {
+    eMarketParticipantState::from_code(self.data[25])
+  }
+  pub fn to_message(&self) -> MarketParticipantPosition {
+    MarketParticipantPosition {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      mpid: self.mpid(),
+      stock: self.stock(),
+      primary_market_maker: self.primary_market_maker(),
+      market_maker_mode: self.market_maker_mode(),
+      market_participant_state: self.market_participant_state(),
+    }
+  }
+} // MarketParticipantPositionView
+
+#[derive(Clone, Copy)]
+pub struct MwcbDeclineLevelView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> MwcbDeclineLevelView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<MwcbDeclineLevelView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != MwcbDeclineLevel::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: MwcbDeclineLevel::TYPE, offset: 0 });
+    }
+    if data.len() < MWCB_DECLINE_LEVEL_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: MWCB_DECLINE_LEVEL_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..MWCB_DECLINE_LEVEL_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[MWCB_DECLINE_LEVEL_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn level_1(&self) -> Price8 {
+    Price8(u64::from_be_bytes(self.data[11..19].try_into().unwrap()))
+  }
+  pub fn level_2(&self) -> Price8 {
+    Price8(u64::from_be_bytes(self.data[19..27].try_into().unwrap()))
+  }
+  pub fn level_3(&self) -> Price8 {
+    Price8(u64::from_be_bytes(self.data[27..35].try_into().unwrap()))
+  }
+  pub fn to_message(&self) -> MwcbDeclineLevel {
+    MwcbDeclineLevel {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      level_1: self.level_1(),
+      level_2: self.level_2(),
+      level_3: self.level_3(),
+    }
+  }
+} // MwcbDeclineLevelView
+
+#[derive(Clone, Copy)]
+pub struct MwcbStatusView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> MwcbStatusView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<MwcbStatusView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != MwcbStatus::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: MwcbStatus::TYPE, offset: 0 });
+    }
+    if data.len() < MWCB_STATUS_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: MWCB_STATUS_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..MWCB_STATUS_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[MWCB_STATUS_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn breached_level(&self) -> eBreachedLevel {
+    eBreachedLevel::from_code(self.data[11])
+  }
+  pub fn to_message(&self) -> MwcbStatus {
+    MwcbStatus {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      breached_level: self.breached_level(),
+    }
+  }
+} // MwcbStatusView
+
+#[derive(Clone, Copy)]
+pub struct IpoQuotingPeriodUpdateView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> IpoQuotingPeriodUpdateView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<IpoQuotingPeriodUpdateView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != IpoQuotingPeriodUpdate::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: IpoQuotingPeriodUpdate::TYPE, offset: 0 });
+    }
+    if data.len() < IPO_QUOTING_PERIOD_UPDATE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: IPO_QUOTING_PERIOD_UPDATE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..IPO_QUOTING_PERIOD_UPDATE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[IPO_QUOTING_PERIOD_UPDATE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn ipo_quotation_release_time(&self) -> u32 {
+    u32::from_be_bytes(self.data[19..23].try_into().unwrap())
+  }
+  pub fn ipo_quotation_release_qualifier(&self) -> eIPOQuotationReleaseQualifier {
+    eIPOQuotationReleaseQualifier::from_code(self.data[23])
+  }
+  pub fn ipo_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[24..28].try_into().unwrap()))
+  }
+  pub fn to_message(&self) -> IpoQuotingPeriodUpdate {
+    IpoQuotingPeriodUpdate {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      ipo_quotation_release_time: self.ipo_quotation_release_time(),
+      ipo_quotation_release_qualifier: self.ipo_quotation_release_qualifier(),
+      ipo_price: self.ipo_price(),
+    }
+  }
+} // IpoQuotingPeriodUpdateView
+
+#[derive(Clone, Copy)]
+pub struct LuldAuctionCollarView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> LuldAuctionCollarView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<LuldAuctionCollarView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != LuldAuctionCollar::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: LuldAuctionCollar::TYPE, offset: 0 });
+    }
+    if data.len() < LULD_AUCTION_COLLAR_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: LULD_AUCTION_COLLAR_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..LULD_AUCTION_COLLAR_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[LULD_AUCTION_COLLAR_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn auction_collar_reference_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[19..23].try_into().unwrap()))
+  }
+  pub fn upper_auction_collar_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[23..27].try_into().unwrap()))
+  }
+  pub fn lower_auction_collar_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[27..31].try_into().unwrap()))
+  }
+  pub fn auction_collar_extension(&self) -> u32 {
+    u32::from_be_bytes(self.data[31..35].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> LuldAuctionCollar {
+    LuldAuctionCollar {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      auction_collar_reference_price: self.auction_collar_reference_price(),
+      upper_auction_collar_price: self.upper_auction_collar_price(),
+      lower_auction_collar_price: self.lower_auction_collar_price(),
+      auction_collar_extension: self.auction_collar_extension(),
+    }
+  }
+} // LuldAuctionCollarView
+
+#[derive(Clone, Copy)]
+pub struct OperationalHaltView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OperationalHaltView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OperationalHaltView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OperationalHalt::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OperationalHalt::TYPE, offset: 0 });
+    }
+    if data.len() < OPERATIONAL_HALT_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: OPERATIONAL_HALT_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..OPERATIONAL_HALT_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[OPERATIONAL_HALT_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn market_code(&self) -> eMarketCode {
+    eMarketCode::from_code(self.data[19])
+  }
+  pub fn operational_halt_action(&self) -> eOperationalHaltAction {
+    eOperationalHaltAction::from_code(self.data[20])
+  }
+  pub fn to_message(&self) -> OperationalHalt {
+    OperationalHalt {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      market_code: self.market_code(),
+      operational_halt_action: self.operational_halt_action(),
+    }
+  }
+} // OperationalHaltView
+
+#[derive(Clone, Copy)]
+pub struct AddOrderView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> AddOrderView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<AddOrderView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != AddOrder::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: AddOrder::TYPE, offset: 0 });
+    }
+    if data.len() < ADD_ORDER_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ADD_ORDER_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ADD_ORDER_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ADD_ORDER_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn buy_sell_indicator(&self) -> eBuySellIndicator {
+    eBuySellIndicator::from_code(self.data[19])
+  }
+  pub fn shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[20..24].try_into().unwrap())
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[24..32].try_into().unwrap())
+  }
+  pub fn price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[32..36].try_into().unwrap()))
+  }
+  pub fn to_message(&self) -> AddOrder {
+    AddOrder {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      buy_sell_indicator: self.buy_sell_indicator(),
+      shares: self.shares(),
+      stock: self.stock(),
+      price: self.price(),
+    }
+  }
+} // AddOrderView
+
+#[derive(Clone, Copy)]
+pub struct AddOrderWithMpidView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> AddOrderWithMpidView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<AddOrderWithMpidView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != AddOrderWithMpid::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: AddOrderWithMpid::TYPE, offset: 0 });
+    }
+    if data.len() < ADD_ORDER_WITH_MPID_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ADD_ORDER_WITH_MPID_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ADD_ORDER_WITH_MPID_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ADD_ORDER_WITH_MPID_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn buy_sell_indicator(&self) -> eBuySellIndicator {
+    eBuySellIndicator::from_code(self.data[19])
+  }
+  pub fn shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[20..24].try_into().unwrap())
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[24..32].try_into().unwrap())
+  }
+  pub fn price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[32..36].try_into().unwrap()))
+  }
+  pub fn attribution(&self) -> Mpid {
+    Mpid::from_bytes(self.data[36..40].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> AddOrderWithMpid {
+    AddOrderWithMpid {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      buy_sell_indicator: self.buy_sell_indicator(),
+      shares: self.shares(),
+      stock: self.stock(),
+      price: self.price(),
+      attribution: self.attribution(),
+    }
+  }
+} // AddOrderWithMpidView
+
+#[derive(Clone, Copy)]
+pub struct OrderExecutedView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OrderExecutedView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OrderExecutedView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OrderExecuted::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OrderExecuted::TYPE, offset: 0 });
+    }
+    if data.len() < ORDER_EXECUTED_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ORDER_EXECUTED_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ORDER_EXECUTED_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ORDER_EXECUTED_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn executed_shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[19..23].try_into().unwrap())
+  }
+  pub fn match_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[23..31].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> OrderExecuted {
+    OrderExecuted {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      executed_shares: self.executed_shares(),
+      match_number: self.match_number(),
+    }
+  }
+} // OrderExecutedView
+
+#[derive(Clone, Copy)]
+pub struct OrderExecutedWithPriceView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OrderExecutedWithPriceView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OrderExecutedWithPriceView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OrderExecutedWithPrice::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OrderExecutedWithPrice::TYPE, offset: 0 });
+    }
+    if data.len() < ORDER_EXECUTED_WITH_PRICE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ORDER_EXECUTED_WITH_PRICE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ORDER_EXECUTED_WITH_PRICE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ORDER_EXECUTED_WITH_PRICE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn executed_shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[19..23].try_into().unwrap())
+  }
+  pub fn match_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[23..31].try_into().unwrap())
+  }
+  pub fn printable(&self) -> ePrintable {
+    ePrintable::from_code(self.data[31])
+  }
+  pub fn execution_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[32..36].try_into().unwrap()))
+  }
+  pub fn to_message(&self) -> OrderExecutedWithPrice {
+    OrderExecutedWithPrice {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      executed_shares: self.executed_shares(),
+      match_number: self.match_number(),
+      printable: self.printable(),
+      execution_price: self.execution_price(),
+    }
+  }
+} // OrderExecutedWithPriceView
+
+#[derive(Clone, Copy)]
+pub struct OrderCancelView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OrderCancelView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OrderCancelView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OrderCancel::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OrderCancel::TYPE, offset: 0 });
+    }
+    if data.len() < ORDER_CANCEL_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ORDER_CANCEL_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ORDER_CANCEL_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ORDER_CANCEL_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn cancelled_shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[19..23].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> OrderCancel {
+    OrderCancel {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      cancelled_shares: self.cancelled_shares(),
+    }
+  }
+} // OrderCancelView
+
+#[derive(Clone, Copy)]
+pub struct OrderDeleteView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OrderDeleteView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OrderDeleteView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OrderDelete::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OrderDelete::TYPE, offset: 0 });
+    }
+    if data.len() < ORDER_DELETE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ORDER_DELETE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ORDER_DELETE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ORDER_DELETE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> OrderDelete {
+    OrderDelete {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+    }
+  }
+} // OrderDeleteView
+
+#[derive(Clone, Copy)]
+pub struct OrderReplaceView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> OrderReplaceView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<OrderReplaceView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != OrderReplace::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: OrderReplace::TYPE, offset: 0 });
+    }
+    if data.len() < ORDER_REPLACE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: ORDER_REPLACE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..ORDER_REPLACE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[ORDER_REPLACE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn original_order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn new_order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[19..27].try_into().unwrap())
+  }
+  pub fn shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[27..31].try_into().unwrap())
+  }
+  pub fn price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[31..35].try_into().unwrap()))
+  }
+  pub fn to_message(&self) -> OrderReplace {
+    OrderReplace {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      original_order_reference_number: self.original_order_reference_number(),
+      new_order_reference_number: self.new_order_reference_number(),
+      shares: self.shares(),
+      price: self.price(),
+    }
+  }
+} // OrderReplaceView
+
+#[derive(Clone, Copy)]
+pub struct TradeView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> TradeView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<TradeView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != Trade::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: Trade::TYPE, offset: 0 });
+    }
+    if data.len() < TRADE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: TRADE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..TRADE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[TRADE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn order_reference_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn buy_sell_indicator(&self) -> eBuySellIndicator {
+    eBuySellIndicator::from_code(self.data[19])
+  }
+  pub fn shares(&self) -> u32 {
+    u32::from_be_bytes(self.data[20..24].try_into().unwrap())
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[24..32].try_into().unwrap())
+  }
+  pub fn price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[32..36].try_into().unwrap()))
+  }
+  pub fn match_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[36..44].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> Trade {
+    Trade {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      order_reference_number: self.order_reference_number(),
+      buy_sell_indicator: self.buy_sell_indicator(),
+      shares: self.shares(),
+      stock: self.stock(),
+      price: self.price(),
+      match_number: self.match_number(),
+    }
+  }
+} // TradeView
+
+#[derive(Clone, Copy)]
+pub struct CrossTradeView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> CrossTradeView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<CrossTradeView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != CrossTrade::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: CrossTrade::TYPE, offset: 0 });
+    }
+    if data.len() < CROSS_TRADE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: CROSS_TRADE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..CROSS_TRADE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[CROSS_TRADE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn shares(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[19..27].try_into().unwrap())
+  }
+  pub fn cross_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[27..31].try_into().unwrap()))
+  }
+  pub fn match_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[31..39].try_into().unwrap())
+  }
+  pub fn cross_type(&self) -> eCrossType {
+    eCrossType::from_code(self.data[39])
+  }
+  pub fn to_message(&self) -> CrossTrade {
+    CrossTrade {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      shares: self.shares(),
+      stock: self.stock(),
+      cross_price: self.cross_price(),
+      match_number: self.match_number(),
+      cross_type: self.cross_type(),
+    }
+  }
+} // CrossTradeView
+
+#[derive(Clone, Copy)]
+pub struct BrokenTradeView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> BrokenTradeView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<BrokenTradeView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != BrokenTrade::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: BrokenTrade::TYPE, offset: 0 });
+    }
+    if data.len() < BROKEN_TRADE_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: BROKEN_TRADE_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..BROKEN_TRADE_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[BROKEN_TRADE_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn match_number(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn to_message(&self) -> BrokenTrade {
+    BrokenTrade {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      match_number: self.match_number(),
+    }
+  }
+} // BrokenTradeView
+
+#[derive(Clone, Copy)]
+pub struct NetOrderImbalanceIndicatorView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> NetOrderImbalanceIndicatorView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<NetOrderImbalanceIndicatorView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != NetOrderImbalanceIndicator::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: NetOrderImbalanceIndicator::TYPE, offset: 0 });
+    }
+    if data.len() < NET_ORDER_IMBALANCE_INDICATOR_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: NET_ORDER_IMBALANCE_INDICATOR_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..NET_ORDER_IMBALANCE_INDICATOR_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[NET_ORDER_IMBALANCE_INDICATOR_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn paired_shares(&self) -> u64 {
+    u64::from_be_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn imbalance_shares(&self) -> u64 {
+    u64::from_be_bytes(self.data[19..27].try_into().unwrap())
+  }
+  pub fn imbalance_direction(&self) -> eImbalanceDirection {
+    eImbalanceDirection::from_code(self.data[27])
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[28..36].try_into().unwrap())
+  }
+  pub fn far_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[36..40].try_into().unwrap()))
+  }
+  pub fn near_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[40..44].try_into().unwrap()))
+  }
+  pub fn current_reference_price(&self) -> Price4 {
+    Price4(u32::from_be_bytes(self.data[44..48].try_into().unwrap()))
+  }
+  pub fn cross_type(&self) -> eCrossType {
+    eCrossType::from_code(self.data[48])
+  }
+  pub fn price_variation_indicator(&self) -> ePriceVariationIndicator {
+    ePriceVariationIndicator::from_code(self.data[49])
+  }
+  pub fn to_message(&self) -> NetOrderImbalanceIndicator {
+    NetOrderImbalanceIndicator {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      paired_shares: self.paired_shares(),
+      imbalance_shares: self.imbalance_shares(),
+      imbalance_direction: self.imbalance_direction(),
+      stock: self.stock(),
+      far_price: self.far_price(),
+      near_price: self.near_price(),
+      current_reference_price: self.current_reference_price(),
+      cross_type: self.cross_type(),
+      price_variation_indicator: self.price_variation_indicator(),
+    }
+  }
+} // NetOrderImbalanceIndicatorView
+
+#[derive(Clone, Copy)]
+pub struct RetailPriceImprovementIndicatorView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> RetailPriceImprovementIndicatorView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<RetailPriceImprovementIndicatorView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != RetailPriceImprovementIndicator::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: RetailPriceImprovementIndicator::TYPE, offset: 0 });
+    }
+    if data.len() < RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn stock_locate(&self) -> u16 {
+    u16::from_be_bytes(self.data[1..3].try_into().unwrap())
+  }
+  pub fn tracking_number(&self) -> u16 {
+    u16::from_be_bytes(self.data[3..5].try_into().unwrap())
+  }
+  pub fn timestamp(&self) -> ItchTimestamp {
+    ItchTimestamp(u48_to_u64(self.data[5..11].try_into().unwrap()))
+  }
+  pub fn stock(&self) -> Symbol {
+    Symbol::from_bytes(self.data[11..19].try_into().unwrap())
+  }
+  pub fn interest_flag(&self) -> eInterestFlag {
+    eInterestFlag::from_code(self.data[19])
+  }
+  pub fn to_message(&self) -> RetailPriceImprovementIndicator {
+    RetailPriceImprovementIndicator {
+      message_type: self.message_type(),
+      stock_locate: self.stock_locate(),
+      tracking_number: self.tracking_number(),
+      timestamp: self.timestamp(),
+      stock: self.stock(),
+      interest_flag: self.interest_flag(),
+    }
+  }
+} // RetailPriceImprovementIndicatorView
+
+#[derive(Clone, Copy)]
+pub struct EndOfSnapshotView<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> EndOfSnapshotView<'a> {
+  pub fn new(data: &'a [u8]) -> Result<EndOfSnapshotView<'a>, DecodeError> {
+    if data.is_empty() {
+      return Err(DecodeError::Empty);
+    }
+    if data[0] != EndOfSnapshot::TYPE {
+      return Err(DecodeError::WrongType { type_byte: data[0], expected: EndOfSnapshot::TYPE, offset: 0 });
+    }
+    if data.len() < END_OF_SNAPSHOT_SIZE {
+      return Err(DecodeError::Truncated { type_byte: data[0], expected: END_OF_SNAPSHOT_SIZE, actual: data.len(), offset: 0 });
+    }
+    Ok(Self{data})
+  }
+  pub fn as_bytes(&self) -> &'a [u8] {
+    &self.data[..END_OF_SNAPSHOT_SIZE]
+  }
+  // bytes past the schema size, e.g. fields from a newer spec revision
+  pub fn trailing(&self) -> &'a [u8] {
+    &self.data[END_OF_SNAPSHOT_SIZE..]
+  }
+  pub fn message_type(&self) -> u8 {
+    self.data[0]
+  }
+  pub fn sequence_number(&self) -> &'a [u8;20] {
+    self.data[1..21].try_into().unwrap()
+  }
+  pub fn to_message(&self) -> EndOfSnapshot {
+    EndOfSnapshot {
+      message_type: self.message_type(),
+      sequence_number: *self.sequence_number(),
+    }
+  }
+} // EndOfSnapshotView
+
+pub trait ItchViewHandler {
+  fn on_system_event(&mut self, _msg: SystemEventView<'_>) {}
+  fn on_stock_directory(&mut self, _msg: StockDirectoryView<'_>) {}
+  fn on_stock_trading_action(&mut self, _msg: StockTradingActionView<'_>) {}
+  fn on_reg_sho_restriction(&mut self, _msg: RegShoRestrictionView<'_>) {}
+  fn on_market_participant_position(&mut self, _msg: MarketParticipantPositionView<'_>) {}
+  fn on_mwcb_decline_level(&mut self, _msg: MwcbDeclineLevelView<'_>) {}
+  fn on_mwcb_status(&mut self, _msg: MwcbStatusView<'_>) {}
+  fn on_ipo_quoting_period_update(&mut self, _msg: IpoQuotingPeriodUpdateView<'_>) {}
+  fn on_luld_auction_collar(&mut self, _msg: LuldAuctionCollarView<'_>) {}
+  fn on_operational_halt(&mut self, _msg: OperationalHaltView<'_>) {}
+  fn on_add_order(&mut self, _msg: AddOrderView<'_>) {}
+  fn on_add_order_with_mpid(&mut self, _msg: AddOrderWithMpidView<'_>) {}
+  fn on_order_executed(&mut self, _msg: OrderExecutedView<'_>) {}
+  fn on_order_executed_with_price(&mut self, _msg: OrderExecutedWithPriceView<'_>) {}
+  fn on_order_cancel(&mut self, _msg: OrderCancelView<'_>) {}
+  fn on_order_delete(&mut self, _msg: OrderDeleteView<'_>) {}
+  fn on_order_replace(&mut self, _msg: OrderReplaceView<'_>) {}
+  fn on_trade(&mut self, _msg: TradeView<'_>) {}
+  fn on_cross_trade(&mut self, _msg: CrossTradeView<'_>) {}
+  fn on_broken_trade(&mut self, _msg: BrokenTradeView<'_>) {}
+  fn on_net_order_imbalance_indicator(&mut self, _msg: NetOrderImbalanceIndicatorView<'_>) {}
+  fn on_retail_price_improvement_indicator(&mut self, _msg: RetailPriceImprovementIndicatorView<'_>) {}
+  fn on_end_of_snapshot(&mut self, _msg: EndOfSnapshotView<'_>) {}
+}
+
+pub fn crack_message_view<T: ItchViewHandler>(msg: &[u8], handler: &mut T) -> Result<(), DecodeError> {
+  let tipe = match msg.first() {
+    Some(tipe) => *tipe,
+    None => return Err(DecodeError::Empty),
+  };
+  match tipe {
+    SystemEvent::TYPE => handler.on_system_event(SystemEventView::new(msg)?),
+    StockDirectory::TYPE => handler.on_stock_directory(StockDirectoryView::new(msg)?),
+    StockTradingAction::TYPE => handler.on_stock_trading_action(StockTradingActionView::new(msg)?),
+    RegShoRestriction::TYPE => handler.on_reg_sho_restriction(RegShoRestrictionView::new(msg)?),
+    MarketParticipantPosition::TYPE => handler.on_market_participant_position(MarketParticipantPositionView::new(msg)?),
+    MwcbDeclineLevel::TYPE => handler.on_mwcb_decline_level(MwcbDeclineLevelView::new(msg)?),
+    MwcbStatus::TYPE => handler.on_mwcb_status(MwcbStatusView::new(msg)?),
+    IpoQuotingPeriodUpdate::TYPE => handler.on_ipo_quoting_period_update(IpoQuotingPeriodUpdateView::new(msg)?),
+    LuldAuctionCollar::TYPE => handler.on_luld_auction_collar(LuldAuctionCollarView::new(msg)?),
+    OperationalHalt::TYPE => handler.on_operational_halt(OperationalHaltView::new(msg)?),
+    AddOrder::TYPE => handler.on_add_order(AddOrderView::new(msg)?),
+    AddOrderWithMpid::TYPE => handler.on_add_order_with_mpid(AddOrderWithMpidView::new(msg)?),
+    OrderExecuted::TYPE => handler.on_order_executed(OrderExecutedView::new(msg)?),
+    OrderExecutedWithPrice::TYPE => handler.on_order_executed_with_price(OrderExecutedWithPriceView::new(msg)?),
+    OrderCancel::TYPE => handler.on_order_cancel(OrderCancelView::new(msg)?),
+    OrderDelete::TYPE => handler.on_order_delete(OrderDeleteView::new(msg)?),
+    OrderReplace::TYPE => handler.on_order_replace(OrderReplaceView::new(msg)?),
+    Trade::TYPE => handler.on_trade(TradeView::new(msg)?),
+    CrossTrade::TYPE => handler.on_cross_trade(CrossTradeView::new(msg)?),
+    BrokenTrade::TYPE => handler.on_broken_trade(BrokenTradeView::new(msg)?),
+    NetOrderImbalanceIndicator::TYPE => handler.on_net_order_imbalance_indicator(NetOrderImbalanceIndicatorView::new(msg)?),
+    RetailPriceImprovementIndicator::TYPE => handler.on_retail_price_improvement_indicator(RetailPriceImprovementIndicatorView::new(msg)?),
+    EndOfSnapshot::TYPE => handler.on_end_of_snapshot(EndOfSnapshotView::new(msg)?),
+    _ => return Err(DecodeError::UnknownType { type_byte: tipe, offset: 0 }),
+  }
+  Ok(())
+}
+
+impl ItchMessageType for SystemEvent {
+  const TYPE : u8 = b'S';
+  const SIZE : usize = SYSTEM_EVENT_SIZE;
+  const NAME : &'static str = "SystemEvent";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "event_code"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 21];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    SystemEvent::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("event_code", FieldValue::Code { code: self.event_code.code(), name: self.event_code.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+}
+
+impl ItchMessageType for StockDirectory {
+  const TYPE : u8 = b'R';
+  const SIZE : usize = STOCK_DIRECTORY_SIZE;
+  const NAME : &'static str = "StockDirectory";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "market_category", "financial_status_indicator", "round_lot_size", "round_lots_only", "issue_classification", "issue_sub_type", "authenticity", "short_sale_threshold_indicator", "ipo_flag", "luld_reference_price_tier", "etp_flag", "etp_leverage_factor", "inverse_indicator"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 29, 38, 10, 18, 29, 2, 10, 14, 22, 33, 24, 10, 25];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    StockDirectory::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("market_category", FieldValue::Code { code: self.market_category.code(), name: self.market_category.name() });
+    visitor.field("financial_status_indicator", FieldValue::Code { code: self.financial_status_indicator.code(), name: self.financial_status_indicator.name() });
+    visitor.field("round_lot_size", FieldValue::U32(self.round_lot_size));
+    visitor.field("round_lots_only", FieldValue::Code { code: self.round_lots_only.code(), name: self.round_lots_only.name() });
+    visitor.field("issue_classification", FieldValue::Code { code: self.issue_classification.code(), name: self.issue_classification.name() });
+    visitor.field("issue_sub_type", FieldValue::Text(self.issue_sub_type.as_str()));
+    visitor.field("authenticity", FieldValue::Code { code: self.authenticity.code(), name: self.authenticity.name() });
+    visitor.field("short_sale_threshold_indicator", FieldValue::Code { code: self.short_sale_threshold_indicator.code(), name: self.short_sale_threshold_indicator.name() });
+    visitor.field("ipo_flag", FieldValue::Code { code: self.ipo_flag.code(), name: self.ipo_flag.name() });
+    visitor.field("luld_reference_price_tier", FieldValue::Code { code: self.luld_reference_price_tier.code(), name: self.luld_reference_price_tier.name() });
+    visitor.field("etp_flag", FieldValue::Code { code: self.etp_flag.code(), name: self.etp_flag.name() });
+    visitor.field("etp_leverage_factor", FieldValue::U32(self.etp_leverage_factor));
+    visitor.field("inverse_indicator", FieldValue::Code { code: self.inverse_indicator.code(), name: self.inverse_indicator.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for StockTradingAction {
+  const TYPE : u8 = b'H';
+  const SIZE : usize = STOCK_TRADING_ACTION_SIZE;
+  const NAME : &'static str = "StockTradingAction";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "trading_state", "reserved", "reason"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 14, 4, 4];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    StockTradingAction::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("trading_state", FieldValue::Code { code: self.trading_state.code(), name: self.trading_state.name() });
+    visitor.field("reserved", FieldValue::Char(self.reserved));
+    visitor.field("reason", FieldValue::Bytes(&self.reason));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for RegShoRestriction {
+  const TYPE : u8 = b'Y';
+  const SIZE : usize = REG_SHO_RESTRICTION_SIZE;
+  const NAME : &'static str = "RegShoRestriction";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "reg_sho_action"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 59];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    RegShoRestriction::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("reg_sho_action", FieldValue::Code { code: self.reg_sho_action.code(), name: self.reg_sho_action.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for MarketParticipantPosition {
+  const TYPE : u8 = b'L';
+  const SIZE : usize = MARKET_PARTICIPANT_POSITION_SIZE;
+  const NAME : &'static str = "MarketParticipantPosition";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "mpid", "stock", "primary_market_maker", "market_maker_mode", "market_participant_state"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 4, 8, 24, 13, 17];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    MarketParticipantPosition::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("mpid", FieldValue::Text(self.mpid.as_str()));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("primary_market_maker", FieldValue::Code { code: self.primary_market_maker.code(), name: self.primary_market_maker.name() });
+    visitor.field("market_maker_mode", FieldValue::Code { code: self.market_maker_mode.code(), name: self.market_maker_mode.name() });
+    visitor.field("market_participant_state", FieldValue::Code { code: self.market_participant_state.code(), name: self.market_participant_state.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for MwcbDeclineLevel {
+  const TYPE : u8 = b'V';
+  const SIZE : usize = MWCB_DECLINE_LEVEL_SIZE;
+  const NAME : &'static str = "MwcbDeclineLevel";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "level_1", "level_2", "level_3"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 21, 21, 21];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    MwcbDeclineLevel::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("level_1", FieldValue::Price8(self.level_1));
+    visitor.field("level_2", FieldValue::Price8(self.level_2));
+    visitor.field("level_3", FieldValue::Price8(self.level_3));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+}
+
+impl ItchMessageType for MwcbStatus {
+  const TYPE : u8 = b'W';
+  const SIZE : usize = MWCB_STATUS_SIZE;
+  const NAME : &'static str = "MwcbStatus";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "breached_level"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 7];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    MwcbStatus::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("breached_level", FieldValue::Code { code: self.breached_level.code(), name: self.breached_level.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+}
+
+impl ItchMessageType for IpoQuotingPeriodUpdate {
+  const TYPE : u8 = b'K';
+  const SIZE : usize = IPO_QUOTING_PERIOD_UPDATE_SIZE;
+  const NAME : &'static str = "IpoQuotingPeriodUpdate";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "ipo_quotation_release_time", "ipo_quotation_release_qualifier", "ipo_price"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 10, 34, 11];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    IpoQuotingPeriodUpdate::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("ipo_quotation_release_time", FieldValue::U32(self.ipo_quotation_release_time));
+    visitor.field("ipo_quotation_release_qualifier", FieldValue::Code { code: self.ipo_quotation_release_qualifier.code(), name: self.ipo_quotation_release_qualifier.name() });
+    visitor.field("ipo_price", FieldValue::Price4(self.ipo_price));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for LuldAuctionCollar {
+  const TYPE : u8 = b'J';
+  const SIZE : usize = LULD_AUCTION_COLLAR_SIZE;
+  const NAME : &'static str = "LuldAuctionCollar";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "auction_collar_reference_price", "upper_auction_collar_price", "lower_auction_collar_price", "auction_collar_extension"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 11, 11, 11, 10];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    LuldAuctionCollar::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("auction_collar_reference_price", FieldValue::Price4(self.auction_collar_reference_price));
+    visitor.field("upper_auction_collar_price", FieldValue::Price4(self.upper_auction_collar_price));
+    visitor.field("lower_auction_collar_price", FieldValue::Price4(self.lower_auction_collar_price));
+    visitor.field("auction_collar_extension", FieldValue::U32(self.auction_collar_extension));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for OperationalHalt {
+  const TYPE : u8 = b'h';
+  const SIZE : usize = OPERATIONAL_HALT_SIZE;
+  const NAME : &'static str = "OperationalHalt";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "market_code", "operational_halt_action"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 6, 7];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OperationalHalt::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("market_code", FieldValue::Code { code: self.market_code.code(), name: self.market_code.name() });
+    visitor.field("operational_halt_action", FieldValue::Code { code: self.operational_halt_action.code(), name: self.operational_halt_action.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for AddOrder {
+  const TYPE : u8 = b'A';
+  const SIZE : usize = ADD_ORDER_SIZE;
+  const NAME : &'static str = "AddOrder";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "buy_sell_indicator", "shares", "stock", "price"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10, 10, 8, 11];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    AddOrder::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("buy_sell_indicator", FieldValue::Code { code: self.buy_sell_indicator.code(), name: self.buy_sell_indicator.name() });
+    visitor.field("shares", FieldValue::U32(self.shares));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("price", FieldValue::Price4(self.price));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for AddOrderWithMpid {
+  const TYPE : u8 = b'F';
+  const SIZE : usize = ADD_ORDER_WITH_MPID_SIZE;
+  const NAME : &'static str = "AddOrderWithMpid";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "buy_sell_indicator", "shares", "stock", "price", "attribution"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10, 10, 8, 11, 4];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    AddOrderWithMpid::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("buy_sell_indicator", FieldValue::Code { code: self.buy_sell_indicator.code(), name: self.buy_sell_indicator.name() });
+    visitor.field("shares", FieldValue::U32(self.shares));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("price", FieldValue::Price4(self.price));
+    visitor.field("attribution", FieldValue::Text(self.attribution.as_str()));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for OrderExecuted {
+  const TYPE : u8 = b'E';
+  const SIZE : usize = ORDER_EXECUTED_SIZE;
+  const NAME : &'static str = "OrderExecuted";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "executed_shares", "match_number"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10, 20];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OrderExecuted::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("executed_shares", FieldValue::U32(self.executed_shares));
+    visitor.field("match_number", FieldValue::U64(self.match_number));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for OrderExecutedWithPrice {
+  const TYPE : u8 = b'C';
+  const SIZE : usize = ORDER_EXECUTED_WITH_PRICE_SIZE;
+  const NAME : &'static str = "OrderExecutedWithPrice";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "executed_shares", "match_number", "printable", "execution_price"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10, 20, 13, 11];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OrderExecutedWithPrice::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("executed_shares", FieldValue::U32(self.executed_shares));
+    visitor.field("match_number", FieldValue::U64(self.match_number));
+    visitor.field("printable", FieldValue::Code { code: self.printable.code(), name: self.printable.name() });
+    visitor.field("execution_price", FieldValue::Price4(self.execution_price));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for OrderCancel {
+  const TYPE : u8 = b'X';
+  const SIZE : usize = ORDER_CANCEL_SIZE;
+  const NAME : &'static str = "OrderCancel";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "cancelled_shares"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OrderCancel::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("cancelled_shares", FieldValue::U32(self.cancelled_shares));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for OrderDelete {
+  const TYPE : u8 = b'D';
+  const SIZE : usize = ORDER_DELETE_SIZE;
+  const NAME : &'static str = "OrderDelete";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OrderDelete::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for OrderReplace {
+  const TYPE : u8 = b'U';
+  const SIZE : usize = ORDER_REPLACE_SIZE;
+  const NAME : &'static str = "OrderReplace";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "original_order_reference_number", "new_order_reference_number", "shares", "price"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 20, 10, 11];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    OrderReplace::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("original_order_reference_number", FieldValue::U64(self.original_order_reference_number));
+    visitor.field("new_order_reference_number", FieldValue::U64(self.new_order_reference_number));
+    visitor.field("shares", FieldValue::U32(self.shares));
+    visitor.field("price", FieldValue::Price4(self.price));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.new_order_reference_number)
+  }
+}
+
+impl ItchMessageType for Trade {
+  const TYPE : u8 = b'P';
+  const SIZE : usize = TRADE_SIZE;
+  const NAME : &'static str = "Trade";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "order_reference_number", "buy_sell_indicator", "shares", "stock", "price", "match_number"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 10, 10, 8, 11, 20];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    Trade::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("order_reference_number", FieldValue::U64(self.order_reference_number));
+    visitor.field("buy_sell_indicator", FieldValue::Code { code: self.buy_sell_indicator.code(), name: self.buy_sell_indicator.name() });
+    visitor.field("shares", FieldValue::U32(self.shares));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("price", FieldValue::Price4(self.price));
+    visitor.field("match_number", FieldValue::U64(self.match_number));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+  fn order_reference_number(&self) -> Option<u64> {
+    Some(self.order_reference_number)
+  }
+}
+
+impl ItchMessageType for CrossTrade {
+  const TYPE : u8 = b'Q';
+  const SIZE : usize = CROSS_TRADE_SIZE;
+  const NAME : &'static str = "CrossTrade";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "shares", "stock", "cross_price", "match_number", "cross_type"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 8, 11, 20, 56];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    CrossTrade::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("shares", FieldValue::U64(self.shares));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("cross_price", FieldValue::Price4(self.cross_price));
+    visitor.field("match_number", FieldValue::U64(self.match_number));
+    visitor.field("cross_type", FieldValue::Code { code: self.cross_type.code(), name: self.cross_type.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for BrokenTrade {
+  const TYPE : u8 = b'B';
+  const SIZE : usize = BROKEN_TRADE_SIZE;
+  const NAME : &'static str = "BrokenTrade";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "match_number"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    BrokenTrade::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("match_number", FieldValue::U64(self.match_number));
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+}
+
+impl ItchMessageType for NetOrderImbalanceIndicator {
+  const TYPE : u8 = b'I';
+  const SIZE : usize = NET_ORDER_IMBALANCE_INDICATOR_SIZE;
+  const NAME : &'static str = "NetOrderImbalanceIndicator";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "paired_shares", "imbalance_shares", "imbalance_direction", "stock", "far_price", "near_price", "current_reference_price", "cross_type", "price_variation_indicator"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 20, 20, 32, 8, 11, 11, 11, 56, 27];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    NetOrderImbalanceIndicator::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("paired_shares", FieldValue::U64(self.paired_shares));
+    visitor.field("imbalance_shares", FieldValue::U64(self.imbalance_shares));
+    visitor.field("imbalance_direction", FieldValue::Code { code: self.imbalance_direction.code(), name: self.imbalance_direction.name() });
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("far_price", FieldValue::Price4(self.far_price));
+    visitor.field("near_price", FieldValue::Price4(self.near_price));
+    visitor.field("current_reference_price", FieldValue::Price4(self.current_reference_price));
+    visitor.field("cross_type", FieldValue::Code { code: self.cross_type.code(), name: self.cross_type.name() });
+    visitor.field("price_variation_indicator", FieldValue::Code { code: self.price_variation_indicator.code(), name: self.price_variation_indicator.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for RetailPriceImprovementIndicator {
+  const TYPE : u8 = b'N';
+  const SIZE : usize = RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE;
+  const NAME : &'static str = "RetailPriceImprovementIndicator";
+  const FIELDS : &'static [&'static str] = &["message_type", "stock_locate", "tracking_number", "timestamp", "stock", "interest_flag"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 5, 5, 18, 8, 37];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    RetailPriceImprovementIndicator::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("stock_locate", FieldValue::U16(self.stock_locate));
+    visitor.field("tracking_number", FieldValue::U16(self.tracking_number));
+    visitor.field("timestamp", FieldValue::Timestamp(self.timestamp));
+    visitor.field("stock", FieldValue::Text(self.stock.as_str()));
+    visitor.field("interest_flag", FieldValue::Code { code: self.interest_flag.code(), name: self.interest_flag.name() });
+  }
+  fn stock_locate(&self) -> Option<u16> {
+    Some(self.stock_locate)
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    Some(self.tracking_number)
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    Some(self.timestamp)
+  }
+  fn stock(&self) -> Option<Symbol> {
+    Some(self.stock)
+  }
+}
+
+impl ItchMessageType for EndOfSnapshot {
+  const TYPE : u8 = b'G';
+  const SIZE : usize = END_OF_SNAPSHOT_SIZE;
+  const NAME : &'static str = "EndOfSnapshot";
+  const FIELDS : &'static [&'static str] = &["message_type", "sequence_number"];
+  const FIELD_WIDTHS : &'static [usize] = &[4, 20];
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+    EndOfSnapshot::decode(bytes)
+  }
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    visitor.field("message_type", FieldValue::Char(self.message_type));
+    visitor.field("sequence_number", FieldValue::Bytes(&self.sequence_number));
+  }
+}
+
+pub trait ItchHandler {
+  fn on_system_event(&mut self, _msg: &SystemEvent, _ctx: &MessageContext) {}
+  fn on_stock_directory(&mut self, _msg: &StockDirectory, _ctx: &MessageContext) {}
+  fn on_stock_trading_action(&mut self, _msg: &StockTradingAction, _ctx: &MessageContext) {}
+  fn on_reg_sho_restriction(&mut self, _msg: &RegShoRestriction, _ctx: &MessageContext) {}
+  fn on_market_participant_position(&mut self, _msg: &MarketParticipantPosition, _ctx: &MessageContext) {}
+  fn on_mwcb_decline_level(&mut self, _msg: &MwcbDeclineLevel, _ctx: &MessageContext) {}
+  fn on_mwcb_status(&mut self, _msg: &MwcbStatus, _ctx: &MessageContext) {}
+  fn on_ipo_quoting_period_update(&mut self, _msg: &IpoQuotingPeriodUpdate, _ctx: &MessageContext) {}
+  fn on_luld_auction_collar(&mut self, _msg: &LuldAuctionCollar, _ctx: &MessageContext) {}
+  fn on_operational_halt(&mut self, _msg: &OperationalHalt, _ctx: &MessageContext) {}
+  fn on_add_order(&mut self, _msg: &AddOrder, _ctx: &MessageContext) {}
+  fn on_add_order_with_mpid(&mut self, _msg: &AddOrderWithMpid, _ctx: &MessageContext) {}
+  fn on_order_executed(&mut self, _msg: &OrderExecuted, _ctx: &MessageContext) {}
+  fn on_order_executed_with_price(&mut self, _msg: &OrderExecutedWithPrice, _ctx: &MessageContext) {}
+  fn on_order_cancel(&mut self, _msg: &OrderCancel, _ctx: &MessageContext) {}
+  fn on_order_delete(&mut self, _msg: &OrderDelete, _ctx: &MessageContext) {}
+  fn on_order_replace(&mut self, _msg: &OrderReplace, _ctx: &MessageContext) {}
+  fn on_trade(&mut self, _msg: &Trade, _ctx: &MessageContext) {}
+  fn on_cross_trade(&mut self, _msg: &CrossTrade, _ctx: &MessageContext) {}
+  fn on_broken_trade(&mut self, _msg: &BrokenTrade, _ctx: &MessageContext) {}
+  fn on_net_order_imbalance_indicator(&mut self, _msg: &NetOrderImbalanceIndicator, _ctx: &MessageContext) {}
+  fn on_retail_price_improvement_indicator(&mut self, _msg: &RetailPriceImprovementIndicator, _ctx: &MessageContext) {}
+  fn on_end_of_snapshot(&mut self, _msg: &EndOfSnapshot, _ctx: &MessageContext) {}
+  // a type byte the schema doesn't know, e.g. a message added after this was generated
+  fn on_unknown(&mut self, _type_byte: u8, _raw: &[u8], _ctx: &MessageContext) {}
+  // a known message type that failed to decode, usually truncated
+  fn on_decode_error(&mut self, _err: DecodeError, _raw: &[u8], _ctx: &MessageContext) {}
+  // bytes past the schema size of a message that was just dispatched, see LengthCheck
+  fn on_trailing_bytes(&mut self, _type_byte: u8, _trailing: &[u8], _ctx: &MessageContext) {}
+}
+
+// Unknown message types go to on_unknown and are not an error, decode
+// failures go to on_decode_error and are also returned.
+pub fn crack_message<T: ItchHandler>(msg: &[u8], handler: &mut T) -> Result<(), DecodeError> {
+  crack_message_with_context(msg, &MessageContext::default(), handler)
+}
+
+pub fn crack_message_with_context<T: ItchHandler>(msg: &[u8], ctx: &MessageContext, handler: &mut T) -> Result<(), DecodeError> {
+  crack_message_checked(msg, ctx, LengthCheck::Lenient, handler)
+}
+
+pub fn crack_message_checked<T: ItchHandler>(msg: &[u8], ctx: &MessageContext, check: LengthCheck, handler: &mut T) -> Result<(), DecodeError> {
+  let tipe = match msg.first() {
+    Some(tipe) => *tipe,
+    None => {
+      handler.on_decode_error(DecodeError::Empty, msg, ctx);
+      return Err(DecodeError::Empty);
+    },
+  };
+  let size = ItchMessage::size_of_type(tipe);
+  if let Some(size) = size {
+    if let Err(err) = check.check(tipe, size, msg.len()) {
+      handler.on_decode_error(err.clone(), msg, ctx);
+      return Err(err);
+    }
+  }
+  let result = match tipe {
+    SystemEvent::TYPE => SystemEvent::decode(msg).map(|decoded| handler.on_system_event(&decoded, ctx)),
+    StockDirectory::TYPE => StockDirectory::decode(msg).map(|decoded| handler.on_stock_directory(&decoded, ctx)),
+    StockTradingAction::TYPE => StockTradingAction::decode(msg).map(|decoded| handler.on_stock_trading_action(&decoded, ctx)),
+    RegShoRestriction::TYPE => RegShoRestriction::decode(msg).map(|decoded| handler.on_reg_sho_restriction(&decoded, ctx)),
+    MarketParticipantPosition::TYPE => MarketParticipantPosition::decode(msg).map(|decoded| handler.on_market_participant_position(&decoded, ctx)),
+    MwcbDeclineLevel::TYPE => MwcbDeclineLevel::decode(msg).map(|decoded| handler.on_mwcb_decline_level(&decoded, ctx)),
+    MwcbStatus::TYPE => MwcbStatus::decode(msg).map(|decoded| handler.on_mwcb_status(&decoded, ctx)),
+    IpoQuotingPeriodUpdate::TYPE => IpoQuotingPeriodUpdate::decode(msg).map(|decoded| handler.on_ipo_quoting_period_update(&decoded, ctx)),
+    LuldAuctionCollar::TYPE => LuldAuctionCollar::decode(msg).map(|decoded| handler.on_luld_auction_collar(&decoded, ctx)),
+    OperationalHalt::TYPE => OperationalHalt::decode(msg).map(|decoded| handler.on_operational_halt(&decoded, ctx)),
+    AddOrder::TYPE => AddOrder::decode(msg).map(|decoded| handler.on_add_order(&decoded, ctx)),
+    AddOrderWithMpid::TYPE => AddOrderWithMpid::decode(msg).map(|decoded| handler.on_add_order_with_mpid(&decoded, ctx)),
+    OrderExecuted::TYPE => OrderExecuted::decode(msg).map(|decoded| handler.on_order_executed(&decoded, ctx)),
+    OrderExecutedWithPrice::TYPE => OrderExecutedWithPrice::decode(msg).map(|decoded| handler.on_order_executed_with_price(&decoded, ctx)),
+    OrderCancel::TYPE => OrderCancel::decode(msg).map(|decoded| handler.on_order_cancel(&decoded, ctx)),
+    OrderDelete::TYPE => OrderDelete::decode(msg).map(|decoded| handler.on_order_delete(&decoded, ctx)),
+    OrderReplace::TYPE => OrderReplace::decode(msg).map(|decoded| handler.on_order_replace(&decoded, ctx)),
+    Trade::TYPE => Trade::decode(msg).map(|decoded| handler.on_trade(&decoded, ctx)),
+    CrossTrade::TYPE => CrossTrade::decode(msg).map(|decoded| handler.on_cross_trade(&decoded, ctx)),
+    BrokenTrade::TYPE => BrokenTrade::decode(msg).map(|decoded| handler.on_broken_trade(&decoded, ctx)),
+    NetOrderImbalanceIndicator::TYPE => NetOrderImbalanceIndicator::decode(msg).map(|decoded| handler.on_net_order_imbalance_indicator(&decoded, ctx)),
+    RetailPriceImprovementIndicator::TYPE => RetailPriceImprovementIndicator::decode(msg).map(|decoded| handler.on_retail_price_improvement_indicator(&decoded, ctx)),
+    EndOfSnapshot::TYPE => EndOfSnapshot::decode(msg).map(|decoded| handler.on_end_of_snapshot(&decoded, ctx)),
+    _ => {
+      handler.on_unknown(tipe, msg, ctx);
+      Ok(())
+    },
+  };
+  match (&result, size) {
+    (Err(err), _) => handler.on_decode_error(err.clone(), msg, ctx),
+    (Ok(()), Some(size)) if msg.len() > size => handler.on_trailing_bytes(tipe, &msg[size..], ctx),
+    _ => {},
+  }
+  result
+}
+impl Encode for PacketHeader {
+  const ENCODED_LEN : usize = PACKET_HEADER_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_all(&self.session.0[..10])?;
+    wrt.write_u64::<BigEndian>(self.sequence_number)?;
+    wrt.write_u16::<BigEndian>(self.message_count)?;
+    Ok(())
+  }
+}
+
+impl Encode for MessageBlock {
+  const ENCODED_LEN : usize = MESSAGE_BLOCK_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u16::<BigEndian>(self.message_length)?;
+    Ok(())
+  }
+}
+
+impl Encode for RequestPacket {
+  const ENCODED_LEN : usize = REQUEST_PACKET_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_all(&self.session.0[..10])?;
+    wrt.write_u64::<BigEndian>(self.sequence_number)?;
+    wrt.write_u16::<BigEndian>(self.requested_message_count)?;
+    Ok(())
+  }
+}
+
+impl Encode for SystemEvent {
+  const ENCODED_LEN : usize = SYSTEM_EVENT_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(SystemEvent::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u8(self.event_code.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for StockDirectory {
+  const ENCODED_LEN : usize = STOCK_DIRECTORY_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(StockDirectory::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.market_category.code())?;
+    wrt.write_u8(self.financial_status_indicator.code())?;
+    wrt.write_u32::<BigEndian>(self.round_lot_size)?;
+    wrt.write_u8(self.round_lots_only.code())?;
+    wrt.write_u8(self.issue_classification.code())?;
+    wrt.write_all(&self.issue_sub_type.0[..2])?;
+    wrt.write_u8(self.authenticity.code())?;
+    wrt.write_u8(self.short_sale_threshold_indicator.code())?;
+    wrt.write_u8(self.ipo_flag.code())?;
+    wrt.write_u8(self.luld_reference_price_tier.code())?;
+    wrt.write_u8(self.etp_flag.code())?;
+    wrt.write_u32::<BigEndian>(self.etp_leverage_factor)?;
+    wrt.write_u8(self.inverse_indicator.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for StockTradingAction {
+  const ENCODED_LEN : usize = STOCK_TRADING_ACTION_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(StockTradingAction::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.trading_state.code())?;
+    wrt.write_u8(self.reserved)?;
+    wrt.write_all(&self.reason[..4])?;
+    Ok(())
+  }
+}
+
+impl Encode for RegShoRestriction {
+  const ENCODED_LEN : usize = REG_SHO_RESTRICTION_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(RegShoRestriction::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.reg_sho_action.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for MarketParticipantPosition {
+  const ENCODED_LEN : usize = MARKET_PARTICIPANT_POSITION_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(MarketParticipantPosition::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.mpid.0[..4])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.primary_market_maker.code())?;
+    wrt.write_u8(self.market_maker_mode.code())?;
+    wrt.write_u8(self.market_participant_state.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for MwcbDeclineLevel {
+  const ENCODED_LEN : usize = MWCB_DECLINE_LEVEL_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(MwcbDeclineLevel::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.level_1.0)?;
+    wrt.write_u64::<BigEndian>(self.level_2.0)?;
+    wrt.write_u64::<BigEndian>(self.level_3.0)?;
+    Ok(())
+  }
+}
+
+impl Encode for MwcbStatus {
+  const ENCODED_LEN : usize = MWCB_STATUS_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(MwcbStatus::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u8(self.breached_level.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for IpoQuotingPeriodUpdate {
+  const ENCODED_LEN : usize = IPO_QUOTING_PERIOD_UPDATE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(IpoQuotingPeriodUpdate::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.ipo_quotation_release_time)?;
+    wrt.write_u8(self.ipo_quotation_release_qualifier.code())?;
+    wrt.write_u32::<BigEndian>(self.ipo_price.0)?;
+    Ok(())
+  }
+}
+
+impl Encode for LuldAuctionCollar {
+  const ENCODED_LEN : usize = LULD_AUCTION_COLLAR_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(LuldAuctionCollar::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.auction_collar_reference_price.0)?;
+    wrt.write_u32::<BigEndian>(self.upper_auction_collar_price.0)?;
+    wrt.write_u32::<BigEndian>(self.lower_auction_collar_price.0)?;
+    wrt.write_u32::<BigEndian>(self.auction_collar_extension)?;
+    Ok(())
+  }
+}
+
+impl Encode for OperationalHalt {
+  const ENCODED_LEN : usize = OPERATIONAL_HALT_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OperationalHalt::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.market_code.code())?;
+    wrt.write_u8(self.operational_halt_action.code())?;
+    Ok(())
+  }
+}
+
+impl Encode for AddOrder {
+  const ENCODED_LEN : usize = ADD_ORDER_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(AddOrder::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u8(self.buy_sell_indicator.code())?;
+    wrt.write_u32::<BigEndian>(self.shares)?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.price.0)?;
+    Ok(())
+  }
+}
+
+impl Encode for AddOrderWithMpid {
+  const ENCODED_LEN : usize = ADD_ORDER_WITH_MPID_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(AddOrderWithMpid::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u8(self.buy_sell_indicator.code())?;
+    wrt.write_u32::<BigEndian>(self.shares)?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.price.0)?;
+    wrt.write_all(&self.attribution.0[..4])?;
+    Ok(())
   }
 }
-#[derive(Default)]
-pub struct NetOrderImbalanceIndicator {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub paired_shares: u64,
-  pub imbalance_shares: u64,
-  pub imbalance_direction: eImbalanceDirection,
-  pub stock: [u8;8],
-  pub far_price: u32,
-  pub near_price: u32,
-  pub current_reference_price: u32,
-  pub cross_type: eCrossType,
-  pub price_variation_indicator: ePriceVariationIndicator,
-} // NetOrderImbalanceIndicator
-pub const NET_ORDER_IMBALANCE_INDICATOR_SIZE : usize = 50;
 
-impl NetOrderImbalanceIndicator {
-  pub const TYPE : u8 = 'I' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(NetOrderImbalanceIndicator,usize)> {
-    if bytes.len() < NET_ORDER_IMBALANCE_INDICATOR_SIZE {
-      return None;
-    }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.paired_shares = rdr.read_u64::<BigEndian>().unwrap();
-    obj.imbalance_shares = rdr.read_u64::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.imbalance_direction.0)).unwrap();
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    obj.far_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.near_price = rdr.read_u32::<BigEndian>().unwrap();
-    obj.current_reference_price = rdr.read_u32::<BigEndian>().unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.cross_type.0)).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.price_variation_indicator.0)).unwrap();
-    Some((obj, NET_ORDER_IMBALANCE_INDICATOR_SIZE))
+impl Encode for OrderExecuted {
+  const ENCODED_LEN : usize = ORDER_EXECUTED_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OrderExecuted::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u32::<BigEndian>(self.executed_shares)?;
+    wrt.write_u64::<BigEndian>(self.match_number)?;
+    Ok(())
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<NetOrderImbalanceIndicator> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    obj.paired_shares = rdr.read_u64::<BigEndian>()?;
-    obj.imbalance_shares = rdr.read_u64::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.imbalance_direction.0))?;
-    rdr.read_exact(&mut obj.stock[..8])?;
-    obj.far_price = rdr.read_u32::<BigEndian>()?;
-    obj.near_price = rdr.read_u32::<BigEndian>()?;
-    obj.current_reference_price = rdr.read_u32::<BigEndian>()?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.cross_type.0))?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.price_variation_indicator.0))?;
-    Ok(obj)
+}
+
+impl Encode for OrderExecutedWithPrice {
+  const ENCODED_LEN : usize = ORDER_EXECUTED_WITH_PRICE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OrderExecutedWithPrice::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u32::<BigEndian>(self.executed_shares)?;
+    wrt.write_u64::<BigEndian>(self.match_number)?;
+    wrt.write_u8(self.printable.code())?;
+    wrt.write_u32::<BigEndian>(self.execution_price.0)?;
+    Ok(())
   }
 }
-impl fmt::Display for NetOrderImbalanceIndicator {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "NetOrderImbalanceIndicator(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},paired_shares:{},imbalance_shares:{},imbalance_direction:{},stock:{},far_price:{},near_price:{},current_reference_price:{},cross_type:{},price_variation_indicator:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, self.paired_shares, self.imbalance_shares, self.imbalance_direction, String::from_utf8_lossy(&self.stock[..]), self.far_price, self.near_price, self.current_reference_price, self.cross_type, self.price_variation_indicator)
+
+impl Encode for OrderCancel {
+  const ENCODED_LEN : usize = ORDER_CANCEL_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OrderCancel::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u32::<BigEndian>(self.cancelled_shares)?;
+    Ok(())
   }
 }
-#[derive(Default)]
-pub struct RetailPriceImprovementIndicator {
-  pub message_type: u8,
-  pub stock_locate: u16,
-  pub tracking_number: u16,
-  pub timestamp: u64,
-  pub stock: [u8;8],
-  pub interest_flag: eInterestFlag,
-} // RetailPriceImprovementIndicator
-pub const RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE : usize = 20;
 
-impl RetailPriceImprovementIndicator {
-  pub const TYPE : u8 = 'N' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(RetailPriceImprovementIndicator,usize)> {
-    if bytes.len() < RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE {
-      return None;
-    }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    obj.stock_locate = rdr.read_u16::<BigEndian>().unwrap();
-    obj.tracking_number = rdr.read_u16::<BigEndian>().unwrap();
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6]).unwrap();
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8]).unwrap();
-    rdr.read_exact(std::slice::from_mut(&mut obj.interest_flag.0)).unwrap();
-    Some((obj, RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE))
+impl Encode for OrderDelete {
+  const ENCODED_LEN : usize = ORDER_DELETE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OrderDelete::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    Ok(())
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<RetailPriceImprovementIndicator> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    obj.stock_locate = rdr.read_u16::<BigEndian>()?;
-    obj.tracking_number = rdr.read_u16::<BigEndian>()?;
-    let mut the_u48 = [0u8; 6];
-    rdr.read_exact(&mut the_u48[..6])?;
-    obj.timestamp = u48_to_u64(&the_u48);
-    rdr.read_exact(&mut obj.stock[..8])?;
-    rdr.read_exact(std::slice::from_mut(&mut obj.interest_flag.0))?;
-    Ok(obj)
+}
+
+impl Encode for OrderReplace {
+  const ENCODED_LEN : usize = ORDER_REPLACE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(OrderReplace::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.original_order_reference_number)?;
+    wrt.write_u64::<BigEndian>(self.new_order_reference_number)?;
+    wrt.write_u32::<BigEndian>(self.shares)?;
+    wrt.write_u32::<BigEndian>(self.price.0)?;
+    Ok(())
   }
 }
-impl fmt::Display for RetailPriceImprovementIndicator {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "RetailPriceImprovementIndicator(message_type:{},stock_locate:{},tracking_number:{},timestamp:{},stock:{},interest_flag:{})", self.message_type, self.stock_locate, self.tracking_number, self.timestamp, String::from_utf8_lossy(&self.stock[..]), self.interest_flag)
+
+impl Encode for Trade {
+  const ENCODED_LEN : usize = TRADE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(Trade::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.order_reference_number)?;
+    wrt.write_u8(self.buy_sell_indicator.code())?;
+    wrt.write_u32::<BigEndian>(self.shares)?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.price.0)?;
+    wrt.write_u64::<BigEndian>(self.match_number)?;
+    Ok(())
   }
 }
-#[derive(Default)]
-pub struct EndOfSnapshot {
-  pub message_type: u8,
-  pub sequence_number: [u8;20],
-} // EndOfSnapshot
-pub const END_OF_SNAPSHOT_SIZE : usize = 21;
 
-impl EndOfSnapshot {
-  pub const TYPE : u8 = 'G' as u8;
-  pub fn from_bytes(bytes: &[u8]) -> Option<(EndOfSnapshot,usize)> {
-    if bytes.len() < END_OF_SNAPSHOT_SIZE {
-      return None;
-    }
-    let mut rdr = Cursor::new(bytes);
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type)).unwrap();
-    rdr.read_exact(&mut obj.sequence_number[..20]).unwrap();
-    Some((obj, END_OF_SNAPSHOT_SIZE))
+impl Encode for CrossTrade {
+  const ENCODED_LEN : usize = CROSS_TRADE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(CrossTrade::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.shares)?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.cross_price.0)?;
+    wrt.write_u64::<BigEndian>(self.match_number)?;
+    wrt.write_u8(self.cross_type.code())?;
+    Ok(())
   }
-  pub fn from_cursor(rdr: &mut Cursor<&[u8]>) -> std::io::Result<EndOfSnapshot> {
-    let mut obj = Self::default();
-    rdr.read_exact(std::slice::from_mut(&mut obj.message_type))?;
-    rdr.read_exact(&mut obj.sequence_number[..20])?;
-    Ok(obj)
+}
+
+impl Encode for BrokenTrade {
+  const ENCODED_LEN : usize = BROKEN_TRADE_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(BrokenTrade::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.match_number)?;
+    Ok(())
   }
 }
-impl fmt::Display for EndOfSnapshot {
-  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "EndOfSnapshot(message_type:{},sequence_number:{})", self.message_type, String::from_utf8_lossy(&self.sequence_number[..]))
+
+impl Encode for NetOrderImbalanceIndicator {
+  const ENCODED_LEN : usize = NET_ORDER_IMBALANCE_INDICATOR_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(NetOrderImbalanceIndicator::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_u64::<BigEndian>(self.paired_shares)?;
+    wrt.write_u64::<BigEndian>(self.imbalance_shares)?;
+    wrt.write_u8(self.imbalance_direction.code())?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u32::<BigEndian>(self.far_price.0)?;
+    wrt.write_u32::<BigEndian>(self.near_price.0)?;
+    wrt.write_u32::<BigEndian>(self.current_reference_price.0)?;
+    wrt.write_u8(self.cross_type.code())?;
+    wrt.write_u8(self.price_variation_indicator.code())?;
+    Ok(())
   }
 }
-pub trait ItchHandler {
-  fn on_system_event(&mut self, _msg: SystemEvent) {}
-  fn on_stock_directory(&mut self, _msg: StockDirectory) {}
-  fn on_stock_trading_action(&mut self, _msg: StockTradingAction) {}
-  fn on_reg_sho_restriction(&mut self, _msg: RegShoRestriction) {}
-  fn on_market_participant_position(&mut self, _msg: MarketParticipantPosition) {}
-  fn on_mwcb_decline_level(&mut self, _msg: MwcbDeclineLevel) {}
-  fn on_mwcb_status(&mut self, _msg: MwcbStatus) {}
-  fn on_ipo_quoting_period_update(&mut self, _msg: IpoQuotingPeriodUpdate) {}
-  fn on_luld_auction_collar(&mut self, _msg: LuldAuctionCollar) {}
-  fn on_operational_halt(&mut self, _msg: OperationalHalt) {}
-  fn on_add_order(&mut self, _msg: AddOrder) {}
-  fn on_add_order_with_mpid(&mut self, _msg: AddOrderWithMpid) {}
-  fn on_order_executed(&mut self, _msg: OrderExecuted) {}
-  fn on_order_executed_with_price(&mut self, _msg: OrderExecutedWithPrice) {}
-  fn on_order_cancel(&mut self, _msg: OrderCancel) {}
-  fn on_order_delete(&mut self, _msg: OrderDelete) {}
-  fn on_order_replace(&mut self, _msg: OrderReplace) {}
-  fn on_trade(&mut self, _msg: Trade) {}
-  fn on_cross_trade(&mut self, _msg: CrossTrade) {}
-  fn on_broken_trade(&mut self, _msg: BrokenTrade) {}
-  fn on_net_order_imbalance_indicator(&mut self, _msg: NetOrderImbalanceIndicator) {}
-  fn on_retail_price_improvement_indicator(&mut self, _msg: RetailPriceImprovementIndicator) {}
-  fn on_end_of_snapshot(&mut self, _msg: EndOfSnapshot) {}
-}
-
-pub fn crack_message<T: ItchHandler>(msg: &[u8], handler: &mut T) {
-  let tipe = msg[0];
-  let mut rdr = Cursor::new(msg);
-  match tipe {
-    SystemEvent::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const SystemEvent) };
-      if let Ok(msg) = SystemEvent::from_cursor(&mut rdr) {
-        handler.on_system_event(msg);
-      }
-      else { unreachable!() }
-    },
-    StockDirectory::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const StockDirectory) };
-      if let Ok(msg) = StockDirectory::from_cursor(&mut rdr) {
-        handler.on_stock_directory(msg);
-      }
-      else { unreachable!() }
-    },
-    StockTradingAction::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const StockTradingAction) };
-      if let Ok(msg) = StockTradingAction::from_cursor(&mut rdr) {
-        handler.on_stock_trading_action(msg);
-      }
-      else { unreachable!() }
-    },
-    RegShoRestriction::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const RegShoRestriction) };
-      if let Ok(msg) = RegShoRestriction::from_cursor(&mut rdr) {
-        handler.on_reg_sho_restriction(msg);
-      }
-      else { unreachable!() }
-    },
-    MarketParticipantPosition::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const MarketParticipantPosition) };
-      if let Ok(msg) = MarketParticipantPosition::from_cursor(&mut rdr) {
-        handler.on_market_participant_position(msg);
-      }
-      else { unreachable!() }
-    },
-    MwcbDeclineLevel::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const MwcbDeclineLevel) };
-      if let Ok(msg) = MwcbDeclineLevel::from_cursor(&mut rdr) {
-        handler.on_mwcb_decline_level(msg);
-      }
-      else { unreachable!() }
-    },
-    MwcbStatus::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const MwcbStatus) };
-      if let Ok(msg) = MwcbStatus::from_cursor(&mut rdr) {
-        handler.on_mwcb_status(msg);
-      }
-      else { unreachable!() }
-    },
-    IpoQuotingPeriodUpdate::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const IpoQuotingPeriodUpdate) };
-      if let Ok(msg) = IpoQuotingPeriodUpdate::from_cursor(&mut rdr) {
-        handler.on_ipo_quoting_period_update(msg);
-      }
-      else { unreachable!() }
-    },
-    LuldAuctionCollar::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const LuldAuctionCollar) };
-      if let Ok(msg) = LuldAuctionCollar::from_cursor(&mut rdr) {
-        handler.on_luld_auction_collar(msg);
-      }
-      else { unreachable!() }
-    },
-    OperationalHalt::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OperationalHalt) };
-      if let Ok(msg) = OperationalHalt::from_cursor(&mut rdr) {
-        handler.on_operational_halt(msg);
-      }
-      else { unreachable!() }
-    },
-    AddOrder::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const AddOrder) };
-      if let Ok(msg) = AddOrder::from_cursor(&mut rdr) {
-        handler.on_add_order(msg);
-      }
-      else { unreachable!() }
-    },
-    AddOrderWithMpid::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const AddOrderWithMpid) };
-      if let Ok(msg) = AddOrderWithMpid::from_cursor(&mut rdr) {
-        handler.on_add_order_with_mpid(msg);
-      }
-      else { unreachable!() }
-    },
-    OrderExecuted::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OrderExecuted) };
-      if let Ok(msg) = OrderExecuted::from_cursor(&mut rdr) {
-        handler.on_order_executed(msg);
-      }
-      else { unreachable!() }
-    },
-    OrderExecutedWithPrice::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OrderExecutedWithPrice) };
-      if let Ok(msg) = OrderExecutedWithPrice::from_cursor(&mut rdr) {
-        handler.on_order_executed_with_price(msg);
-      }
-      else { unreachable!() }
-    },
-    OrderCancel::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OrderCancel) };
-      if let Ok(msg) = OrderCancel::from_cursor(&mut rdr) {
-        handler.on_order_cancel(msg);
-      }
-      else { unreachable!() }
-    },
-    OrderDelete::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OrderDelete) };
-      if let Ok(msg) = OrderDelete::from_cursor(&mut rdr) {
-        handler.on_order_delete(msg);
-      }
-      else { unreachable!() }
-    },
-    OrderReplace::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const OrderReplace) };
-      if let Ok(msg) = OrderReplace::from_cursor(&mut rdr) {
-        handler.on_order_replace(msg);
-      }
-      else { unreachable!() }
-    },
-    Trade::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const Trade) };
-      if let Ok(msg) = Trade::from_cursor(&mut rdr) {
-        handler.on_trade(msg);
-      }
-      else { unreachable!() }
-    },
-    CrossTrade::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const CrossTrade) };
-      if let Ok(msg) = CrossTrade::from_cursor(&mut rdr) {
-        handler.on_cross_trade(msg);
-      }
-      else { unreachable!() }
-    },
-    BrokenTrade::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const BrokenTrade) };
-      if let Ok(msg) = BrokenTrade::from_cursor(&mut rdr) {
-        handler.on_broken_trade(msg);
-      }
-      else { unreachable!() }
-    },
-    NetOrderImbalanceIndicator::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const NetOrderImbalanceIndicator) };
-      if let Ok(msg) = NetOrderImbalanceIndicator::from_cursor(&mut rdr) {
-        handler.on_net_order_imbalance_indicator(msg);
-      }
-      else { unreachable!() }
-    },
-    RetailPriceImprovementIndicator::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const RetailPriceImprovementIndicator) };
-      if let Ok(msg) = RetailPriceImprovementIndicator::from_cursor(&mut rdr) {
-        handler.on_retail_price_improvement_indicator(msg);
-      }
-      else { unreachable!() }
-    },
-    EndOfSnapshot::TYPE => {
-      //let msg = unsafe { std::ptr::read(msg[..].as_ptr() as *const EndOfSnapshot) };
-      if let Ok(msg) = EndOfSnapshot::from_cursor(&mut rdr) {
-        handler.on_end_of_snapshot(msg);
-      }
-      else { unreachable!() }
-    },
-    _ => { panic!("unknown type!") },
-  }
-}
-pub fn write_system_event(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, event_code: eSystemEvent) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = SystemEvent::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(std::slice::from_ref(&event_code.0))?;
-  assert_eq!(wrt.position() - start_pos, SYSTEM_EVENT_SIZE as u64);
-  Ok(())
+
+impl Encode for RetailPriceImprovementIndicator {
+  const ENCODED_LEN : usize = RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(RetailPriceImprovementIndicator::TYPE)?;
+    wrt.write_u16::<BigEndian>(self.stock_locate)?;
+    wrt.write_u16::<BigEndian>(self.tracking_number)?;
+    wrt.write_all(&u64::to_be_bytes(self.timestamp.0)[2..])?;
+    wrt.write_all(&self.stock.0[..8])?;
+    wrt.write_u8(self.interest_flag.code())?;
+    Ok(())
+  }
 }
 
-pub fn write_system_event_struct(wrt: &mut Cursor<&mut [u8]>, msg: SystemEvent) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = SystemEvent::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(std::slice::from_ref(&msg.event_code.0))?;
-  assert_eq!(wrt.position() - start_pos, SYSTEM_EVENT_SIZE as u64);
-  Ok(())
+impl Encode for EndOfSnapshot {
+  const ENCODED_LEN : usize = END_OF_SNAPSHOT_SIZE;
+  fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    wrt.write_u8(EndOfSnapshot::TYPE)?;
+    wrt.write_all(&self.sequence_number[..20])?;
+    Ok(())
+  }
 }
-pub fn write_stock_directory(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], market_category: eMarketCategory, financial_status_indicator: eFinancialStatusIndicator, round_lot_size: u32, round_lots_only: eRoundLotsOnly, issue_classification: eIssueClassification, issue_sub_type: [u8;2], authenticity: eAuthenticity, short_sale_threshold_indicator: eShortSaleThresholdIndicator, ipo_flag: eIPOFlag, luld_reference_price_tier: eLULDReferencePriceTier, etp_flag: eETPFlag, etp_leverage_factor: u32, inverse_indicator: eInverseIndicator) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = StockDirectory::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&market_category.0))?;
-  wrt.write_all(std::slice::from_ref(&financial_status_indicator.0))?;
-  wrt.write_u32::<BigEndian>(round_lot_size)?;
-  wrt.write_all(std::slice::from_ref(&round_lots_only.0))?;
-  wrt.write_all(std::slice::from_ref(&issue_classification.0))?;
-  wrt.write_all(&issue_sub_type[..2])?;
-  wrt.write_all(std::slice::from_ref(&authenticity.0))?;
-  wrt.write_all(std::slice::from_ref(&short_sale_threshold_indicator.0))?;
-  wrt.write_all(std::slice::from_ref(&ipo_flag.0))?;
-  wrt.write_all(std::slice::from_ref(&luld_reference_price_tier.0))?;
-  wrt.write_all(std::slice::from_ref(&etp_flag.0))?;
-  wrt.write_u32::<BigEndian>(etp_leverage_factor)?;
-  wrt.write_all(std::slice::from_ref(&inverse_indicator.0))?;
-  assert_eq!(wrt.position() - start_pos, STOCK_DIRECTORY_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_system_event<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, event_code: eSystemEvent) -> std::io::Result<()> {
+  SystemEvent {
+    message_type: SystemEvent::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    event_code,
+  }.encode(wrt)
 }
 
-pub fn write_stock_directory_struct(wrt: &mut Cursor<&mut [u8]>, msg: StockDirectory) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = StockDirectory::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.market_category.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.financial_status_indicator.0))?;
-  wrt.write_u32::<BigEndian>(msg.round_lot_size)?;
-  wrt.write_all(std::slice::from_ref(&msg.round_lots_only.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.issue_classification.0))?;
-  wrt.write_all(&msg.issue_sub_type[..2])?;
-  wrt.write_all(std::slice::from_ref(&msg.authenticity.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.short_sale_threshold_indicator.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.ipo_flag.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.luld_reference_price_tier.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.etp_flag.0))?;
-  wrt.write_u32::<BigEndian>(msg.etp_leverage_factor)?;
-  wrt.write_all(std::slice::from_ref(&msg.inverse_indicator.0))?;
-  assert_eq!(wrt.position() - start_pos, STOCK_DIRECTORY_SIZE as u64);
-  Ok(())
+pub fn write_system_event_struct<W: Write>(wrt: &mut W, msg: SystemEvent) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_stock_trading_action(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], trading_state: eTradingState, reserved: u8, reason: [u8;4]) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = StockTradingAction::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&trading_state.0))?;
-  wrt.write_all(std::slice::from_ref(&reserved))?;
-  wrt.write_all(&reason[..4])?;
-  assert_eq!(wrt.position() - start_pos, STOCK_TRADING_ACTION_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_stock_directory<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, market_category: eMarketCategory, financial_status_indicator: eFinancialStatusIndicator, round_lot_size: u32, round_lots_only: eRoundLotsOnly, issue_classification: eIssueClassification, issue_sub_type: IssueSubType, authenticity: eAuthenticity, short_sale_threshold_indicator: eShortSaleThresholdIndicator, ipo_flag: eIPOFlag, luld_reference_price_tier: eLULDReferencePriceTier, etp_flag: eETPFlag, etp_leverage_factor: u32, inverse_indicator: eInverseIndicator) -> std::io::Result<()> {
+  StockDirectory {
+    message_type: StockDirectory::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    market_category,
+    financial_status_indicator,
+    round_lot_size,
+    round_lots_only,
+    issue_classification,
+    issue_sub_type,
+    authenticity,
+    short_sale_threshold_indicator,
+    ipo_flag,
+    luld_reference_price_tier,
+    etp_flag,
+    etp_leverage_factor,
+    inverse_indicator,
+  }.encode(wrt)
 }
 
-pub fn write_stock_trading_action_struct(wrt: &mut Cursor<&mut [u8]>, msg: StockTradingAction) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = StockTradingAction::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.trading_state.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.reserved))?;
-  wrt.write_all(&msg.reason[..4])?;
-  assert_eq!(wrt.position() - start_pos, STOCK_TRADING_ACTION_SIZE as u64);
-  Ok(())
+pub fn write_stock_directory_struct<W: Write>(wrt: &mut W, msg: StockDirectory) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_reg_sho_restriction(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], reg_sho_action: eRegSHOAction) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = RegShoRestriction::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&reg_sho_action.0))?;
-  assert_eq!(wrt.position() - start_pos, REG_SHO_RESTRICTION_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_stock_trading_action<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, trading_state: eTradingState, reserved: u8, reason: [u8;4]) -> std::io::Result<()> {
+  StockTradingAction {
+    message_type: StockTradingAction::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    trading_state,
+    reserved,
+    reason,
+  }.encode(wrt)
 }
 
-pub fn write_reg_sho_restriction_struct(wrt: &mut Cursor<&mut [u8]>, msg: RegShoRestriction) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = RegShoRestriction::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.reg_sho_action.0))?;
-  assert_eq!(wrt.position() - start_pos, REG_SHO_RESTRICTION_SIZE as u64);
-  Ok(())
+pub fn write_stock_trading_action_struct<W: Write>(wrt: &mut W, msg: StockTradingAction) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_market_participant_position(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, mpid: [u8;4], stock: [u8;8], primary_market_maker: ePrimaryMarketMaker, market_maker_mode: eMarketMakerMode, market_participant_state: eMarketParticipantState) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MarketParticipantPosition::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&mpid[..4])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&primary_market_maker.0))?;
-  wrt.write_all(std::slice::from_ref(&market_maker_mode.0))?;
-  wrt.write_all(std::slice::from_ref(&market_participant_state.0))?;
-  assert_eq!(wrt.position() - start_pos, MARKET_PARTICIPANT_POSITION_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_reg_sho_restriction<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, reg_sho_action: eRegSHOAction) -> std::io::Result<()> {
+  RegShoRestriction {
+    message_type: RegShoRestriction::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    reg_sho_action,
+  }.encode(wrt)
 }
 
-pub fn write_market_participant_position_struct(wrt: &mut Cursor<&mut [u8]>, msg: MarketParticipantPosition) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MarketParticipantPosition::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.mpid[..4])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.primary_market_maker.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.market_maker_mode.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.market_participant_state.0))?;
-  assert_eq!(wrt.position() - start_pos, MARKET_PARTICIPANT_POSITION_SIZE as u64);
-  Ok(())
+pub fn write_reg_sho_restriction_struct<W: Write>(wrt: &mut W, msg: RegShoRestriction) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_mwcb_decline_level(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, level_1: u64, level_2: u64, level_3: u64) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MwcbDeclineLevel::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(level_1)?;
-  wrt.write_u64::<BigEndian>(level_2)?;
-  wrt.write_u64::<BigEndian>(level_3)?;
-  assert_eq!(wrt.position() - start_pos, MWCB_DECLINE_LEVEL_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+#[allow(bindings_with_variant_name)]
+pub fn write_market_participant_position<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, mpid: Mpid, stock: Symbol, primary_market_maker: ePrimaryMarketMaker, market_maker_mode: eMarketMakerMode, market_participant_state: eMarketParticipantState) -> std::io::Result<()> {
+  MarketParticipantPosition {
+    message_type: MarketParticipantPosition::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    mpid,
+    stock,
+    primary_market_maker,
+    market_maker_mode,
+    market_participant_state,
+  }.encode(wrt)
 }
 
-pub fn write_mwcb_decline_level_struct(wrt: &mut Cursor<&mut [u8]>, msg: MwcbDeclineLevel) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MwcbDeclineLevel::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.level_1)?;
-  wrt.write_u64::<BigEndian>(msg.level_2)?;
-  wrt.write_u64::<BigEndian>(msg.level_3)?;
-  assert_eq!(wrt.position() - start_pos, MWCB_DECLINE_LEVEL_SIZE as u64);
-  Ok(())
+pub fn write_market_participant_position_struct<W: Write>(wrt: &mut W, msg: MarketParticipantPosition) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_mwcb_status(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, breached_level: eBreachedLevel) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MwcbStatus::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(std::slice::from_ref(&breached_level.0))?;
-  assert_eq!(wrt.position() - start_pos, MWCB_STATUS_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_mwcb_decline_level<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, level_1: Price8, level_2: Price8, level_3: Price8) -> std::io::Result<()> {
+  MwcbDeclineLevel {
+    message_type: MwcbDeclineLevel::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    level_1,
+    level_2,
+    level_3,
+  }.encode(wrt)
 }
 
-pub fn write_mwcb_status_struct(wrt: &mut Cursor<&mut [u8]>, msg: MwcbStatus) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = MwcbStatus::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(std::slice::from_ref(&msg.breached_level.0))?;
-  assert_eq!(wrt.position() - start_pos, MWCB_STATUS_SIZE as u64);
-  Ok(())
+pub fn write_mwcb_decline_level_struct<W: Write>(wrt: &mut W, msg: MwcbDeclineLevel) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_ipo_quoting_period_update(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], ipo_quotation_release_time: u32, ipo_quotation_release_qualifier: eIPOQuotationReleaseQualifier, ipo_price: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = IpoQuotingPeriodUpdate::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(ipo_quotation_release_time)?;
-  wrt.write_all(std::slice::from_ref(&ipo_quotation_release_qualifier.0))?;
-  wrt.write_u32::<BigEndian>(ipo_price)?;
-  assert_eq!(wrt.position() - start_pos, IPO_QUOTING_PERIOD_UPDATE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_mwcb_status<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, breached_level: eBreachedLevel) -> std::io::Result<()> {
+  MwcbStatus {
+    message_type: MwcbStatus::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    breached_level,
+  }.encode(wrt)
 }
 
-pub fn write_ipo_quoting_period_update_struct(wrt: &mut Cursor<&mut [u8]>, msg: IpoQuotingPeriodUpdate) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = IpoQuotingPeriodUpdate::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.ipo_quotation_release_time)?;
-  wrt.write_all(std::slice::from_ref(&msg.ipo_quotation_release_qualifier.0))?;
-  wrt.write_u32::<BigEndian>(msg.ipo_price)?;
-  assert_eq!(wrt.position() - start_pos, IPO_QUOTING_PERIOD_UPDATE_SIZE as u64);
-  Ok(())
+pub fn write_mwcb_status_struct<W: Write>(wrt: &mut W, msg: MwcbStatus) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_luld_auction_collar(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], auction_collar_reference_price: u32, upper_auction_collar_price: u32, lower_auction_collar_price: u32, auction_collar_extension: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = LuldAuctionCollar::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(auction_collar_reference_price)?;
-  wrt.write_u32::<BigEndian>(upper_auction_collar_price)?;
-  wrt.write_u32::<BigEndian>(lower_auction_collar_price)?;
-  wrt.write_u32::<BigEndian>(auction_collar_extension)?;
-  assert_eq!(wrt.position() - start_pos, LULD_AUCTION_COLLAR_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_ipo_quoting_period_update<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, ipo_quotation_release_time: u32, ipo_quotation_release_qualifier: eIPOQuotationReleaseQualifier, ipo_price: Price4) -> std::io::Result<()> {
+  IpoQuotingPeriodUpdate {
+    message_type: IpoQuotingPeriodUpdate::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    ipo_quotation_release_time,
+    ipo_quotation_release_qualifier,
+    ipo_price,
+  }.encode(wrt)
 }
 
-pub fn write_luld_auction_collar_struct(wrt: &mut Cursor<&mut [u8]>, msg: LuldAuctionCollar) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = LuldAuctionCollar::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.auction_collar_reference_price)?;
-  wrt.write_u32::<BigEndian>(msg.upper_auction_collar_price)?;
-  wrt.write_u32::<BigEndian>(msg.lower_auction_collar_price)?;
-  wrt.write_u32::<BigEndian>(msg.auction_collar_extension)?;
-  assert_eq!(wrt.position() - start_pos, LULD_AUCTION_COLLAR_SIZE as u64);
-  Ok(())
+pub fn write_ipo_quoting_period_update_struct<W: Write>(wrt: &mut W, msg: IpoQuotingPeriodUpdate) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_operational_halt(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], market_code: eMarketCode, operational_halt_action: eOperationalHaltAction) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OperationalHalt::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&market_code.0))?;
-  wrt.write_all(std::slice::from_ref(&operational_halt_action.0))?;
-  assert_eq!(wrt.position() - start_pos, OPERATIONAL_HALT_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_luld_auction_collar<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, auction_collar_reference_price: Price4, upper_auction_collar_price: Price4, lower_auction_collar_price: Price4, auction_collar_extension: u32) -> std::io::Result<()> {
+  LuldAuctionCollar {
+    message_type: LuldAuctionCollar::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    auction_collar_reference_price,
+    upper_auction_collar_price,
+    lower_auction_collar_price,
+    auction_collar_extension,
+  }.encode(wrt)
 }
 
-pub fn write_operational_halt_struct(wrt: &mut Cursor<&mut [u8]>, msg: OperationalHalt) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OperationalHalt::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.market_code.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.operational_halt_action.0))?;
-  assert_eq!(wrt.position() - start_pos, OPERATIONAL_HALT_SIZE as u64);
-  Ok(())
+pub fn write_luld_auction_collar_struct<W: Write>(wrt: &mut W, msg: LuldAuctionCollar) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_add_order(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: [u8;8], price: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = AddOrder::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(shares)?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(price)?;
-  assert_eq!(wrt.position() - start_pos, ADD_ORDER_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_operational_halt<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, market_code: eMarketCode, operational_halt_action: eOperationalHaltAction) -> std::io::Result<()> {
+  OperationalHalt {
+    message_type: OperationalHalt::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    market_code,
+    operational_halt_action,
+  }.encode(wrt)
 }
 
-pub fn write_add_order_struct(wrt: &mut Cursor<&mut [u8]>, msg: AddOrder) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = AddOrder::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&msg.buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(msg.shares)?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.price)?;
-  assert_eq!(wrt.position() - start_pos, ADD_ORDER_SIZE as u64);
-  Ok(())
+pub fn write_operational_halt_struct<W: Write>(wrt: &mut W, msg: OperationalHalt) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_add_order_with_mpid(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: [u8;8], price: u32, attribution: [u8;4]) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = AddOrderWithMpid::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(shares)?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(price)?;
-  wrt.write_all(&attribution[..4])?;
-  assert_eq!(wrt.position() - start_pos, ADD_ORDER_WITH_MPID_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_add_order<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: Symbol, price: Price4) -> std::io::Result<()> {
+  AddOrder {
+    message_type: AddOrder::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    buy_sell_indicator,
+    shares,
+    stock,
+    price,
+  }.encode(wrt)
 }
 
-pub fn write_add_order_with_mpid_struct(wrt: &mut Cursor<&mut [u8]>, msg: AddOrderWithMpid) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = AddOrderWithMpid::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&msg.buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(msg.shares)?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.price)?;
-  wrt.write_all(&msg.attribution[..4])?;
-  assert_eq!(wrt.position() - start_pos, ADD_ORDER_WITH_MPID_SIZE as u64);
-  Ok(())
+pub fn write_add_order_struct<W: Write>(wrt: &mut W, msg: AddOrder) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_order_executed(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, executed_shares: u32, match_number: u64) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderExecuted::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_u32::<BigEndian>(executed_shares)?;
-  wrt.write_u64::<BigEndian>(match_number)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_EXECUTED_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_add_order_with_mpid<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: Symbol, price: Price4, attribution: Mpid) -> std::io::Result<()> {
+  AddOrderWithMpid {
+    message_type: AddOrderWithMpid::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    buy_sell_indicator,
+    shares,
+    stock,
+    price,
+    attribution,
+  }.encode(wrt)
 }
 
-pub fn write_order_executed_struct(wrt: &mut Cursor<&mut [u8]>, msg: OrderExecuted) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderExecuted::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_u32::<BigEndian>(msg.executed_shares)?;
-  wrt.write_u64::<BigEndian>(msg.match_number)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_EXECUTED_SIZE as u64);
-  Ok(())
+pub fn write_add_order_with_mpid_struct<W: Write>(wrt: &mut W, msg: AddOrderWithMpid) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_order_executed_with_price(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, executed_shares: u32, match_number: u64, printable: ePrintable, execution_price: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderExecutedWithPrice::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_u32::<BigEndian>(executed_shares)?;
-  wrt.write_u64::<BigEndian>(match_number)?;
-  wrt.write_all(std::slice::from_ref(&printable.0))?;
-  wrt.write_u32::<BigEndian>(execution_price)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_EXECUTED_WITH_PRICE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_order_executed<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, executed_shares: u32, match_number: u64) -> std::io::Result<()> {
+  OrderExecuted {
+    message_type: OrderExecuted::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    executed_shares,
+    match_number,
+  }.encode(wrt)
 }
 
-pub fn write_order_executed_with_price_struct(wrt: &mut Cursor<&mut [u8]>, msg: OrderExecutedWithPrice) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderExecutedWithPrice::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_u32::<BigEndian>(msg.executed_shares)?;
-  wrt.write_u64::<BigEndian>(msg.match_number)?;
-  wrt.write_all(std::slice::from_ref(&msg.printable.0))?;
-  wrt.write_u32::<BigEndian>(msg.execution_price)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_EXECUTED_WITH_PRICE_SIZE as u64);
-  Ok(())
+pub fn write_order_executed_struct<W: Write>(wrt: &mut W, msg: OrderExecuted) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_order_cancel(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, cancelled_shares: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderCancel::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_u32::<BigEndian>(cancelled_shares)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_CANCEL_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_order_executed_with_price<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, executed_shares: u32, match_number: u64, printable: ePrintable, execution_price: Price4) -> std::io::Result<()> {
+  OrderExecutedWithPrice {
+    message_type: OrderExecutedWithPrice::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    executed_shares,
+    match_number,
+    printable,
+    execution_price,
+  }.encode(wrt)
 }
 
-pub fn write_order_cancel_struct(wrt: &mut Cursor<&mut [u8]>, msg: OrderCancel) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderCancel::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_u32::<BigEndian>(msg.cancelled_shares)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_CANCEL_SIZE as u64);
-  Ok(())
+pub fn write_order_executed_with_price_struct<W: Write>(wrt: &mut W, msg: OrderExecutedWithPrice) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_order_delete(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderDelete::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_DELETE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_order_cancel<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, cancelled_shares: u32) -> std::io::Result<()> {
+  OrderCancel {
+    message_type: OrderCancel::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    cancelled_shares,
+  }.encode(wrt)
 }
 
-pub fn write_order_delete_struct(wrt: &mut Cursor<&mut [u8]>, msg: OrderDelete) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderDelete::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_DELETE_SIZE as u64);
-  Ok(())
+pub fn write_order_cancel_struct<W: Write>(wrt: &mut W, msg: OrderCancel) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_order_replace(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, original_order_reference_number: u64, new_order_reference_number: u64, shares: u32, price: u32) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderReplace::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(original_order_reference_number)?;
-  wrt.write_u64::<BigEndian>(new_order_reference_number)?;
-  wrt.write_u32::<BigEndian>(shares)?;
-  wrt.write_u32::<BigEndian>(price)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_REPLACE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_order_delete<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64) -> std::io::Result<()> {
+  OrderDelete {
+    message_type: OrderDelete::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+  }.encode(wrt)
 }
 
-pub fn write_order_replace_struct(wrt: &mut Cursor<&mut [u8]>, msg: OrderReplace) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = OrderReplace::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.original_order_reference_number)?;
-  wrt.write_u64::<BigEndian>(msg.new_order_reference_number)?;
-  wrt.write_u32::<BigEndian>(msg.shares)?;
-  wrt.write_u32::<BigEndian>(msg.price)?;
-  assert_eq!(wrt.position() - start_pos, ORDER_REPLACE_SIZE as u64);
-  Ok(())
+pub fn write_order_delete_struct<W: Write>(wrt: &mut W, msg: OrderDelete) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_trade(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: [u8;8], price: u32, match_number: u64) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = Trade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(shares)?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(price)?;
-  wrt.write_u64::<BigEndian>(match_number)?;
-  assert_eq!(wrt.position() - start_pos, TRADE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_order_replace<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, original_order_reference_number: u64, new_order_reference_number: u64, shares: u32, price: Price4) -> std::io::Result<()> {
+  OrderReplace {
+    message_type: OrderReplace::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    original_order_reference_number,
+    new_order_reference_number,
+    shares,
+    price,
+  }.encode(wrt)
 }
 
-pub fn write_trade_struct(wrt: &mut Cursor<&mut [u8]>, msg: Trade) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = Trade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.order_reference_number)?;
-  wrt.write_all(std::slice::from_ref(&msg.buy_sell_indicator.0))?;
-  wrt.write_u32::<BigEndian>(msg.shares)?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.price)?;
-  wrt.write_u64::<BigEndian>(msg.match_number)?;
-  assert_eq!(wrt.position() - start_pos, TRADE_SIZE as u64);
-  Ok(())
+pub fn write_order_replace_struct<W: Write>(wrt: &mut W, msg: OrderReplace) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_cross_trade(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, shares: u64, stock: [u8;8], cross_price: u32, match_number: u64, cross_type: eCrossType) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = CrossTrade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(shares)?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(cross_price)?;
-  wrt.write_u64::<BigEndian>(match_number)?;
-  wrt.write_all(std::slice::from_ref(&cross_type.0))?;
-  assert_eq!(wrt.position() - start_pos, CROSS_TRADE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_trade<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, order_reference_number: u64, buy_sell_indicator: eBuySellIndicator, shares: u32, stock: Symbol, price: Price4, match_number: u64) -> std::io::Result<()> {
+  Trade {
+    message_type: Trade::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    order_reference_number,
+    buy_sell_indicator,
+    shares,
+    stock,
+    price,
+    match_number,
+  }.encode(wrt)
 }
 
-pub fn write_cross_trade_struct(wrt: &mut Cursor<&mut [u8]>, msg: CrossTrade) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = CrossTrade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.shares)?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.cross_price)?;
-  wrt.write_u64::<BigEndian>(msg.match_number)?;
-  wrt.write_all(std::slice::from_ref(&msg.cross_type.0))?;
-  assert_eq!(wrt.position() - start_pos, CROSS_TRADE_SIZE as u64);
-  Ok(())
+pub fn write_trade_struct<W: Write>(wrt: &mut W, msg: Trade) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_broken_trade(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, match_number: u64) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = BrokenTrade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(match_number)?;
-  assert_eq!(wrt.position() - start_pos, BROKEN_TRADE_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_cross_trade<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, shares: u64, stock: Symbol, cross_price: Price4, match_number: u64, cross_type: eCrossType) -> std::io::Result<()> {
+  CrossTrade {
+    message_type: CrossTrade::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    shares,
+    stock,
+    cross_price,
+    match_number,
+    cross_type,
+  }.encode(wrt)
 }
 
-pub fn write_broken_trade_struct(wrt: &mut Cursor<&mut [u8]>, msg: BrokenTrade) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = BrokenTrade::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.match_number)?;
-  assert_eq!(wrt.position() - start_pos, BROKEN_TRADE_SIZE as u64);
-  Ok(())
+pub fn write_cross_trade_struct<W: Write>(wrt: &mut W, msg: CrossTrade) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_net_order_imbalance_indicator(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, paired_shares: u64, imbalance_shares: u64, imbalance_direction: eImbalanceDirection, stock: [u8;8], far_price: u32, near_price: u32, current_reference_price: u32, cross_type: eCrossType, price_variation_indicator: ePriceVariationIndicator) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = NetOrderImbalanceIndicator::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(paired_shares)?;
-  wrt.write_u64::<BigEndian>(imbalance_shares)?;
-  wrt.write_all(std::slice::from_ref(&imbalance_direction.0))?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_u32::<BigEndian>(far_price)?;
-  wrt.write_u32::<BigEndian>(near_price)?;
-  wrt.write_u32::<BigEndian>(current_reference_price)?;
-  wrt.write_all(std::slice::from_ref(&cross_type.0))?;
-  wrt.write_all(std::slice::from_ref(&price_variation_indicator.0))?;
-  assert_eq!(wrt.position() - start_pos, NET_ORDER_IMBALANCE_INDICATOR_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_broken_trade<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, match_number: u64) -> std::io::Result<()> {
+  BrokenTrade {
+    message_type: BrokenTrade::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    match_number,
+  }.encode(wrt)
 }
 
-pub fn write_net_order_imbalance_indicator_struct(wrt: &mut Cursor<&mut [u8]>, msg: NetOrderImbalanceIndicator) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = NetOrderImbalanceIndicator::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_u64::<BigEndian>(msg.paired_shares)?;
-  wrt.write_u64::<BigEndian>(msg.imbalance_shares)?;
-  wrt.write_all(std::slice::from_ref(&msg.imbalance_direction.0))?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_u32::<BigEndian>(msg.far_price)?;
-  wrt.write_u32::<BigEndian>(msg.near_price)?;
-  wrt.write_u32::<BigEndian>(msg.current_reference_price)?;
-  wrt.write_all(std::slice::from_ref(&msg.cross_type.0))?;
-  wrt.write_all(std::slice::from_ref(&msg.price_variation_indicator.0))?;
-  assert_eq!(wrt.position() - start_pos, NET_ORDER_IMBALANCE_INDICATOR_SIZE as u64);
-  Ok(())
+pub fn write_broken_trade_struct<W: Write>(wrt: &mut W, msg: BrokenTrade) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_retail_price_improvement_indicator(wrt: &mut Cursor<&mut [u8]>, stock_locate: u16, tracking_number: u16, timestamp: u64, stock: [u8;8], interest_flag: eInterestFlag) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = RetailPriceImprovementIndicator::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(stock_locate)?;
-  wrt.write_u16::<BigEndian>(tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(timestamp)[2..])?;
-  wrt.write_all(&stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&interest_flag.0))?;
-  assert_eq!(wrt.position() - start_pos, RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_net_order_imbalance_indicator<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, paired_shares: u64, imbalance_shares: u64, imbalance_direction: eImbalanceDirection, stock: Symbol, far_price: Price4, near_price: Price4, current_reference_price: Price4, cross_type: eCrossType, price_variation_indicator: ePriceVariationIndicator) -> std::io::Result<()> {
+  NetOrderImbalanceIndicator {
+    message_type: NetOrderImbalanceIndicator::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    paired_shares,
+    imbalance_shares,
+    imbalance_direction,
+    stock,
+    far_price,
+    near_price,
+    current_reference_price,
+    cross_type,
+    price_variation_indicator,
+  }.encode(wrt)
 }
 
-pub fn write_retail_price_improvement_indicator_struct(wrt: &mut Cursor<&mut [u8]>, msg: RetailPriceImprovementIndicator) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = RetailPriceImprovementIndicator::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_u16::<BigEndian>(msg.stock_locate)?;
-  wrt.write_u16::<BigEndian>(msg.tracking_number)?;
-  wrt.write_all(&u64::to_be_bytes(msg.timestamp)[2..])?;
-  wrt.write_all(&msg.stock[..8])?;
-  wrt.write_all(std::slice::from_ref(&msg.interest_flag.0))?;
-  assert_eq!(wrt.position() - start_pos, RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE as u64);
-  Ok(())
+pub fn write_net_order_imbalance_indicator_struct<W: Write>(wrt: &mut W, msg: NetOrderImbalanceIndicator) -> std::io::Result<()> {
+  msg.encode(wrt)
 }
-pub fn write_end_of_snapshot(wrt: &mut Cursor<&mut [u8]>, sequence_number: [u8;20]) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = EndOfSnapshot::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_all(&sequence_number[..20])?;
-  assert_eq!(wrt.position() - start_pos, END_OF_SNAPSHOT_SIZE as u64);
-  Ok(())
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_retail_price_improvement_indicator<W: Write>(wrt: &mut W, stock_locate: u16, tracking_number: u16, timestamp: ItchTimestamp, stock: Symbol, interest_flag: eInterestFlag) -> std::io::Result<()> {
+  RetailPriceImprovementIndicator {
+    message_type: RetailPriceImprovementIndicator::TYPE,
+    stock_locate,
+    tracking_number,
+    timestamp,
+    stock,
+    interest_flag,
+  }.encode(wrt)
 }
 
-pub fn write_end_of_snapshot_struct(wrt: &mut Cursor<&mut [u8]>, msg: EndOfSnapshot) -> std::io::Result<()> {
-  let start_pos = wrt.position();
-  let tipe = EndOfSnapshot::TYPE;
-  wrt.write_all(std::slice::from_ref(&tipe))?;
-  wrt.write_all(&msg.sequence_number[..20])?;
-  assert_eq!(wrt.position() - start_pos, END_OF_SNAPSHOT_SIZE as u64);
-  Ok(())
+pub fn write_retail_price_improvement_indicator_struct<W: Write>(wrt: &mut W, msg: RetailPriceImprovementIndicator) -> std::io::Result<()> {
+  msg.encode(wrt)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_end_of_snapshot<W: Write>(wrt: &mut W, sequence_number: [u8;20]) -> std::io::Result<()> {
+  EndOfSnapshot {
+    message_type: EndOfSnapshot::TYPE,
+    sequence_number,
+  }.encode(wrt)
+}
+
+pub fn write_end_of_snapshot_struct<W: Write>(wrt: &mut W, msg: EndOfSnapshot) -> std::io::Result<()> {
+  msg.encode(wrt)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItchMessage {
+  SystemEvent(SystemEvent),
+  StockDirectory(StockDirectory),
+  StockTradingAction(StockTradingAction),
+  RegShoRestriction(RegShoRestriction),
+  MarketParticipantPosition(MarketParticipantPosition),
+  MwcbDeclineLevel(MwcbDeclineLevel),
+  MwcbStatus(MwcbStatus),
+  IpoQuotingPeriodUpdate(IpoQuotingPeriodUpdate),
+  LuldAuctionCollar(LuldAuctionCollar),
+  OperationalHalt(OperationalHalt),
+  AddOrder(AddOrder),
+  AddOrderWithMpid(AddOrderWithMpid),
+  OrderExecuted(OrderExecuted),
+  OrderExecutedWithPrice(OrderExecutedWithPrice),
+  OrderCancel(OrderCancel),
+  OrderDelete(OrderDelete),
+  OrderReplace(OrderReplace),
+  Trade(Trade),
+  CrossTrade(CrossTrade),
+  BrokenTrade(BrokenTrade),
+  NetOrderImbalanceIndicator(NetOrderImbalanceIndicator),
+  RetailPriceImprovementIndicator(RetailPriceImprovementIndicator),
+  EndOfSnapshot(EndOfSnapshot),
+}
+
+impl ItchMessage {
+  pub fn decode(bytes: &[u8]) -> Result<(ItchMessage, usize), DecodeError> {
+    let tipe = match bytes.first() {
+      Some(tipe) => *tipe,
+      None => return Err(DecodeError::Empty),
+    };
+    match tipe {
+      SystemEvent::TYPE => Ok((ItchMessage::SystemEvent(SystemEvent::decode(bytes)?), SYSTEM_EVENT_SIZE)),
+      StockDirectory::TYPE => Ok((ItchMessage::StockDirectory(StockDirectory::decode(bytes)?), STOCK_DIRECTORY_SIZE)),
+      StockTradingAction::TYPE => Ok((ItchMessage::StockTradingAction(StockTradingAction::decode(bytes)?), STOCK_TRADING_ACTION_SIZE)),
+      RegShoRestriction::TYPE => Ok((ItchMessage::RegShoRestriction(RegShoRestriction::decode(bytes)?), REG_SHO_RESTRICTION_SIZE)),
+      MarketParticipantPosition::TYPE => Ok((ItchMessage::MarketParticipantPosition(MarketParticipantPosition::decode(bytes)?), MARKET_PARTICIPANT_POSITION_SIZE)),
+      MwcbDeclineLevel::TYPE => Ok((ItchMessage::MwcbDeclineLevel(MwcbDeclineLevel::decode(bytes)?), MWCB_DECLINE_LEVEL_SIZE)),
+      MwcbStatus::TYPE => Ok((ItchMessage::MwcbStatus(MwcbStatus::decode(bytes)?), MWCB_STATUS_SIZE)),
+      IpoQuotingPeriodUpdate::TYPE => Ok((ItchMessage::IpoQuotingPeriodUpdate(IpoQuotingPeriodUpdate::decode(bytes)?), IPO_QUOTING_PERIOD_UPDATE_SIZE)),
+      LuldAuctionCollar::TYPE => Ok((ItchMessage::LuldAuctionCollar(LuldAuctionCollar::decode(bytes)?), LULD_AUCTION_COLLAR_SIZE)),
+      OperationalHalt::TYPE => Ok((ItchMessage::OperationalHalt(OperationalHalt::decode(bytes)?), OPERATIONAL_HALT_SIZE)),
+      AddOrder::TYPE => Ok((ItchMessage::AddOrder(AddOrder::decode(bytes)?), ADD_ORDER_SIZE)),
+      AddOrderWithMpid::TYPE => Ok((ItchMessage::AddOrderWithMpid(AddOrderWithMpid::decode(bytes)?), ADD_ORDER_WITH_MPID_SIZE)),
+      OrderExecuted::TYPE => Ok((ItchMessage::OrderExecuted(OrderExecuted::decode(bytes)?), ORDER_EXECUTED_SIZE)),
+      OrderExecutedWithPrice::TYPE => Ok((ItchMessage::OrderExecutedWithPrice(OrderExecutedWithPrice::decode(bytes)?), ORDER_EXECUTED_WITH_PRICE_SIZE)),
+      OrderCancel::TYPE => Ok((ItchMessage::OrderCancel(OrderCancel::decode(bytes)?), ORDER_CANCEL_SIZE)),
+      OrderDelete::TYPE => Ok((ItchMessage::OrderDelete(OrderDelete::decode(bytes)?), ORDER_DELETE_SIZE)),
+      OrderReplace::TYPE => Ok((ItchMessage::OrderReplace(OrderReplace::decode(bytes)?), ORDER_REPLACE_SIZE)),
+      Trade::TYPE => Ok((ItchMessage::Trade(Trade::decode(bytes)?), TRADE_SIZE)),
+      CrossTrade::TYPE => Ok((ItchMessage::CrossTrade(CrossTrade::decode(bytes)?), CROSS_TRADE_SIZE)),
+      BrokenTrade::TYPE => Ok((ItchMessage::BrokenTrade(BrokenTrade::decode(bytes)?), BROKEN_TRADE_SIZE)),
+      NetOrderImbalanceIndicator::TYPE => Ok((ItchMessage::NetOrderImbalanceIndicator(NetOrderImbalanceIndicator::decode(bytes)?), NET_ORDER_IMBALANCE_INDICATOR_SIZE)),
+      RetailPriceImprovementIndicator::TYPE => Ok((ItchMessage::RetailPriceImprovementIndicator(RetailPriceImprovementIndicator::decode(bytes)?), RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE)),
+      EndOfSnapshot::TYPE => Ok((ItchMessage::EndOfSnapshot(EndOfSnapshot::decode(bytes)?), END_OF_SNAPSHOT_SIZE)),
+      _ => Err(DecodeError::UnknownType { type_byte: tipe, offset: 0 }),
+    }
+  }
+  // wire size of a message from its type byte, None if the type is unknown
+  pub fn size_of_type(tipe: u8) -> Option<usize> {
+    match tipe {
+      SystemEvent::TYPE => Some(SYSTEM_EVENT_SIZE),
+      StockDirectory::TYPE => Some(STOCK_DIRECTORY_SIZE),
+      StockTradingAction::TYPE => Some(STOCK_TRADING_ACTION_SIZE),
+      RegShoRestriction::TYPE => Some(REG_SHO_RESTRICTION_SIZE),
+      MarketParticipantPosition::TYPE => Some(MARKET_PARTICIPANT_POSITION_SIZE),
+      MwcbDeclineLevel::TYPE => Some(MWCB_DECLINE_LEVEL_SIZE),
+      MwcbStatus::TYPE => Some(MWCB_STATUS_SIZE),
+      IpoQuotingPeriodUpdate::TYPE => Some(IPO_QUOTING_PERIOD_UPDATE_SIZE),
+      LuldAuctionCollar::TYPE => Some(LULD_AUCTION_COLLAR_SIZE),
+      OperationalHalt::TYPE => Some(OPERATIONAL_HALT_SIZE),
+      AddOrder::TYPE => Some(ADD_ORDER_SIZE),
+      AddOrderWithMpid::TYPE => Some(ADD_ORDER_WITH_MPID_SIZE),
+      OrderExecuted::TYPE => Some(ORDER_EXECUTED_SIZE),
+      OrderExecutedWithPrice::TYPE => Some(ORDER_EXECUTED_WITH_PRICE_SIZE),
+      OrderCancel::TYPE => Some(ORDER_CANCEL_SIZE),
+      OrderDelete::TYPE => Some(ORDER_DELETE_SIZE),
+      OrderReplace::TYPE => Some(ORDER_REPLACE_SIZE),
+      Trade::TYPE => Some(TRADE_SIZE),
+      CrossTrade::TYPE => Some(CROSS_TRADE_SIZE),
+      BrokenTrade::TYPE => Some(BROKEN_TRADE_SIZE),
+      NetOrderImbalanceIndicator::TYPE => Some(NET_ORDER_IMBALANCE_INDICATOR_SIZE),
+      RetailPriceImprovementIndicator::TYPE => Some(RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE),
+      EndOfSnapshot::TYPE => Some(END_OF_SNAPSHOT_SIZE),
+      _ => None,
+    }
+  }
+  pub fn name_of_type(tipe: u8) -> Option<&'static str> {
+    match tipe {
+      SystemEvent::TYPE => Some(SystemEvent::NAME),
+      StockDirectory::TYPE => Some(StockDirectory::NAME),
+      StockTradingAction::TYPE => Some(StockTradingAction::NAME),
+      RegShoRestriction::TYPE => Some(RegShoRestriction::NAME),
+      MarketParticipantPosition::TYPE => Some(MarketParticipantPosition::NAME),
+      MwcbDeclineLevel::TYPE => Some(MwcbDeclineLevel::NAME),
+      MwcbStatus::TYPE => Some(MwcbStatus::NAME),
+      IpoQuotingPeriodUpdate::TYPE => Some(IpoQuotingPeriodUpdate::NAME),
+      LuldAuctionCollar::TYPE => Some(LuldAuctionCollar::NAME),
+      OperationalHalt::TYPE => Some(OperationalHalt::NAME),
+      AddOrder::TYPE => Some(AddOrder::NAME),
+      AddOrderWithMpid::TYPE => Some(AddOrderWithMpid::NAME),
+      OrderExecuted::TYPE => Some(OrderExecuted::NAME),
+      OrderExecutedWithPrice::TYPE => Some(OrderExecutedWithPrice::NAME),
+      OrderCancel::TYPE => Some(OrderCancel::NAME),
+      OrderDelete::TYPE => Some(OrderDelete::NAME),
+      OrderReplace::TYPE => Some(OrderReplace::NAME),
+      Trade::TYPE => Some(Trade::NAME),
+      CrossTrade::TYPE => Some(CrossTrade::NAME),
+      BrokenTrade::TYPE => Some(BrokenTrade::NAME),
+      NetOrderImbalanceIndicator::TYPE => Some(NetOrderImbalanceIndicator::NAME),
+      RetailPriceImprovementIndicator::TYPE => Some(RetailPriceImprovementIndicator::NAME),
+      EndOfSnapshot::TYPE => Some(EndOfSnapshot::NAME),
+      _ => None,
+    }
+  }
+  pub fn encode<W: Write>(&self, wrt: &mut W) -> std::io::Result<()> {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => msg.encode(wrt),
+      ItchMessage::StockDirectory(ref msg) => msg.encode(wrt),
+      ItchMessage::StockTradingAction(ref msg) => msg.encode(wrt),
+      ItchMessage::RegShoRestriction(ref msg) => msg.encode(wrt),
+      ItchMessage::MarketParticipantPosition(ref msg) => msg.encode(wrt),
+      ItchMessage::MwcbDeclineLevel(ref msg) => msg.encode(wrt),
+      ItchMessage::MwcbStatus(ref msg) => msg.encode(wrt),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => msg.encode(wrt),
+      ItchMessage::LuldAuctionCollar(ref msg) => msg.encode(wrt),
+      ItchMessage::OperationalHalt(ref msg) => msg.encode(wrt),
+      ItchMessage::AddOrder(ref msg) => msg.encode(wrt),
+      ItchMessage::AddOrderWithMpid(ref msg) => msg.encode(wrt),
+      ItchMessage::OrderExecuted(ref msg) => msg.encode(wrt),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => msg.encode(wrt),
+      ItchMessage::OrderCancel(ref msg) => msg.encode(wrt),
+      ItchMessage::OrderDelete(ref msg) => msg.encode(wrt),
+      ItchMessage::OrderReplace(ref msg) => msg.encode(wrt),
+      ItchMessage::Trade(ref msg) => msg.encode(wrt),
+      ItchMessage::CrossTrade(ref msg) => msg.encode(wrt),
+      ItchMessage::BrokenTrade(ref msg) => msg.encode(wrt),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => msg.encode(wrt),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => msg.encode(wrt),
+      ItchMessage::EndOfSnapshot(ref msg) => msg.encode(wrt),
+    }
+  }
+  pub fn encode_to_slice(&self, buf: &mut [u8]) -> std::io::Result<usize> {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::StockDirectory(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::StockTradingAction(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::RegShoRestriction(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::MarketParticipantPosition(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::MwcbDeclineLevel(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::MwcbStatus(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::LuldAuctionCollar(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OperationalHalt(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::AddOrder(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::AddOrderWithMpid(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OrderExecuted(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OrderCancel(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OrderDelete(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::OrderReplace(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::Trade(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::CrossTrade(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::BrokenTrade(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => msg.encode_to_slice(buf),
+      ItchMessage::EndOfSnapshot(ref msg) => msg.encode_to_slice(buf),
+    }
+  }
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(self.encoded_len());
+    self.encode(&mut bytes).unwrap();
+    bytes
+  }
+  pub fn encoded_len(&self) -> usize {
+    match *self {
+      ItchMessage::SystemEvent(_) => SYSTEM_EVENT_SIZE,
+      ItchMessage::StockDirectory(_) => STOCK_DIRECTORY_SIZE,
+      ItchMessage::StockTradingAction(_) => STOCK_TRADING_ACTION_SIZE,
+      ItchMessage::RegShoRestriction(_) => REG_SHO_RESTRICTION_SIZE,
+      ItchMessage::MarketParticipantPosition(_) => MARKET_PARTICIPANT_POSITION_SIZE,
+      ItchMessage::MwcbDeclineLevel(_) => MWCB_DECLINE_LEVEL_SIZE,
+      ItchMessage::MwcbStatus(_) => MWCB_STATUS_SIZE,
+      ItchMessage::IpoQuotingPeriodUpdate(_) => IPO_QUOTING_PERIOD_UPDATE_SIZE,
+      ItchMessage::LuldAuctionCollar(_) => LULD_AUCTION_COLLAR_SIZE,
+      ItchMessage::OperationalHalt(_) => OPERATIONAL_HALT_SIZE,
+      ItchMessage::AddOrder(_) => ADD_ORDER_SIZE,
+      ItchMessage::AddOrderWithMpid(_) => ADD_ORDER_WITH_MPID_SIZE,
+      ItchMessage::OrderExecuted(_) => ORDER_EXECUTED_SIZE,
+      ItchMessage::OrderExecutedWithPrice(_) => ORDER_EXECUTED_WITH_PRICE_SIZE,
+      ItchMessage::OrderCancel(_) => ORDER_CANCEL_SIZE,
+      ItchMessage::OrderDelete(_) => ORDER_DELETE_SIZE,
+      ItchMessage::OrderReplace(_) => ORDER_REPLACE_SIZE,
+      ItchMessage::Trade(_) => TRADE_SIZE,
+      ItchMessage::CrossTrade(_) => CROSS_TRADE_SIZE,
+      ItchMessage::BrokenTrade(_) => BROKEN_TRADE_SIZE,
+      ItchMessage::NetOrderImbalanceIndicator(_) => NET_ORDER_IMBALANCE_INDICATOR_SIZE,
+      ItchMessage::RetailPriceImprovementIndicator(_) => RETAIL_PRICE_IMPROVEMENT_INDICATOR_SIZE,
+      ItchMessage::EndOfSnapshot(_) => END_OF_SNAPSHOT_SIZE,
+    }
+  }
+  pub fn name(&self) -> &'static str {
+    match *self {
+      ItchMessage::SystemEvent(_) => SystemEvent::NAME,
+      ItchMessage::StockDirectory(_) => StockDirectory::NAME,
+      ItchMessage::StockTradingAction(_) => StockTradingAction::NAME,
+      ItchMessage::RegShoRestriction(_) => RegShoRestriction::NAME,
+      ItchMessage::MarketParticipantPosition(_) => MarketParticipantPosition::NAME,
+      ItchMessage::MwcbDeclineLevel(_) => MwcbDeclineLevel::NAME,
+      ItchMessage::MwcbStatus(_) => MwcbStatus::NAME,
+      ItchMessage::IpoQuotingPeriodUpdate(_) => IpoQuotingPeriodUpdate::NAME,
+      ItchMessage::LuldAuctionCollar(_) => LuldAuctionCollar::NAME,
+      ItchMessage::OperationalHalt(_) => OperationalHalt::NAME,
+      ItchMessage::AddOrder(_) => AddOrder::NAME,
+      ItchMessage::AddOrderWithMpid(_) => AddOrderWithMpid::NAME,
+      ItchMessage::OrderExecuted(_) => OrderExecuted::NAME,
+      ItchMessage::OrderExecutedWithPrice(_) => OrderExecutedWithPrice::NAME,
+      ItchMessage::OrderCancel(_) => OrderCancel::NAME,
+      ItchMessage::OrderDelete(_) => OrderDelete::NAME,
+      ItchMessage::OrderReplace(_) => OrderReplace::NAME,
+      ItchMessage::Trade(_) => Trade::NAME,
+      ItchMessage::CrossTrade(_) => CrossTrade::NAME,
+      ItchMessage::BrokenTrade(_) => BrokenTrade::NAME,
+      ItchMessage::NetOrderImbalanceIndicator(_) => NetOrderImbalanceIndicator::NAME,
+      ItchMessage::RetailPriceImprovementIndicator(_) => RetailPriceImprovementIndicator::NAME,
+      ItchMessage::EndOfSnapshot(_) => EndOfSnapshot::NAME,
+    }
+  }
+  pub fn field_names(&self) -> &'static [&'static str] {
+    match *self {
+      ItchMessage::SystemEvent(_) => SystemEvent::FIELDS,
+      ItchMessage::StockDirectory(_) => StockDirectory::FIELDS,
+      ItchMessage::StockTradingAction(_) => StockTradingAction::FIELDS,
+      ItchMessage::RegShoRestriction(_) => RegShoRestriction::FIELDS,
+      ItchMessage::MarketParticipantPosition(_) => MarketParticipantPosition::FIELDS,
+      ItchMessage::MwcbDeclineLevel(_) => MwcbDeclineLevel::FIELDS,
+      ItchMessage::MwcbStatus(_) => MwcbStatus::FIELDS,
+      ItchMessage::IpoQuotingPeriodUpdate(_) => IpoQuotingPeriodUpdate::FIELDS,
+      ItchMessage::LuldAuctionCollar(_) => LuldAuctionCollar::FIELDS,
+      ItchMessage::OperationalHalt(_) => OperationalHalt::FIELDS,
+      ItchMessage::AddOrder(_) => AddOrder::FIELDS,
+      ItchMessage::AddOrderWithMpid(_) => AddOrderWithMpid::FIELDS,
+      ItchMessage::OrderExecuted(_) => OrderExecuted::FIELDS,
+      ItchMessage::OrderExecutedWithPrice(_) => OrderExecutedWithPrice::FIELDS,
+      ItchMessage::OrderCancel(_) => OrderCancel::FIELDS,
+      ItchMessage::OrderDelete(_) => OrderDelete::FIELDS,
+      ItchMessage::OrderReplace(_) => OrderReplace::FIELDS,
+      ItchMessage::Trade(_) => Trade::FIELDS,
+      ItchMessage::CrossTrade(_) => CrossTrade::FIELDS,
+      ItchMessage::BrokenTrade(_) => BrokenTrade::FIELDS,
+      ItchMessage::NetOrderImbalanceIndicator(_) => NetOrderImbalanceIndicator::FIELDS,
+      ItchMessage::RetailPriceImprovementIndicator(_) => RetailPriceImprovementIndicator::FIELDS,
+      ItchMessage::EndOfSnapshot(_) => EndOfSnapshot::FIELDS,
+    }
+  }
+  pub fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V) {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::StockDirectory(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::StockTradingAction(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::RegShoRestriction(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::MarketParticipantPosition(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::MwcbDeclineLevel(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::MwcbStatus(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::LuldAuctionCollar(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OperationalHalt(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::AddOrder(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::AddOrderWithMpid(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OrderExecuted(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OrderCancel(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OrderDelete(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::OrderReplace(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::Trade(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::CrossTrade(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::BrokenTrade(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => msg.visit_fields(visitor),
+      ItchMessage::EndOfSnapshot(ref msg) => msg.visit_fields(visitor),
+    }
+  }
+  pub fn message_type(&self) -> u8 {
+    match *self {
+      ItchMessage::SystemEvent(_) => SystemEvent::TYPE,
+      ItchMessage::StockDirectory(_) => StockDirectory::TYPE,
+      ItchMessage::StockTradingAction(_) => StockTradingAction::TYPE,
+      ItchMessage::RegShoRestriction(_) => RegShoRestriction::TYPE,
+      ItchMessage::MarketParticipantPosition(_) => MarketParticipantPosition::TYPE,
+      ItchMessage::MwcbDeclineLevel(_) => MwcbDeclineLevel::TYPE,
+      ItchMessage::MwcbStatus(_) => MwcbStatus::TYPE,
+      ItchMessage::IpoQuotingPeriodUpdate(_) => IpoQuotingPeriodUpdate::TYPE,
+      ItchMessage::LuldAuctionCollar(_) => LuldAuctionCollar::TYPE,
+      ItchMessage::OperationalHalt(_) => OperationalHalt::TYPE,
+      ItchMessage::AddOrder(_) => AddOrder::TYPE,
+      ItchMessage::AddOrderWithMpid(_) => AddOrderWithMpid::TYPE,
+      ItchMessage::OrderExecuted(_) => OrderExecuted::TYPE,
+      ItchMessage::OrderExecutedWithPrice(_) => OrderExecutedWithPrice::TYPE,
+      ItchMessage::OrderCancel(_) => OrderCancel::TYPE,
+      ItchMessage::OrderDelete(_) => OrderDelete::TYPE,
+      ItchMessage::OrderReplace(_) => OrderReplace::TYPE,
+      ItchMessage::Trade(_) => Trade::TYPE,
+      ItchMessage::CrossTrade(_) => CrossTrade::TYPE,
+      ItchMessage::BrokenTrade(_) => BrokenTrade::TYPE,
+      ItchMessage::NetOrderImbalanceIndicator(_) => NetOrderImbalanceIndicator::TYPE,
+      ItchMessage::RetailPriceImprovementIndicator(_) => RetailPriceImprovementIndicator::TYPE,
+      ItchMessage::EndOfSnapshot(_) => EndOfSnapshot::TYPE,
+    }
+  }
+  pub fn stock_locate(&self) -> Option<u16> {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => Some(msg.stock_locate),
+      ItchMessage::StockDirectory(ref msg) => Some(msg.stock_locate),
+      ItchMessage::StockTradingAction(ref msg) => Some(msg.stock_locate),
+      ItchMessage::RegShoRestriction(ref msg) => Some(msg.stock_locate),
+      ItchMessage::MarketParticipantPosition(ref msg) => Some(msg.stock_locate),
+      ItchMessage::MwcbDeclineLevel(ref msg) => Some(msg.stock_locate),
+      ItchMessage::MwcbStatus(ref msg) => Some(msg.stock_locate),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => Some(msg.stock_locate),
+      ItchMessage::LuldAuctionCollar(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OperationalHalt(ref msg) => Some(msg.stock_locate),
+      ItchMessage::AddOrder(ref msg) => Some(msg.stock_locate),
+      ItchMessage::AddOrderWithMpid(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OrderExecuted(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OrderCancel(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OrderDelete(ref msg) => Some(msg.stock_locate),
+      ItchMessage::OrderReplace(ref msg) => Some(msg.stock_locate),
+      ItchMessage::Trade(ref msg) => Some(msg.stock_locate),
+      ItchMessage::CrossTrade(ref msg) => Some(msg.stock_locate),
+      ItchMessage::BrokenTrade(ref msg) => Some(msg.stock_locate),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => Some(msg.stock_locate),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => Some(msg.stock_locate),
+      ItchMessage::EndOfSnapshot(_) => None,
+    }
+  }
+  pub fn tracking_number(&self) -> Option<u16> {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => Some(msg.tracking_number),
+      ItchMessage::StockDirectory(ref msg) => Some(msg.tracking_number),
+      ItchMessage::StockTradingAction(ref msg) => Some(msg.tracking_number),
+      ItchMessage::RegShoRestriction(ref msg) => Some(msg.tracking_number),
+      ItchMessage::MarketParticipantPosition(ref msg) => Some(msg.tracking_number),
+      ItchMessage::MwcbDeclineLevel(ref msg) => Some(msg.tracking_number),
+      ItchMessage::MwcbStatus(ref msg) => Some(msg.tracking_number),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => Some(msg.tracking_number),
+      ItchMessage::LuldAuctionCollar(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OperationalHalt(ref msg) => Some(msg.tracking_number),
+      ItchMessage::AddOrder(ref msg) => Some(msg.tracking_number),
+      ItchMessage::AddOrderWithMpid(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OrderExecuted(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OrderCancel(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OrderDelete(ref msg) => Some(msg.tracking_number),
+      ItchMessage::OrderReplace(ref msg) => Some(msg.tracking_number),
+      ItchMessage::Trade(ref msg) => Some(msg.tracking_number),
+      ItchMessage::CrossTrade(ref msg) => Some(msg.tracking_number),
+      ItchMessage::BrokenTrade(ref msg) => Some(msg.tracking_number),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => Some(msg.tracking_number),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => Some(msg.tracking_number),
+      ItchMessage::EndOfSnapshot(_) => None,
+    }
+  }
+  pub fn timestamp(&self) -> Option<ItchTimestamp> {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => Some(msg.timestamp),
+      ItchMessage::StockDirectory(ref msg) => Some(msg.timestamp),
+      ItchMessage::StockTradingAction(ref msg) => Some(msg.timestamp),
+      ItchMessage::RegShoRestriction(ref msg) => Some(msg.timestamp),
+      ItchMessage::MarketParticipantPosition(ref msg) => Some(msg.timestamp),
+      ItchMessage::MwcbDeclineLevel(ref msg) => Some(msg.timestamp),
+      ItchMessage::MwcbStatus(ref msg) => Some(msg.timestamp),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => Some(msg.timestamp),
+      ItchMessage::LuldAuctionCollar(ref msg) => Some(msg.timestamp),
+      ItchMessage::OperationalHalt(ref msg) => Some(msg.timestamp),
+      ItchMessage::AddOrder(ref msg) => Some(msg.timestamp),
+      ItchMessage::AddOrderWithMpid(ref msg) => Some(msg.timestamp),
+      ItchMessage::OrderExecuted(ref msg) => Some(msg.timestamp),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => Some(msg.timestamp),
+      ItchMessage::OrderCancel(ref msg) => Some(msg.timestamp),
+      ItchMessage::OrderDelete(ref msg) => Some(msg.timestamp),
+      ItchMessage::OrderReplace(ref msg) => Some(msg.timestamp),
+      ItchMessage::Trade(ref msg) => Some(msg.timestamp),
+      ItchMessage::CrossTrade(ref msg) => Some(msg.timestamp),
+      ItchMessage::BrokenTrade(ref msg) => Some(msg.timestamp),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => Some(msg.timestamp),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => Some(msg.timestamp),
+      ItchMessage::EndOfSnapshot(_) => None,
+    }
+  }
+  pub fn dispatch<T: ItchHandler>(&self, ctx: &MessageContext, handler: &mut T) {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => handler.on_system_event(msg, ctx),
+      ItchMessage::StockDirectory(ref msg) => handler.on_stock_directory(msg, ctx),
+      ItchMessage::StockTradingAction(ref msg) => handler.on_stock_trading_action(msg, ctx),
+      ItchMessage::RegShoRestriction(ref msg) => handler.on_reg_sho_restriction(msg, ctx),
+      ItchMessage::MarketParticipantPosition(ref msg) => handler.on_market_participant_position(msg, ctx),
+      ItchMessage::MwcbDeclineLevel(ref msg) => handler.on_mwcb_decline_level(msg, ctx),
+      ItchMessage::MwcbStatus(ref msg) => handler.on_mwcb_status(msg, ctx),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => handler.on_ipo_quoting_period_update(msg, ctx),
+      ItchMessage::LuldAuctionCollar(ref msg) => handler.on_luld_auction_collar(msg, ctx),
+      ItchMessage::OperationalHalt(ref msg) => handler.on_operational_halt(msg, ctx),
+      ItchMessage::AddOrder(ref msg) => handler.on_add_order(msg, ctx),
+      ItchMessage::AddOrderWithMpid(ref msg) => handler.on_add_order_with_mpid(msg, ctx),
+      ItchMessage::OrderExecuted(ref msg) => handler.on_order_executed(msg, ctx),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => handler.on_order_executed_with_price(msg, ctx),
+      ItchMessage::OrderCancel(ref msg) => handler.on_order_cancel(msg, ctx),
+      ItchMessage::OrderDelete(ref msg) => handler.on_order_delete(msg, ctx),
+      ItchMessage::OrderReplace(ref msg) => handler.on_order_replace(msg, ctx),
+      ItchMessage::Trade(ref msg) => handler.on_trade(msg, ctx),
+      ItchMessage::CrossTrade(ref msg) => handler.on_cross_trade(msg, ctx),
+      ItchMessage::BrokenTrade(ref msg) => handler.on_broken_trade(msg, ctx),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => handler.on_net_order_imbalance_indicator(msg, ctx),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => handler.on_retail_price_improvement_indicator(msg, ctx),
+      ItchMessage::EndOfSnapshot(ref msg) => handler.on_end_of_snapshot(msg, ctx),
+    }
+  }
+}
+
+#[macro_export]
+macro_rules! for_each_itch_message {
+  ($m:ident $($args:tt)*) => {
+    $m! {
+      [$($args)*]
+      (on_system_event, $crate::itch::SystemEvent),
+      (on_stock_directory, $crate::itch::StockDirectory),
+      (on_stock_trading_action, $crate::itch::StockTradingAction),
+      (on_reg_sho_restriction, $crate::itch::RegShoRestriction),
+      (on_market_participant_position, $crate::itch::MarketParticipantPosition),
+      (on_mwcb_decline_level, $crate::itch::MwcbDeclineLevel),
+      (on_mwcb_status, $crate::itch::MwcbStatus),
+      (on_ipo_quoting_period_update, $crate::itch::IpoQuotingPeriodUpdate),
+      (on_luld_auction_collar, $crate::itch::LuldAuctionCollar),
+      (on_operational_halt, $crate::itch::OperationalHalt),
+      (on_add_order, $crate::itch::AddOrder),
+      (on_add_order_with_mpid, $crate::itch::AddOrderWithMpid),
+      (on_order_executed, $crate::itch::OrderExecuted),
+      (on_order_executed_with_price, $crate::itch::OrderExecutedWithPrice),
+      (on_order_cancel, $crate::itch::OrderCancel),
+      (on_order_delete, $crate::itch::OrderDelete),
+      (on_order_replace, $crate::itch::OrderReplace),
+      (on_trade, $crate::itch::Trade),
+      (on_cross_trade, $crate::itch::CrossTrade),
+      (on_broken_trade, $crate::itch::BrokenTrade),
+      (on_net_order_imbalance_indicator, $crate::itch::NetOrderImbalanceIndicator),
+      (on_retail_price_improvement_indicator, $crate::itch::RetailPriceImprovementIndicator),
+      (on_end_of_snapshot, $crate::itch::EndOfSnapshot),
+    }
+  };
+}
+
+impl fmt::Display for ItchMessage {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      ItchMessage::SystemEvent(ref msg) => msg.fmt(f),
+      ItchMessage::StockDirectory(ref msg) => msg.fmt(f),
+      ItchMessage::StockTradingAction(ref msg) => msg.fmt(f),
+      ItchMessage::RegShoRestriction(ref msg) => msg.fmt(f),
+      ItchMessage::MarketParticipantPosition(ref msg) => msg.fmt(f),
+      ItchMessage::MwcbDeclineLevel(ref msg) => msg.fmt(f),
+      ItchMessage::MwcbStatus(ref msg) => msg.fmt(f),
+      ItchMessage::IpoQuotingPeriodUpdate(ref msg) => msg.fmt(f),
+      ItchMessage::LuldAuctionCollar(ref msg) => msg.fmt(f),
+      ItchMessage::OperationalHalt(ref msg) => msg.fmt(f),
+      ItchMessage::AddOrder(ref msg) => msg.fmt(f),
+      ItchMessage::AddOrderWithMpid(ref msg) => msg.fmt(f),
+      ItchMessage::OrderExecuted(ref msg) => msg.fmt(f),
+      ItchMessage::OrderExecutedWithPrice(ref msg) => msg.fmt(f),
+      ItchMessage::OrderCancel(ref msg) => msg.fmt(f),
+      ItchMessage::OrderDelete(ref msg) => msg.fmt(f),
+      ItchMessage::OrderReplace(ref msg) => msg.fmt(f),
+      ItchMessage::Trade(ref msg) => msg.fmt(f),
+      ItchMessage::CrossTrade(ref msg) => msg.fmt(f),
+      ItchMessage::BrokenTrade(ref msg) => msg.fmt(f),
+      ItchMessage::NetOrderImbalanceIndicator(ref msg) => msg.fmt(f),
+      ItchMessage::RetailPriceImprovementIndicator(ref msg) => msg.fmt(f),
+      ItchMessage::EndOfSnapshot(ref msg) => msg.fmt(f),
+    }
+  }
 }
 
-pub struct Dumper {}
-impl ItchHandler for Dumper {
-  fn on_system_event(&mut self, msg: SystemEvent) { println!("{}", msg); }
-  fn on_stock_directory(&mut self, msg: StockDirectory) { println!("{}", msg); }
-  fn on_stock_trading_action(&mut self, msg: StockTradingAction) { println!("{}", msg); }
-  fn on_reg_sho_restriction(&mut self, msg: RegShoRestriction) { println!("{}", msg); }
-  fn on_market_participant_position(&mut self, msg: MarketParticipantPosition) { println!("{}", msg); }
-  fn on_mwcb_decline_level(&mut self, msg: MwcbDeclineLevel) { println!("{}", msg); }
-  fn on_mwcb_status(&mut self, msg: MwcbStatus) { println!("{}", msg); }
-  fn on_ipo_quoting_period_update(&mut self, msg: IpoQuotingPeriodUpdate) { println!("{}", msg); }
-  fn on_luld_auction_collar(&mut self, msg: LuldAuctionCollar) { println!("{}", msg); }
-  fn on_operational_halt(&mut self, msg: OperationalHalt) { println!("{}", msg); }
-  fn on_add_order(&mut self, msg: AddOrder) { println!("{}", msg); }
-  fn on_add_order_with_mpid(&mut self, msg: AddOrderWithMpid) { println!("{}", msg); }
-  fn on_order_executed(&mut self, msg: OrderExecuted) { println!("{}", msg); }
-  fn on_order_executed_with_price(&mut self, msg: OrderExecutedWithPrice) { println!("{}", msg); }
-  fn on_order_cancel(&mut self, msg: OrderCancel) { println!("{}", msg); }
-  fn on_order_delete(&mut self, msg: OrderDelete) { println!("{}", msg); }
-  fn on_order_replace(&mut self, msg: OrderReplace) { println!("{}", msg); }
-  fn on_trade(&mut self, msg: Trade) { println!("{}", msg); }
-  fn on_cross_trade(&mut self, msg: CrossTrade) { println!("{}", msg); }
-  fn on_broken_trade(&mut self, msg: BrokenTrade) { println!("{}", msg); }
-  fn on_net_order_imbalance_indicator(&mut self, msg: NetOrderImbalanceIndicator) { println!("{}", msg); }
-  fn on_retail_price_improvement_indicator(&mut self, msg: RetailPriceImprovementIndicator) { println!("{}", msg); }
-  fn on_end_of_snapshot(&mut self, msg: EndOfSnapshot) { println!("{}", msg); }
+impl From<SystemEvent> for ItchMessage {
+  fn from(msg: SystemEvent) -> ItchMessage { ItchMessage::SystemEvent(msg) }
+}
+impl From<StockDirectory> for ItchMessage {
+  fn from(msg: StockDirectory) -> ItchMessage { ItchMessage::StockDirectory(msg) }
+}
+impl From<StockTradingAction> for ItchMessage {
+  fn from(msg: StockTradingAction) -> ItchMessage { ItchMessage::StockTradingAction(msg) }
+}
+impl From<RegShoRestriction> for ItchMessage {
+  fn from(msg: RegShoRestriction) -> ItchMessage { ItchMessage::RegShoRestriction(msg) }
+}
+impl From<MarketParticipantPosition> for ItchMessage {
+  fn from(msg: MarketParticipantPosition) -> ItchMessage { ItchMessage::MarketParticipantPosition(msg) }
+}
+impl From<MwcbDeclineLevel> for ItchMessage {
+  fn from(msg: MwcbDeclineLevel) -> ItchMessage { ItchMessage::MwcbDeclineLevel(msg) }
+}
+impl From<MwcbStatus> for ItchMessage {
+  fn from(msg: MwcbStatus) -> ItchMessage { ItchMessage::MwcbStatus(msg) }
+}
+impl From<IpoQuotingPeriodUpdate> for ItchMessage {
+  fn from(msg: IpoQuotingPeriodUpdate) -> ItchMessage { ItchMessage::IpoQuotingPeriodUpdate(msg) }
 }
+impl From<LuldAuctionCollar> for ItchMessage {
+  fn from(msg: LuldAuctionCollar) -> ItchMessage { ItchMessage::LuldAuctionCollar(msg) }
+}
+impl From<OperationalHalt> for ItchMessage {
+  fn from(msg: OperationalHalt) -> ItchMessage { ItchMessage::OperationalHalt(msg) }
+}
+impl From<AddOrder> for ItchMessage {
+  fn from(msg: AddOrder) -> ItchMessage { ItchMessage::AddOrder(msg) }
+}
+impl From<AddOrderWithMpid> for ItchMessage {
+  fn from(msg: AddOrderWithMpid) -> ItchMessage { ItchMessage::AddOrderWithMpid(msg) }
+}
+impl From<OrderExecuted> for ItchMessage {
+  fn from(msg: OrderExecuted) -> ItchMessage { ItchMessage::OrderExecuted(msg) }
+}
+impl From<OrderExecutedWithPrice> for ItchMessage {
+  fn from(msg: OrderExecutedWithPrice) -> ItchMessage { ItchMessage::OrderExecutedWithPrice(msg) }
+}
+impl From<OrderCancel> for ItchMessage {
+  fn from(msg: OrderCancel) -> ItchMessage { ItchMessage::OrderCancel(msg) }
+}
+impl From<OrderDelete> for ItchMessage {
+  fn from(msg: OrderDelete) -> ItchMessage { ItchMessage::OrderDelete(msg) }
+}
+impl From<OrderReplace> for ItchMessage {
+  fn from(msg: OrderReplace) -> ItchMessage { ItchMessage::OrderReplace(msg) }
+}
+impl From<Trade> for ItchMessage {
+  fn from(msg: Trade) -> ItchMessage { ItchMessage::Trade(msg) }
+}
+impl From<CrossTrade> for ItchMessage {
+  fn from(msg: CrossTrade) -> ItchMessage { ItchMessage::CrossTrade(msg) }
+}
+impl From<BrokenTrade> for ItchMessage {
+  fn from(msg: BrokenTrade) -> ItchMessage { ItchMessage::BrokenTrade(msg) }
+}
+impl From<NetOrderImbalanceIndicator> for ItchMessage {
+  fn from(msg: NetOrderImbalanceIndicator) -> ItchMessage { ItchMessage::NetOrderImbalanceIndicator(msg) }
+}
+impl From<RetailPriceImprovementIndicator> for ItchMessage {
+  fn from(msg: RetailPriceImprovementIndicator) -> ItchMessage { ItchMessage::RetailPriceImprovementIndicator(msg) }
+}
+impl From<EndOfSnapshot> for ItchMessage {
+  fn from(msg: EndOfSnapshot) -> ItchMessage { ItchMessage::EndOfSnapshot(msg) }
+}
+
@@ -1,9 +1,49 @@
 use std::net::{Ipv4Addr, SocketAddrV4};
 
+pub mod alpha;
+#[cfg(feature = "tokio")]
+pub mod async_stream;
+pub mod context;
+pub mod dumper;
+pub mod encode;
+pub mod error;
+pub mod handlers;
+pub mod hexdump;
+pub mod history;
 pub mod itch;
+pub mod message;
 pub mod moldudp;
+pub mod price;
+pub mod receiver;
+pub mod rerequest;
+pub mod retransmit;
+pub mod sequence;
+pub mod session;
+pub mod stats;
+pub mod stream;
+pub mod timestamp;
 
+pub use crate::alpha::*;
+#[cfg(feature = "tokio")]
+pub use crate::async_stream::*;
+pub use crate::context::*;
+pub use crate::dumper::*;
+pub use crate::encode::*;
+pub use crate::error::*;
+pub use crate::handlers::*;
+pub use crate::hexdump::*;
+pub use crate::history::*;
 pub use crate::itch::*;
+pub use crate::message::*;
+pub use crate::price::*;
+pub use crate::receiver::*;
+pub use crate::rerequest::*;
+pub use crate::retransmit::*;
+pub use crate::sequence::*;
+pub use crate::session::*;
+pub use crate::stats::*;
+pub use crate::stream::*;
+pub use crate::timestamp::*;
 
 pub fn itch_multicast_addr() -> SocketAddrV4 {
   let port = 26477;
@@ -33,19 +73,260 @@ fn process_packet() {
     E\x00\x10\x00\x00\x16\xce\xd3\xc5\xb0\xc8\x10\x20\x30\x40\x50\x60\x70\x7F\x00\x00\x00\x0A\x00\x00\x00\x00\x00\x00\x10\x00\
     \x00\x13\
     D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F";
-  let mut handler = itch::Dumper{};
-  let reader = moldudp::MoldReader::new(&msgbuf[..]);
+  let mut msgs = Vec::new();
+  let header = moldudp::decode_packet(&msgbuf[..], &mut msgs).unwrap();
+  assert_eq!(header.session, "SUCKONTHIS");
+  assert_eq!((header.sequence_number, header.message_count), (2, 3));
+  let types = msgs.iter().map(|msg| msg.message_type()).collect::<Vec<_>>();
+  assert_eq!(types, b"AED");
+  let mut handler = Dumper::stdout();
+  for msg in &msgs {
+    msg.dispatch(&MessageContext::default(), &mut handler);
+  }
+
+  // the vector is cleared and reused, and a packet claiming more messages than it holds is rejected
+  let mut short = msgbuf.to_vec();
+  short[19] = 4;
+  let err = moldudp::decode_packet(&short, &mut msgs).unwrap_err();
+  assert!(matches!(err, StreamError::Mold(MoldError::MissingMessages{declared: 4, found: 3, offset}) if offset == short.len()));
+  assert_eq!(msgs.len(), 3);
+  short[19] = 2;
+  assert!(matches!(moldudp::decode_packet(&short, &mut msgs), Err(StreamError::Mold(MoldError::TrailingBytes{..}))));
+}
+
+#[test]
+fn message_context() {
+  #[derive(Default)]
+  struct Seen { seqnos: Vec<u64>, shares: u32 }
+  impl itch::ItchHandler for Seen {
+    fn on_add_order(&mut self, msg: &itch::AddOrder, ctx: &MessageContext) {
+      assert_eq!(ctx.session, "SUCKONTHIS");
+      assert_eq!(ctx.feed, Some(Feed::B));
+      assert!(ctx.received.is_some());
+      self.seqnos.push(ctx.seqno);
+      self.shares += msg.shares;
+    }
+    fn on_order_delete(&mut self, _msg: &itch::OrderDelete, ctx: &MessageContext) {
+      self.seqnos.push(ctx.seqno);
+    }
+  }
+  let msgbuf = b"SUCKONTHIS\x00\x00\x00\x00\x00\x00\x00\x02\
+    \x00\x02\
+    \x00\x24\
+    A\x00\x10\x00\x00\x16\xce\xd3\xc5\xb0\xc8\x10\x20\x30\x40\x50\x60\x70\x7FB\x00\x00\x00\x64AMZN    \x01\xe8\x6e\x48\
+    \x00\x13\
+    D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F";
+  let received = std::time::SystemTime::now();
+  let mut handler = Seen::default();
+  let reader = moldudp::MoldReader::new(&msgbuf[..]).unwrap();
   for (i, msg) in reader.iter().enumerate() {
-    println!("processing session:{} seqno:{} msgno:{}", std::str::from_utf8(reader.session()).unwrap(), reader.seqno(), i);
-    itch::crack_message(msg, &mut handler);
+    let msg = msg.unwrap();
+    let ctx = MessageContext::for_packet(&reader, i).with_received(received).with_feed(Feed::B);
+    itch::crack_message_with_context(msg, &ctx, &mut handler).unwrap();
+  }
+  assert_eq!(handler.seqnos, vec![2, 3]);
+  assert_eq!(handler.shares, 100);
+
+  let mut last = *msgbuf;
+  last[10..18].copy_from_slice(&u64::MAX.to_be_bytes());
+  let reader = moldudp::MoldReader::new(&last[..]).unwrap();
+  assert_eq!(MessageContext::for_packet(&reader, 1).seqno, 0);
+}
+
+#[test]
+fn crack_message_errors() {
+  #[derive(Default)]
+  struct Problems { unknown: Vec<(u8, usize)>, errors: Vec<DecodeError> }
+  impl itch::ItchHandler for Problems {
+    fn on_unknown(&mut self, type_byte: u8, raw: &[u8], _ctx: &MessageContext) {
+      self.unknown.push((type_byte, raw.len()));
+    }
+    fn on_decode_error(&mut self, err: DecodeError, _raw: &[u8], _ctx: &MessageContext) {
+      self.errors.push(err);
+    }
+  }
+  let mut handler = (Problems::default(), Dumper::stdout());
+  assert_eq!(itch::crack_message(b"", &mut handler), Err(DecodeError::Empty));
+  // unknown types are reported to the handler but aren't an error
+  assert_eq!(itch::crack_message(b"~\x00\x01", &mut handler), Ok(()));
+  // order delete cut off in the middle of the order reference number
+  let err = itch::crack_message(b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20", &mut handler).unwrap_err();
+  assert_eq!(err, DecodeError::Truncated{type_byte: b'D', expected: itch::ORDER_DELETE_SIZE, actual: 13, offset: 0});
+  assert_eq!(handler.0.unknown, vec![(b'~', 3)]);
+  assert_eq!(handler.0.errors, vec![DecodeError::Empty, err.clone()]);
+  assert_eq!(err.at_offset(20).offset(), 20);
+}
+
+#[test]
+fn crack_message_length_checks() {
+  #[derive(Default)]
+  struct Trailing { deletes: usize, trailing: Vec<u8>, errors: usize }
+  impl itch::ItchHandler for Trailing {
+    fn on_order_delete(&mut self, _msg: &itch::OrderDelete, _ctx: &MessageContext) {
+      self.deletes += 1;
+    }
+    fn on_trailing_bytes(&mut self, _type_byte: u8, trailing: &[u8], _ctx: &MessageContext) {
+      self.trailing.extend_from_slice(trailing);
+    }
+    fn on_decode_error(&mut self, _err: DecodeError, _raw: &[u8], _ctx: &MessageContext) {
+      self.errors += 1;
+    }
+  }
+  // order delete with two bytes from some future revision of the spec
+  let msg = b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F\xAB\xCD";
+  let ctx = MessageContext::default();
+  let mut handler = Trailing::default();
+  itch::crack_message_checked(msg, &ctx, LengthCheck::Lenient, &mut handler).unwrap();
+  assert_eq!(itch::crack_message_checked(msg, &ctx, LengthCheck::Strict, &mut handler),
+    Err(DecodeError::LengthMismatch{type_byte: b'D', expected: itch::ORDER_DELETE_SIZE, actual: itch::ORDER_DELETE_SIZE + 2, offset: 0}));
+  itch::crack_message_checked(&msg[..itch::ORDER_DELETE_SIZE], &ctx, LengthCheck::Strict, &mut handler).unwrap();
+  assert_eq!((handler.deletes, handler.errors), (2, 1));
+  assert_eq!(handler.trailing, vec![0xAB, 0xCD]);
+  assert_eq!(itch::OrderDeleteView::new(msg).unwrap().trailing(), &[0xAB, 0xCD]);
+}
+
+#[test]
+fn crack_views() {
+  struct Orders { refs: Vec<u64>, shares: u32 }
+  impl itch::ItchViewHandler for Orders {
+    fn on_add_order(&mut self, msg: itch::AddOrderView<'_>) {
+      assert_eq!(msg.stock(), "AMZN");
+      assert_eq!(msg.to_message().price, msg.price());
+      self.refs.push(msg.order_reference_number());
+      self.shares += msg.shares();
+    }
+    fn on_order_delete(&mut self, msg: itch::OrderDeleteView<'_>) {
+      self.refs.push(msg.order_reference_number());
+    }
+  }
+  let msgbuf = b"A\x00\x10\x00\x00\x16\xce\xd3\xc5\xb0\xc8\x10\x20\x30\x40\x50\x60\x70\x7FB\x00\x00\x00\x64AMZN    \x01\xe8\x6e\x48";
+  let mut handler = Orders{refs: vec![], shares: 0};
+  itch::crack_message_view(&msgbuf[..], &mut handler).unwrap();
+  itch::crack_message_view(b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F", &mut handler).unwrap();
+  assert_eq!(handler.refs, vec![0x102030405060707f, 0x102030405060707f]);
+  assert_eq!(handler.shares, 100);
+  assert!(itch::AddOrderView::new(&msgbuf[..20]).is_err());
+}
+
+#[test]
+fn message_values() {
+  let add = b"A\x00\x10\x00\x00\x16\xce\xd3\xc5\xb0\xc8\x10\x20\x30\x40\x50\x60\x70\x7FB\x00\x00\x00\x64AMZN    \x01\xe8\x6e\x48";
+  let delete = b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F";
+  let mut msgs = vec![];
+  for bytes in [&add[..], &delete[..]] {
+    let (msg, len) = itch::ItchMessage::decode(bytes).unwrap();
+    assert_eq!(len, bytes.len());
+    assert_eq!(msg.to_bytes(), bytes);
+    msgs.push(msg);
+  }
+  msgs.sort_by_key(|msg| msg.timestamp());
+  assert_eq!(msgs[0].message_type(), itch::OrderDelete::TYPE);
+  assert_eq!(msgs[0].stock_locate(), Some(16));
+  match msgs[1] {
+    itch::ItchMessage::AddOrder(ref add_order) => assert_eq!(add_order.shares, 100),
+    ref other => panic!("expected an add order, got {}", other),
+  }
+  assert_eq!(itch::ItchMessage::decode(b"Z"), Err(DecodeError::UnknownType{type_byte: b'Z', offset: 0}));
+}
+
+#[test]
+fn enum_codes() {
+  use std::convert::TryFrom;
+  assert_eq!(itch::eBuySellIndicator::try_from(b'B'), Ok(itch::eBuySellIndicator::Buy_Order));
+  assert_eq!(itch::eBuySellIndicator::try_from(b'X'), Err(UnknownCode{enum_name: "eBuySellIndicator", code: b'X'}));
+  assert_eq!(u8::from(itch::eCrossType::Nasdaq_Closing_Cross), b'C');
+  assert_eq!(itch::eTradingState::Halted.to_string(), "'H' (Halted)");
+  // unknown codes survive a decode/encode round trip
+  let bytes = itch::ItchMessage::AddOrder(itch::AddOrder{
+    message_type: itch::AddOrder::TYPE,
+    buy_sell_indicator: itch::eBuySellIndicator::from_code(b'?'),
+    ..Default::default()
+  }).to_bytes();
+  assert_eq!(bytes[19], b'?');
+  let add_order = itch::AddOrder::decode(&bytes).unwrap();
+  assert_eq!(add_order.buy_sell_indicator, itch::eBuySellIndicator::Unknown(b'?'));
+  assert!(!add_order.buy_sell_indicator.is_known());
+  assert_eq!(add_order.buy_sell_indicator.to_string(), "Unknown");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_json_messages() {
+  let add = itch::ItchMessage::AddOrder(itch::AddOrder {
+    message_type: itch::AddOrder::TYPE,
+    stock_locate: 7,
+    timestamp: "09:30:00.000000001".parse().unwrap(),
+    buy_sell_indicator: itch::eBuySellIndicator::Buy_Order,
+    shares: 100,
+    stock: Symbol::new("AMZN").unwrap(),
+    price: "3100.25".parse().unwrap(),
+    ..Default::default()
+  });
+  let json = serde_json::to_value(add).unwrap();
+  assert_eq!(json["AddOrder"]["stock"], "AMZN");
+  assert_eq!(json["AddOrder"]["buy_sell_indicator"], "Buy_Order");
+  assert_eq!(json["AddOrder"]["price"], "3100.2500");
+  assert_eq!(json["AddOrder"]["timestamp"], "09:30:00.000000001");
+  assert_eq!(serde_json::from_value::<itch::ItchMessage>(json).unwrap(), add);
+  // codes outside the schema round trip as the bare character
+  let cross = itch::eCrossType::from_code(b'X');
+  assert_eq!(serde_json::to_string(&cross).unwrap(), "\"X\"");
+  assert_eq!(serde_json::from_str::<itch::eCrossType>("\"X\"").unwrap(), cross);
+  let header = itch::PacketHeader{session: Session::from_bytes(*b"0000012345"), sequence_number: 42, message_count: 3};
+  let json = serde_json::to_string(&header).unwrap();
+  assert_eq!(serde_json::from_str::<itch::PacketHeader>(&json).unwrap(), header);
+}
+
+#[test]
+fn encode_anywhere() {
+  let delete = itch::OrderDelete {
+    stock_locate: 16,
+    timestamp: ItchTimestamp(0xff),
+    order_reference_number: 0x102030405060707f,
+    ..Default::default()
+  };
+  let expected = b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F";
+  assert_eq!(delete.to_vec(), &expected[..]);
+
+  let mut out = std::io::BufWriter::new(Vec::new());
+  itch::write_order_delete(&mut out, 16, 0, ItchTimestamp(0xff), 0x102030405060707f).unwrap();
+  assert_eq!(out.into_inner().unwrap(), &expected[..]);
+
+  let mut small = [0u8; 10];
+  assert!(delete.encode_to_slice(&mut small).is_err());
+  let mut big = [0u8; 64];
+  assert_eq!(delete.encode_to_slice(&mut big).unwrap(), itch::ORDER_DELETE_SIZE);
+
+  let mut writer = moldudp::MoldWriter::new("SESSION001", 1);
+  writer.add_encoded(&delete).add_encoded(&delete);
+  let reader = moldudp::MoldReader::new(writer.data()).unwrap();
+  assert_eq!(reader.iter().collect::<Vec<_>>(), vec![Ok(&expected[..]), Ok(&expected[..])]);
+}
+
+#[test]
+fn generic_message_types() {
+  fn count<M: ItchMessageType>(msgs: &[&[u8]]) -> usize {
+    msgs.iter().filter(|msg| msg.first() == Some(&M::TYPE)).count()
+  }
+  fn locates<M: ItchMessageType>(msgs: &[&[u8]]) -> Vec<Option<u16>> {
+    msgs.iter().filter_map(|msg| M::decode(msg).ok()).map(|msg| msg.stock_locate()).collect()
   }
+  let delete = b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F";
+  let snapshot = b"G00000000000000012345";
+  let msgs = [&delete[..], &snapshot[..], &delete[..]];
+  assert_eq!(count::<itch::OrderDelete>(&msgs), 2);
+  assert_eq!(count::<itch::AddOrder>(&msgs), 0);
+  assert_eq!(locates::<itch::OrderDelete>(&msgs), vec![Some(16), Some(16)]);
+  assert_eq!(locates::<itch::EndOfSnapshot>(&msgs), vec![None]);
+  assert_eq!(<itch::EndOfSnapshot as ItchMessageType>::NAME, "EndOfSnapshot");
+  assert_eq!(<itch::AddOrder as ItchMessageType>::SIZE, itch::ADD_ORDER_SIZE);
 }
 
 #[test]
 fn test_write() {
-  let mut data = vec![0u8;512];
+  let mut data = [0u8;512];
   let mut cursor = std::io::Cursor::new(&mut data[..]);
-  itch::write_system_event(&mut cursor, 1234, 0, 0, itch::eSystemEvent(itch::eSystemEvent::Start_of_Messages)).unwrap();
+  itch::write_system_event(&mut cursor, 1234, 0, ItchTimestamp(0), itch::eSystemEvent::Start_of_Messages).unwrap();
   let expected = b"S\
     \x04\xd2\
     \x00\x00\
@@ -62,14 +343,16 @@ fn test_write() {
     \x00\x12\xd6\x44"
     ;
 
-  let mut add_order = itch::AddOrder::default();
-  add_order.stock_locate = 666;
-  add_order.timestamp = 65535;
-  add_order.order_reference_number = 0xbeef;
-  add_order.buy_sell_indicator = itch::eBuySellIndicator(itch::eBuySellIndicator::Sell_Order);
-  add_order.shares = 100;
-  add_order.stock = b"AMZN    ".clone();
-  add_order.price = 1234500;
+  let add_order = itch::AddOrder {
+    stock_locate: 666,
+    timestamp: ItchTimestamp(65535),
+    order_reference_number: 0xbeef,
+    buy_sell_indicator: itch::eBuySellIndicator::Sell_Order,
+    shares: 100,
+    stock: Symbol::new("AMZN").unwrap(),
+    price: "123.45".parse().unwrap(),
+    ..Default::default()
+  };
   itch::write_add_order_struct(&mut cursor, add_order).unwrap();
 
   assert_eq!(data[..12+36], expected[..12+36]);
@@ -0,0 +1,82 @@
+use crate::alpha::Symbol;
+use crate::error::DecodeError;
+use crate::price::{Price4, Price8};
+use crate::timestamp::ItchTimestamp;
+
+// Implemented by every message struct in the schema, so code can be written
+// once over all message kinds. The header fields are optional because not
+// every message carries them (EndOfSnapshot has no locate or timestamp).
+pub trait ItchMessageType: Sized {
+  const TYPE : u8;
+  const SIZE : usize;
+  const NAME : &'static str;
+  // field names in wire order, the order visit_fields reports them in
+  const FIELDS : &'static [&'static str];
+  // widest each field gets when displayed, for lining up columns
+  const FIELD_WIDTHS : &'static [usize];
+
+  fn decode(bytes: &[u8]) -> Result<Self, DecodeError>;
+  fn visit_fields<V: FieldVisitor + ?Sized>(&self, visitor: &mut V);
+
+  fn stock_locate(&self) -> Option<u16> {
+    None
+  }
+  fn tracking_number(&self) -> Option<u16> {
+    None
+  }
+  fn timestamp(&self) -> Option<ItchTimestamp> {
+    None
+  }
+  fn stock(&self) -> Option<Symbol> {
+    None
+  }
+  // the order the message adds or acts on, the new reference for OrderReplace
+  fn order_reference_number(&self) -> Option<u64> {
+    None
+  }
+}
+
+// One field of a message, typed by its schema type rather than its Rust type
+// so output code doesn't need to know every message struct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldValue<'a> {
+  Char(u8),
+  U16(u16),
+  U32(u32),
+  U64(u64),
+  Price4(Price4),
+  Price8(Price8),
+  Timestamp(ItchTimestamp),
+  // symbols and other space padded ascii, trimmed
+  Text(&'a str),
+  // fixed width character fields that aren't known to be text
+  Bytes(&'a [u8]),
+  // enum fields, name is "Unknown" for codes not in the schema
+  Code { code: u8, name: &'static str },
+}
+
+pub trait FieldVisitor {
+  fn field(&mut self, name: &'static str, value: FieldValue<'_>);
+}
+
+// What to do with a message that is longer than its type's schema size.
+// Lenient accepts it and hands the extra bytes to on_trailing_bytes, so
+// fields appended by a future spec revision don't break decoding; Strict
+// rejects it with DecodeError::LengthMismatch. Short messages are always
+// an error.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthCheck {
+  Strict,
+  #[default]
+  Lenient,
+}
+
+impl LengthCheck {
+  pub fn check(self, type_byte: u8, expected: usize, actual: usize) -> Result<(), DecodeError> {
+    match self {
+      LengthCheck::Strict if actual > expected =>
+        Err(DecodeError::LengthMismatch { type_byte, expected, actual, offset: 0 }),
+      _ => Ok(()),
+    }
+  }
+}
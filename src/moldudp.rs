@@ -1,8 +1,25 @@
 use std::iter::{Iterator, IntoIterator};
 use std::convert::TryInto;
+use crate::alpha::Session;
+use crate::encode::Encode;
+use crate::error::{MoldError, StreamError};
+use crate::itch::{ItchMessage, PacketHeader, MESSAGE_BLOCK_SIZE, PACKET_HEADER_SIZE};
 
 const MOLD_HEADER_LEN : usize = 20;
+// message count of the packet a server sends after a session's last message
+pub const END_OF_SESSION : u16 = 0xFFFF;
 
+// What a MoldUDP64 packet is, going by its message count. Heartbeats and
+// the end-of-session packet carry no messages, their sequence number is the
+// next one the server will send.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PacketKind {
+  Data,
+  Heartbeat,
+  EndOfSession,
+}
+
+#[derive(Clone, Copy, Debug)]
 pub struct MoldReader<'a> {
   data: &'a [u8],
 }
@@ -19,15 +36,36 @@ fn as_u64(bytes: &[u8]) -> u64 {
 }
 
 impl<'a> MoldReader<'a> {
-  pub fn new(data: &'a [u8]) -> Self {
-    Self{data: data}
+  // checks the header is all there, message blocks are checked as they're iterated
+  pub fn new(data: &'a [u8]) -> Result<Self, MoldError> {
+    if data.len() < MOLD_HEADER_LEN {
+      return Err(MoldError::ShortHeader { actual: data.len() });
+    }
+    Ok(Self{data})
   }
-  pub fn iter(&self) -> MoldIter {
-    let iter = MoldIter{data: self.data, bytes_eaten: 0, msg_count: 0};
-    iter
+  pub fn iter(&self) -> MoldIter<'a> {
+    MoldIter{data: self.data, bytes_eaten: MOLD_HEADER_LEN, index: 0, msg_count: self.len() as u16, done: false}
   }
+  pub fn kind(&self) -> PacketKind {
+    match self.message_count() {
+      0 => PacketKind::Heartbeat,
+      END_OF_SESSION => PacketKind::EndOfSession,
+      _ => PacketKind::Data,
+    }
+  }
+  // the header's count as sent, END_OF_SESSION included
+  pub fn message_count(&self) -> u16 {
+    self.data[19] as u16 + ((self.data[18] as u16) << 8)
+  }
+  // messages in the packet, none for heartbeats and end-of-session
   pub fn len(&self) -> usize {
-    (self.data[19] as u16 + ((self.data[18] as u16) << 8)) as usize
+    match self.kind() {
+      PacketKind::Data => self.message_count() as usize,
+      _ => 0,
+    }
+  }
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
   }
   pub fn seqno(&self) -> u64 {
     as_u64(&self.data[10..])
@@ -38,39 +76,84 @@ impl<'a> MoldReader<'a> {
 }
 
 impl<'a> IntoIterator for &'a MoldReader<'a> {
-  type Item = &'a [u8];
+  type Item = Result<&'a [u8], MoldError>;
   type IntoIter = MoldIter<'a>;
   fn into_iter(self) -> Self::IntoIter {
     self.iter()
   }
 }
 
+// Yields each message block's body. A framing problem is yielded once as an
+// error and ends the iteration.
 pub struct MoldIter<'a> {
   data: &'a [u8],
   bytes_eaten: usize,
+  index: u16,
   msg_count: u16,
+  done: bool,
 }
 
-impl<'a> Iterator for MoldIter<'a> {
-  type Item = &'a [u8];
-  fn next(&mut self) -> Option<&'a [u8]> {
-    if self.bytes_eaten == 0 {
-      self.bytes_eaten = 10 + 8 + 2;
-      self.msg_count = self.data[19] as u16 + ((self.data[18] as u16) << 8);
-    }
-    let bytes_remaining = self.data.len() - self.bytes_eaten;
-    if bytes_remaining <= 0 || self.msg_count <= 0 {
+impl<'a> MoldIter<'a> {
+  fn next_block(&mut self) -> Option<Result<&'a [u8], MoldError>> {
+    let offset = self.bytes_eaten;
+    let bytes_remaining = self.data.len() - offset;
+    if self.index == self.msg_count {
+      if bytes_remaining > 0 {
+        return Some(Err(MoldError::TrailingBytes { offset, len: bytes_remaining }));
+      }
       return None;
     }
-    let offset = self.bytes_eaten;
+    if bytes_remaining == 0 {
+      return Some(Err(MoldError::MissingMessages { declared: self.msg_count, found: self.index, offset }));
+    }
+    if bytes_remaining < 2 {
+      return Some(Err(MoldError::TruncatedBlock { index: self.index, offset, expected: 2, actual: bytes_remaining }));
+    }
     let msglen = (((self.data[offset] as u16) << 8) + self.data[offset+1] as u16) as usize;
     if bytes_remaining < msglen + 2 {
-      return None;
+      return Some(Err(MoldError::TruncatedBlock { index: self.index, offset: offset + 2, expected: msglen, actual: bytes_remaining - 2 }));
     }
-    self.msg_count -= 1;
+    self.index += 1;
     self.bytes_eaten += 2 + msglen;
-    return Some(&self.data[offset+2..offset+2+msglen]);
+    Some(Ok(&self.data[offset+2..offset+2+msglen]))
+  }
+}
+
+impl<'a> Iterator for MoldIter<'a> {
+  type Item = Result<&'a [u8], MoldError>;
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let next = self.next_block();
+    if !matches!(next, Some(Ok(_))) {
+      self.done = true;
+    }
+    next
+  }
+}
+
+// Decodes every message of a MoldUDP64 packet into out, which is cleared
+// first so its allocation can be reused packet after packet. Heartbeats and
+// end-of-session leave out empty, check the header's kind. The header's
+// message count must match the message blocks that follow, a mismatch is
+// the MoldError from MoldIter in StreamError::Mold; a message that doesn't
+// decode is a StreamError::Decode. On either out keeps the messages before
+// the problem. Error offsets are from the start of the packet.
+pub fn decode_packet(packet: &[u8], out: &mut Vec<ItchMessage>) -> Result<PacketHeader, StreamError> {
+  out.clear();
+  let reader = MoldReader::new(packet)?;
+  let header = PacketHeader::decode(packet)?;
+  out.reserve(reader.len());
+  let mut offset = PACKET_HEADER_SIZE;
+  for msg in reader.iter() {
+    let msg = msg?;
+    offset += MESSAGE_BLOCK_SIZE;
+    let (decoded, _) = ItchMessage::decode(msg).map_err(|err| err.at_offset(offset))?;
+    out.push(decoded);
+    offset += msg.len();
   }
+  Ok(header)
 }
 
 pub struct MoldWriter {
@@ -85,6 +168,22 @@ impl MoldWriter {
     ans
   }
 
+  // for a session that is already a Session, no str to check
+  pub fn with_session(session: Session, seqno: u64) -> Self {
+    let mut ans = Self{buf: [0u8; 1400], bytes_written: 0};
+    ans.buf[0..10].copy_from_slice(session.as_bytes());
+    ans.set_seqno(seqno).set_message_count(0);
+    ans
+  }
+
+  // the packet that tells receivers the session is over, seqno is one past
+  // the session's last message
+  pub fn end_of_session(session: Session, seqno: u64) -> Self {
+    let mut ans = Self::with_session(session, seqno);
+    ans.set_message_count(END_OF_SESSION);
+    ans
+  }
+
   pub fn set_session(&mut self, what: &str) -> &mut Self {
     self.buf[0..10].copy_from_slice(what.as_bytes());
     self
@@ -105,7 +204,6 @@ impl MoldWriter {
     let mut msg_count = u16::from_be_bytes(msg_count_bytes);
     msg_count += 1;
     self.set_message_count(msg_count);
-    println!("current message count: {}", msg_count);
   }
   pub fn add_message(&mut self, what: &[u8]) -> &mut Self {
     if ! self.can_fit(what.len()) {
@@ -121,7 +219,7 @@ impl MoldWriter {
     self.buf[offsets.0..offsets.1].copy_from_slice(&msg_size.to_be_bytes()[..]);
     self.buf[offsets.0+2..offsets.0+2+what.len()].copy_from_slice(what);
     self.bytes_written += what.len() + 2;
-    return self;
+    self
   }
   pub fn write_message<F : FnOnce(&mut [u8])>(&mut self, msg_size: u16, writer: F) -> &mut Self {
     if ! self.can_fit(msg_size.into()) {
@@ -132,7 +230,12 @@ impl MoldWriter {
     self.buf[offsets.0..offsets.1].copy_from_slice(&msg_size.to_be_bytes()[..]);
     writer(&mut self.buf[offsets.0+2..offsets.0+2+msg_size as usize]);
     self.bytes_written += (msg_size + 2) as usize;
-    return self;
+    self
+  }
+  pub fn add_encoded<M: Encode>(&mut self, msg: &M) -> &mut Self {
+    self.write_message(M::ENCODED_LEN as u16, |loc| {
+      msg.encode_to_slice(loc).expect("slot is sized to the message");
+    })
   }
   pub fn size_remaining(&self) -> usize {
     self.buf.len() - (self.bytes_written + MOLD_HEADER_LEN)
@@ -141,7 +244,7 @@ impl MoldWriter {
     (msg_size + 2) < self.size_remaining()
   }
   pub fn data(&self) -> &[u8] {
-    return &self.buf[..self.bytes_written+MOLD_HEADER_LEN];
+    &self.buf[..self.bytes_written+MOLD_HEADER_LEN]
   }
   pub fn reset(&mut self) {
     self.set_message_count(0);
@@ -153,9 +256,9 @@ impl MoldWriter {
 fn mold_iterate() {
   use std::str;
   let msgbuf = b"1234567890\x00\x00\x00\x00\x00\x00\x00\x01\x00\x03\x00\x01X\x00\x04ASDF\x00\x0AABCDEFGHIJ";
-  let reader = MoldReader::new(&msgbuf[..]);
+  let reader = MoldReader::new(&msgbuf[..]).unwrap();
   for (i, msg) in reader.iter().enumerate() {
-    println!("msg: session[{}] seqno[{}] msg#{}: \"{}\"", str::from_utf8(reader.session()).unwrap(), reader.seqno(), i, str::from_utf8(msg).unwrap());
+    println!("msg: session[{}] seqno[{}] msg#{}: \"{}\"", str::from_utf8(reader.session()).unwrap(), reader.seqno(), i, str::from_utf8(msg.unwrap()).unwrap());
   }
   println!("seqno was: {}", reader.seqno());
 }
@@ -179,12 +282,93 @@ fn mold_writer() {
   assert_eq!(&buf[0..10], b"1234567890");
   println!("{:?}", buf);
 
-  let reader = MoldReader::new(&buf);
+  let reader = MoldReader::new(buf).unwrap();
   assert_eq!(reader.len(), 5);
   assert_eq!(reader.seqno(), 666);
-  let expected = vec!["HELLO","GOODBYE","BOOGADEEBOO","FOOBAR","BAZQUXFOOBAR"];
+  let expected = ["HELLO","GOODBYE","BOOGADEEBOO","FOOBAR","BAZQUXFOOBAR"];
   for (i, msg) in reader.iter().enumerate() {
+    let msg = msg.unwrap();
     println!("msg: session[{}] seqno[{}] msg#{}: \"{}\"", str::from_utf8(reader.session()).unwrap(), reader.seqno() + i as u64, i, str::from_utf8(msg).unwrap());
     assert_eq!(std::str::from_utf8(msg).unwrap(), expected[i]);
   }
 }
+
+#[test]
+fn mold_packet_kinds() {
+  let heartbeat = MoldWriter::new("1234567890", 12);
+  let reader = MoldReader::new(heartbeat.data()).unwrap();
+  assert_eq!((reader.kind(), reader.len(), reader.seqno()), (PacketKind::Heartbeat, 0, 12));
+  assert_eq!(reader.iter().count(), 0);
+
+  let end = MoldWriter::end_of_session(Session::new("1234567890").unwrap(), 12);
+  let reader = MoldReader::new(end.data()).unwrap();
+  assert_eq!((reader.kind(), reader.len(), reader.message_count()), (PacketKind::EndOfSession, 0, END_OF_SESSION));
+  assert_eq!(reader.iter().count(), 0);
+  let mut msgs = vec![ItchMessage::decode(b"S\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00O").unwrap().0];
+  assert_eq!(decode_packet(end.data(), &mut msgs).unwrap().message_count, END_OF_SESSION);
+  assert!(msgs.is_empty());
+
+  let mut data = MoldWriter::new("1234567890", 12);
+  data.add_message(b"X");
+  assert_eq!(MoldReader::new(data.data()).unwrap().kind(), PacketKind::Data);
+}
+
+#[test]
+fn mold_framing_errors() {
+  let header = b"1234567890\x00\x00\x00\x00\x00\x00\x00\x01";
+  let packet = |count: &[u8], blocks: &[u8]| [&header[..], count, blocks].concat();
+  assert_eq!(MoldReader::new(&header[..]).err(), Some(MoldError::ShortHeader { actual: 18 }));
+  fn results(data: &[u8]) -> Vec<Result<&[u8], MoldError>> {
+    MoldReader::new(data).unwrap().iter().collect()
+  }
+  assert_eq!(results(&packet(b"\x00\x02", b"\x00\x01X\x00")),
+    vec![Ok(&b"X"[..]), Err(MoldError::TruncatedBlock { index: 1, offset: 23, expected: 2, actual: 1 })]);
+  assert_eq!(results(&packet(b"\x00\x01", b"\x00\x05XY")),
+    vec![Err(MoldError::TruncatedBlock { index: 0, offset: 22, expected: 5, actual: 2 })]);
+  assert_eq!(results(&packet(b"\x00\x03", b"\x00\x01X")),
+    vec![Ok(&b"X"[..]), Err(MoldError::MissingMessages { declared: 3, found: 1, offset: 23 })]);
+  assert_eq!(results(&packet(b"\x00\x01", b"\x00\x01X!!")),
+    vec![Ok(&b"X"[..]), Err(MoldError::TrailingBytes { offset: 23, len: 2 })]);
+  assert_eq!(results(&packet(b"\x00\x00", b"")), vec![]);
+}
+
+#[test]
+fn mold_fuzz_never_panics() {
+  use crate::itch::crack_message;
+  use crate::stats::ItchStats;
+  // xorshift, so failures reproduce
+  let mut state = 0x2545f4914f6cdd1du64;
+  let mut random = move || {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+  };
+  let mut valid = MoldWriter::new("SESSION001", 1);
+  valid.add_message(b"D\x00\x10\x00\x00\x00\x00\x00\x00\x00\xFF\x10\x20\x30\x40\x50\x60\x70\x7F");
+  valid.add_message(b"S\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00O");
+  let valid = valid.data().to_vec();
+  let mut stats = ItchStats::new();
+  let mut msgs = Vec::new();
+  for round in 0..20000 {
+    let mut packet = if round % 2 == 0 {
+      (0..random() % 96).map(|_| random() as u8).collect::<Vec<_>>()
+    } else {
+      let mut packet = valid.clone();
+      for _ in 0..1 + random() % 4 {
+        let at = random() as usize % packet.len();
+        packet[at] = random() as u8;
+      }
+      packet
+    };
+    packet.truncate(random() as usize % (packet.len() + 8));
+    if let Ok(reader) = MoldReader::new(&packet) {
+      let _ = (reader.len(), reader.seqno(), reader.session());
+      for msg in reader.iter().flatten() {
+        let _ = crack_message(msg, &mut stats);
+      }
+      assert!(reader.iter().count() <= reader.len() + 1);
+    }
+    let _ = decode_packet(&packet, &mut msgs);
+  }
+}
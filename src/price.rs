@@ -0,0 +1,210 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use crate::error::PriceError;
+use crate::itch::{MAX_PRICE4, MAX_PRICE8, PRICE4_SCALE, PRICE8_SCALE};
+
+// Fixed point prices as they appear on the wire: Price4 carries 4 implied
+// decimal places (most order and trade prices), Price8 carries 8 (the MWCB
+// decline levels).
+macro_rules! fixed_point_price {
+  ($name:ident, $raw:ty, $scale:expr, $max:expr, $decimals:expr) => {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct $name(pub $raw);
+
+    impl $name {
+      pub const SCALE : $raw = $scale;
+      pub const DECIMALS : usize = $decimals;
+      pub const MAX : $name = $name($max);
+      pub const ZERO : $name = $name(0);
+
+      pub fn from_raw(raw: $raw) -> Self {
+        $name(raw)
+      }
+      pub fn raw(&self) -> $raw {
+        self.0
+      }
+      // whole units plus a fraction expressed in 1/SCALE units
+      pub fn from_parts(whole: $raw, fraction: $raw) -> Result<Self, PriceError> {
+        if fraction >= Self::SCALE {
+          return Err(PriceError::TooPrecise);
+        }
+        whole.checked_mul(Self::SCALE)
+          .and_then(|raw| raw.checked_add(fraction))
+          .map($name)
+          .ok_or(PriceError::OutOfRange)
+      }
+      pub fn whole(&self) -> $raw {
+        self.0 / Self::SCALE
+      }
+      pub fn fraction(&self) -> $raw {
+        self.0 % Self::SCALE
+      }
+      // anything above the schema maximum is not a tradable price
+      pub fn is_valid(&self) -> bool {
+        self.0 <= $max
+      }
+      pub fn from_f64(value: f64) -> Result<Self, PriceError> {
+        let raw = (value * Self::SCALE as f64).round();
+        if !raw.is_finite() || raw < 0.0 || raw > <$raw>::MAX as f64 {
+          return Err(PriceError::OutOfRange);
+        }
+        Ok($name(raw as $raw))
+      }
+      pub fn to_f64(&self) -> f64 {
+        self.0 as f64 / Self::SCALE as f64
+      }
+      pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map($name)
+      }
+      pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map($name)
+      }
+      pub fn checked_mul(self, qty: $raw) -> Option<Self> {
+        self.0.checked_mul(qty).map($name)
+      }
+      pub fn saturating_add(self, rhs: Self) -> Self {
+        $name(self.0.saturating_add(rhs.0))
+      }
+      pub fn saturating_sub(self, rhs: Self) -> Self {
+        $name(self.0.saturating_sub(rhs.0))
+      }
+    }
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{:0width$}", self.whole(), self.fraction(), width = Self::DECIMALS)
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = PriceError;
+      fn from_str(s: &str) -> Result<Self, PriceError> {
+        let (whole, fraction) = match s.find('.') {
+          Some(dot) => (&s[..dot], &s[dot+1..]),
+          None => (s, ""),
+        };
+        if whole.is_empty() && fraction.is_empty() {
+          return Err(PriceError::Invalid);
+        }
+        if !whole.bytes().chain(fraction.bytes()).all(|c| c.is_ascii_digit()) {
+          return Err(PriceError::Invalid);
+        }
+        if fraction.len() > Self::DECIMALS {
+          return Err(PriceError::TooPrecise);
+        }
+        let whole = if whole.is_empty() { 0 } else { whole.parse::<$raw>().map_err(|_| PriceError::OutOfRange)? };
+        // right pad the fraction with zeros out to the full scale
+        let fraction = fraction.bytes().chain(std::iter::repeat(b'0')).take(Self::DECIMALS)
+          .fold(0, |acc: $raw, c| acc * 10 + (c - b'0') as $raw);
+        Self::from_parts(whole, fraction)
+      }
+    }
+
+    impl TryFrom<f64> for $name {
+      type Error = PriceError;
+      fn try_from(value: f64) -> Result<Self, PriceError> {
+        Self::from_f64(value)
+      }
+    }
+
+    impl From<$name> for f64 {
+      fn from(price: $name) -> f64 {
+        price.to_f64()
+      }
+    }
+
+    // decimal strings for human readable formats, the raw wire integer otherwise
+    #[cfg(feature = "serde")]
+    impl serde::Serialize for $name {
+      fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+          serializer.collect_str(self)
+        } else {
+          serde::Serialize::serialize(&self.0, serializer)
+        }
+      }
+    }
+
+    #[cfg(feature = "serde")]
+    impl<'de> serde::Deserialize<'de> for $name {
+      fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+          let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+          s.parse().map_err(serde::de::Error::custom)
+        } else {
+          <$raw as serde::Deserialize>::deserialize(deserializer).map($name)
+        }
+      }
+    }
+
+    // the operators saturate rather than panic or wrap, so a sum past the
+    // raw maximum stays there and fails is_valid; checked_add/checked_sub
+    // tell you it happened
+    impl Add for $name {
+      type Output = $name;
+      fn add(self, rhs: $name) -> $name {
+        self.saturating_add(rhs)
+      }
+    }
+
+    impl Sub for $name {
+      type Output = $name;
+      fn sub(self, rhs: $name) -> $name {
+        self.saturating_sub(rhs)
+      }
+    }
+  };
+}
+
+fixed_point_price!(Price4, u32, PRICE4_SCALE, MAX_PRICE4, 4);
+fixed_point_price!(Price8, u64, PRICE8_SCALE, MAX_PRICE8, 8);
+
+impl From<Price4> for Price8 {
+  fn from(price: Price4) -> Price8 {
+    Price8(price.0 as u64 * (PRICE8_SCALE / PRICE4_SCALE as u64))
+  }
+}
+
+impl Price8 {
+  // exact conversion only, fails if the price uses the extra 4 decimals
+  pub fn to_price4(&self) -> Result<Price4, PriceError> {
+    let factor = PRICE8_SCALE / PRICE4_SCALE as u64;
+    if self.0 % factor != 0 {
+      return Err(PriceError::TooPrecise);
+    }
+    u32::try_from(self.0 / factor).map(Price4).map_err(|_| PriceError::OutOfRange)
+  }
+}
+
+#[test]
+fn price_display_and_parse() {
+  assert_eq!(Price4(1234500).to_string(), "123.4500");
+  assert_eq!(Price8(123450000).to_string(), "1.23450000");
+  assert_eq!("123.45".parse::<Price4>(), Ok(Price4(1234500)));
+  assert_eq!("7".parse::<Price4>(), Ok(Price4(70000)));
+  assert_eq!(".5".parse::<Price8>(), Ok(Price8(50000000)));
+  assert_eq!("1.23456".parse::<Price4>(), Err(PriceError::TooPrecise));
+  assert_eq!("1.2x".parse::<Price4>(), Err(PriceError::Invalid));
+  assert_eq!("-1".parse::<Price4>(), Err(PriceError::Invalid));
+  assert_eq!("500000".parse::<Price4>(), Err(PriceError::OutOfRange));
+}
+
+#[test]
+fn price_conversions() {
+  assert_eq!(Price4::from_f64(10.25), Ok(Price4(102500)));
+  assert_eq!(Price4(102500).to_f64(), 10.25);
+  assert_eq!(Price4::from_f64(-1.0), Err(PriceError::OutOfRange));
+  assert_eq!(Price8::from(Price4(102500)), Price8(1025000000));
+  assert_eq!(Price8(1025000000).to_price4(), Ok(Price4(102500)));
+  assert_eq!(Price8(1025000001).to_price4(), Err(PriceError::TooPrecise));
+  assert!(Price4(MAX_PRICE4).is_valid());
+  assert!(!Price4(MAX_PRICE4 + 1).is_valid());
+  assert!(Price8(MAX_PRICE8).is_valid());
+  assert!(!Price8(MAX_PRICE8 + 1).is_valid());
+  assert_eq!(Price4(10000) + Price4(2500), Price4(12500));
+  assert_eq!(Price4(u32::MAX) + Price4(1), Price4(u32::MAX));
+  assert_eq!(Price4(10000) - Price4(20000), Price4::ZERO);
+  assert_eq!(Price4(10000).checked_sub(Price4(20000)), None);
+  assert!(Price4(10000) < Price4(10001));
+}
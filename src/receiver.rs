@@ -0,0 +1,131 @@
+use std::io;
+use std::net::{ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+use crate::alpha::Session;
+use crate::error::StreamError;
+use crate::moldudp::{MoldReader, PacketKind};
+
+// What MoldReceiver::recv saw. Data borrows the receiver's buffer, so it has
+// to be dealt with before the next call.
+#[derive(Debug)]
+pub enum MoldEvent<'a> {
+  Data(MoldReader<'a>),
+  Heartbeat { session: Session, seqno: u64 },
+  // the server is done with the session, nothing more will come on it
+  EndOfSession { session: Session, seqno: u64 },
+  // no packet of any kind for the heartbeat timeout, repeated every timeout
+  // for as long as the line stays quiet
+  Timeout { silent_for: Duration },
+}
+
+// Blocking MoldUDP64 receiver that tells the feed handler about heartbeats,
+// a quiet line and the end of the session alongside the data packets.
+// Timeouts are off until with_heartbeat_timeout, and stop being reported
+// once the session has ended since silence is expected then.
+pub struct MoldReceiver {
+  socket: UdpSocket,
+  buf: Box<[u8]>,
+  heartbeat_timeout: Option<Duration>,
+  last_packet: Instant,
+  deadline: Option<Instant>,
+  ended: bool,
+}
+
+impl MoldReceiver {
+  pub fn new(socket: UdpSocket) -> Self {
+    MoldReceiver{socket, buf: vec![0u8; 64 * 1024].into_boxed_slice(), heartbeat_timeout: None, last_packet: Instant::now(), deadline: None, ended: false}
+  }
+  pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+    UdpSocket::bind(addr).map(Self::new)
+  }
+  // NASDAQ sends heartbeats about once a second, a few seconds is typical
+  pub fn with_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+    self.heartbeat_timeout = Some(timeout);
+    self.deadline = Some(self.last_packet + timeout);
+    self
+  }
+  pub fn socket(&self) -> &UdpSocket {
+    &self.socket
+  }
+  pub fn into_inner(self) -> UdpSocket {
+    self.socket
+  }
+  pub fn session_ended(&self) -> bool {
+    self.ended
+  }
+  pub fn last_packet(&self) -> Instant {
+    self.last_packet
+  }
+
+  // Waits for the next packet, or until the heartbeat timeout runs out. A
+  // packet too short for its header is a StreamError::Mold; its message
+  // blocks are checked as the Data reader is iterated.
+  pub fn recv(&mut self) -> Result<MoldEvent<'_>, StreamError> {
+    let len = loop {
+      let wait = match self.deadline {
+        Some(deadline) if !self.ended => {
+          let now = Instant::now();
+          if now >= deadline {
+            self.deadline = self.heartbeat_timeout.map(|timeout| now + timeout);
+            return Ok(MoldEvent::Timeout { silent_for: now - self.last_packet });
+          }
+          Some(deadline - now)
+        },
+        _ => None,
+      };
+      self.socket.set_read_timeout(wait)?;
+      match self.socket.recv(&mut self.buf) {
+        Ok(len) => break len,
+        Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => continue,
+        Err(err) => return Err(err.into()),
+      }
+    };
+    self.last_packet = Instant::now();
+    self.deadline = self.heartbeat_timeout.map(|timeout| self.last_packet + timeout);
+    let reader = MoldReader::new(&self.buf[..len])?;
+    let session = Session::from_bytes(reader.session().try_into().unwrap());
+    Ok(match reader.kind() {
+      PacketKind::Data => MoldEvent::Data(reader),
+      PacketKind::Heartbeat => MoldEvent::Heartbeat { session, seqno: reader.seqno() },
+      PacketKind::EndOfSession => {
+        self.ended = true;
+        MoldEvent::EndOfSession { session, seqno: reader.seqno() }
+      },
+    })
+  }
+}
+
+#[test]
+fn receiver_events() {
+  use crate::error::MoldError;
+  use crate::moldudp::MoldWriter;
+  let mut receiver = MoldReceiver::bind("127.0.0.1:0").unwrap().with_heartbeat_timeout(Duration::from_millis(20));
+  let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+  sender.connect(receiver.socket().local_addr().unwrap()).unwrap();
+
+  assert!(matches!(receiver.recv().unwrap(), MoldEvent::Timeout { silent_for } if silent_for >= Duration::from_millis(20)));
+  match receiver.recv().unwrap() {
+    MoldEvent::Timeout { silent_for } => assert!(silent_for >= Duration::from_millis(40)),
+    other => panic!("expected a second timeout, got {:?}", other),
+  }
+
+  let mut data = MoldWriter::new("SESSION042", 7);
+  data.add_message(b"X").add_message(b"YZ");
+  sender.send(data.data()).unwrap();
+  sender.send(MoldWriter::new("SESSION042", 9).data()).unwrap();
+  sender.send(b"short").unwrap();
+  sender.send(MoldWriter::end_of_session(Session::new("SESSION042").unwrap(), 9).data()).unwrap();
+
+  match receiver.recv().unwrap() {
+    MoldEvent::Data(reader) => {
+      assert_eq!(reader.seqno(), 7);
+      assert_eq!(reader.iter().collect::<Vec<_>>(), vec![Ok(&b"X"[..]), Ok(&b"YZ"[..])]);
+    },
+    other => panic!("expected data, got {:?}", other),
+  }
+  assert!(matches!(receiver.recv().unwrap(), MoldEvent::Heartbeat { session, seqno: 9 } if session == "SESSION042"));
+  assert!(matches!(receiver.recv(), Err(StreamError::Mold(MoldError::ShortHeader { actual: 5 }))));
+  assert!(!receiver.session_ended());
+  assert!(matches!(receiver.recv().unwrap(), MoldEvent::EndOfSession { seqno: 9, .. }));
+  assert!(receiver.session_ended());
+}
@@ -0,0 +1,332 @@
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+use crate::alpha::Session;
+use crate::encode::Encode;
+use crate::error::StreamError;
+use crate::itch::RequestPacket;
+use crate::moldudp::{MoldReader, END_OF_SESSION};
+use crate::sequence::{Recovery, RecoveryStrategy, SeqGap, SequenceTracker};
+
+// Part of a gap: a request once it has been sent, otherwise the rest of the
+// gap that hasn't been asked for yet. origin is where the gap started.
+#[derive(Clone, Copy, Debug)]
+struct Outstanding {
+  session: Session,
+  gap: SeqGap,
+  origin: u64,
+  sent: Option<Instant>,
+  attempts: u32,
+}
+
+// Asks a MoldUDP64 rerequest server for the messages a SequenceTracker
+// found missing. As the tracker's recovery strategy it waits on every gap,
+// and SequenceTracker::recover sends requests for it and merges what comes
+// back. Requests of at most max_messages_per_request messages are cut from
+// the front of a gap as they are sent, with no more than max_in_flight of
+// one gap out at a time, so a huge gap from a bad sequence number costs no
+// more than a small one. A request that isn't answered within the timeout
+// is sent again, up to max_retries times, after which the tracker abandons
+// what is left of it. A partial answer shrinks the request, the rest goes
+// again on timeout.
+pub struct Rerequester {
+  socket: UdpSocket,
+  endpoint: SocketAddr,
+  timeout: Duration,
+  max_retries: u32,
+  max_messages: u16,
+  max_in_flight: usize,
+  outstanding: Vec<Outstanding>,
+  abandoned: Vec<SeqGap>,
+  requests_sent: u64,
+  buf: Box<[u8]>,
+}
+
+impl Rerequester {
+  // answers come back on socket, which is made non-blocking
+  pub fn new(socket: UdpSocket, endpoint: SocketAddr) -> io::Result<Self> {
+    socket.set_nonblocking(true)?;
+    Ok(Rerequester{socket, endpoint, timeout: Duration::from_secs(1), max_retries: 3, max_messages: END_OF_SESSION - 1,
+      max_in_flight: 4, outstanding: Vec::new(), abandoned: Vec::new(), requests_sent: 0, buf: vec![0u8; 64 * 1024].into_boxed_slice()})
+  }
+  pub fn bind<A: ToSocketAddrs>(local: A, endpoint: SocketAddr) -> io::Result<Self> {
+    Self::new(UdpSocket::bind(local)?, endpoint)
+  }
+  pub fn with_timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = timeout;
+    self
+  }
+  pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+    self.max_retries = max_retries;
+    self
+  }
+  // servers often cap a request well below what the count field can hold
+  pub fn with_max_messages_per_request(mut self, max_messages: u16) -> Self {
+    self.max_messages = max_messages.clamp(1, END_OF_SESSION - 1);
+    self
+  }
+  // requests out at once for any one gap
+  pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+    self.max_in_flight = max_in_flight.max(1);
+    self
+  }
+  pub fn socket(&self) -> &UdpSocket {
+    &self.socket
+  }
+  pub fn endpoint(&self) -> SocketAddr {
+    self.endpoint
+  }
+  pub fn requests_sent(&self) -> u64 {
+    self.requests_sent
+  }
+  // what is still being asked for: each request sent, then the rest of
+  // each gap not asked for yet
+  pub fn outstanding(&self) -> impl Iterator<Item = SeqGap> + '_ {
+    self.outstanding.iter().map(|req| req.gap)
+  }
+  // when the next request is due to be sent, for sizing a poll timeout
+  pub fn next_deadline(&self) -> Option<Instant> {
+    self.outstanding.iter().filter_map(|req| match req.sent {
+      Some(sent) => Some(sent + self.timeout),
+      None if self.in_flight(req.origin) < self.max_in_flight => Some(Instant::now()),
+      None => None,
+    }).min()
+  }
+
+  fn in_flight(&self, origin: u64) -> usize {
+    self.outstanding.iter().filter(|req| req.origin == origin && req.sent.is_some()).count()
+  }
+
+  pub fn send_due(&mut self) -> io::Result<()> {
+    self.send_due_at(Instant::now())
+  }
+  // Sends every request that has timed out by now, and new ones from gaps
+  // with room for more in flight. Requests out of retries are dropped and
+  // returned by take_abandoned.
+  pub fn send_due_at(&mut self, now: Instant) -> io::Result<()> {
+    let mut i = 0;
+    while i < self.outstanding.len() {
+      let req = self.outstanding[i];
+      match req.sent {
+        Some(sent) if now < sent + self.timeout => {
+          i += 1;
+          continue;
+        },
+        Some(_) if req.attempts > self.max_retries => {
+          self.abandoned.push(self.outstanding.remove(i).gap);
+          continue;
+        },
+        Some(_) => {},
+        None if self.in_flight(req.origin) >= self.max_in_flight => {
+          i += 1;
+          continue;
+        },
+        // cut the next request off the front of what is left
+        None => {
+          let count = req.gap.count.min(self.max_messages as u64);
+          if count < req.gap.count {
+            self.outstanding[i].gap = SeqGap::new(req.gap.first_missing + count, req.gap.count - count);
+            self.outstanding.insert(i, Outstanding{gap: SeqGap::new(req.gap.first_missing, count), ..req});
+          }
+        },
+      }
+      let req = self.outstanding[i];
+      let packet = RequestPacket{session: req.session, sequence_number: req.gap.first_missing, requested_message_count: req.gap.count as u16};
+      self.socket.send_to(&packet.to_vec(), self.endpoint)?;
+      self.requests_sent += 1;
+      self.outstanding[i].sent = Some(now);
+      self.outstanding[i].attempts += 1;
+      i += 1;
+    }
+    Ok(())
+  }
+  pub fn take_abandoned(&mut self) -> Vec<SeqGap> {
+    std::mem::take(&mut self.abandoned)
+  }
+  // Reads one answer off the socket into buf without waiting, None if there
+  // isn't one. Answers from anywhere but the endpoint are dropped.
+  pub fn recv_retransmission(&self, buf: &mut [u8]) -> io::Result<Option<usize>> {
+    loop {
+      match self.socket.recv_from(buf) {
+        Ok((len, from)) if from == self.endpoint => return Ok(Some(len)),
+        Ok(_) => continue,
+        Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(None),
+        Err(err) => return Err(err),
+      }
+    }
+  }
+}
+
+impl RecoveryStrategy for Rerequester {
+  fn on_gap(&mut self, session: Session, gap: SeqGap) -> Recovery {
+    self.outstanding.push(Outstanding{session, gap, origin: gap.first_missing, sent: None, attempts: 0});
+    Recovery::Wait
+  }
+  fn on_filled(&mut self, _session: Session, filled: SeqGap) {
+    let mut kept = Vec::with_capacity(self.outstanding.len() + 1);
+    for req in self.outstanding.drain(..) {
+      let start = req.gap.first_missing.max(filled.first_missing);
+      let end = req.gap.end().min(filled.end());
+      if start >= end {
+        kept.push(req);
+        continue;
+      }
+      if req.gap.first_missing < start {
+        kept.push(Outstanding{gap: SeqGap::new(req.gap.first_missing, start - req.gap.first_missing), ..req});
+      }
+      if end < req.gap.end() {
+        kept.push(Outstanding{gap: SeqGap::new(end, req.gap.end() - end), ..req});
+      }
+    }
+    self.outstanding = kept;
+  }
+  fn on_session_switch(&mut self, _from: Session, _to: Session) {
+    self.outstanding.clear();
+  }
+}
+
+impl SequenceTracker<Rerequester> {
+  // Sends due requests, then tracks every retransmission waiting on the
+  // rerequest socket, handing each recovered message to on_message in the
+  // order it arrived. Gaps out of retries are abandoned. Returns how many
+  // messages were recovered. Call it whenever the live feed is idle or the
+  // rerequester's next deadline passes.
+  pub fn recover<F: FnMut(u64, &[u8])>(&mut self, on_message: F) -> Result<usize, StreamError> {
+    self.recover_at(Instant::now(), on_message)
+  }
+  // recover with timeouts judged as of now
+  pub fn recover_at<F: FnMut(u64, &[u8])>(&mut self, now: Instant, mut on_message: F) -> Result<usize, StreamError> {
+    self.strategy_mut().send_due_at(now)?;
+    for gap in self.strategy_mut().take_abandoned() {
+      self.abandon(gap);
+    }
+    let mut buf = std::mem::take(&mut self.strategy_mut().buf);
+    let mut recovered = 0;
+    let result = loop {
+      let len = match self.strategy().recv_retransmission(&mut buf) {
+        Ok(Some(len)) => len,
+        Ok(None) => break Ok(recovered),
+        Err(err) => break Err(err.into()),
+      };
+      let reader = match MoldReader::new(&buf[..len]) {
+        Ok(reader) => reader,
+        Err(err) => break Err(err.into()),
+      };
+      for (seqno, msg) in self.track(&reader).new_messages() {
+        on_message(seqno, msg);
+        recovered += 1;
+      }
+    };
+    self.strategy_mut().buf = buf;
+    result
+  }
+}
+
+// blocks until an answer is waiting on socket, so tests don't sleep for one
+#[cfg(test)]
+pub(crate) fn wait_readable(socket: &UdpSocket) {
+  socket.set_nonblocking(false).unwrap();
+  socket.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+  socket.peek_from(&mut [0u8; 1]).unwrap();
+  socket.set_nonblocking(true).unwrap();
+}
+
+#[test]
+fn rerequest_loopback() {
+  use crate::moldudp::MoldWriter;
+  let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+  server.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+  let rerequester = Rerequester::bind("127.0.0.1:0", server.local_addr().unwrap()).unwrap()
+    .with_timeout(Duration::from_secs(1))
+    .with_max_retries(1)
+    .with_max_messages_per_request(4);
+  let client = rerequester.socket().local_addr().unwrap();
+  let session = Session::new("SESSION001").unwrap();
+  let start = Instant::now();
+  let after = |secs| start + Duration::from_secs(secs);
+  let mut tracker = SequenceTracker::with_strategy(rerequester).expecting(session, 1);
+  let packet = |seqno: u64, count: u64| {
+    let mut writer = MoldWriter::new("SESSION001", seqno);
+    for n in seqno..seqno + count {
+      writer.add_message(&n.to_be_bytes());
+    }
+    writer
+  };
+  let serve = |request: &RequestPacket, count: u64| {
+    server.send_to(packet(request.sequence_number, count).data(), client).unwrap();
+  };
+  let requests = || {
+    let mut buf = [0u8; 64];
+    let (len, from) = server.recv_from(&mut buf).unwrap();
+    assert_eq!(from, client);
+    RequestPacket::decode(&buf[..len]).unwrap()
+  };
+  let recover_until = |tracker: &mut SequenceTracker<Rerequester>, want: usize| {
+    let mut got = Vec::new();
+    while got.len() < want {
+      wait_readable(tracker.strategy().socket());
+      tracker.recover_at(start, |seqno, msg| got.push((seqno, u64::from_be_bytes(msg.try_into().unwrap())))).unwrap();
+    }
+    got
+  };
+
+  let live = packet(1, 2);
+  assert_eq!(tracker.track(&MoldReader::new(live.data()).unwrap()).new_count(), 2);
+  let live = packet(11, 1);
+  assert_eq!(tracker.track(&MoldReader::new(live.data()).unwrap()).gap, Some(SeqGap::new(3, 8)));
+  assert_eq!(tracker.strategy().outstanding().collect::<Vec<_>>(), vec![SeqGap::new(3, 8)]);
+
+  tracker.recover_at(start, |_, _| panic!("nothing to recover yet")).unwrap();
+  assert_eq!(tracker.strategy().outstanding().collect::<Vec<_>>(), vec![SeqGap::new(3, 4), SeqGap::new(7, 4)]);
+  let first = requests();
+  assert_eq!((first.session, first.sequence_number, first.requested_message_count), (session, 3, 4));
+  let second = requests();
+  assert_eq!((second.sequence_number, second.requested_message_count), (7, 4));
+
+  // all of the first request, the first half of the second
+  serve(&first, 4);
+  serve(&second, 2);
+  let got = recover_until(&mut tracker, 6);
+  assert_eq!(got, (3..9).map(|n| (n, n)).collect::<Vec<_>>());
+  assert_eq!(tracker.strategy().outstanding().collect::<Vec<_>>(), vec![SeqGap::new(9, 2)]);
+
+  // a duplicate answer adds nothing
+  serve(&first, 4);
+  wait_readable(tracker.strategy().socket());
+  tracker.recover_at(start, |_, _| panic!("duplicates aren't recovered")).unwrap();
+
+  // the rest is asked for again after the timeout, then given up on
+  tracker.recover_at(after(1), |_, _| {}).unwrap();
+  let retry = requests();
+  assert_eq!((retry.sequence_number, retry.requested_message_count), (9, 2));
+  tracker.recover_at(after(2), |_, _| {}).unwrap();
+  assert_eq!(tracker.strategy().outstanding().count(), 0);
+  assert!(tracker.missing().is_empty());
+  assert_eq!((tracker.lost(), tracker.duplicates(), tracker.strategy().requests_sent()), (2, 4, 3));
+}
+
+#[test]
+fn rerequest_huge_gap() {
+  use crate::moldudp::MoldWriter;
+  let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+  let rerequester = Rerequester::bind("127.0.0.1:0", server.local_addr().unwrap()).unwrap()
+    .with_timeout(Duration::from_secs(1))
+    .with_max_messages_per_request(100)
+    .with_max_in_flight(2);
+  let session = Session::new("SESSION001").unwrap();
+  let mut tracker = SequenceTracker::with_strategy(rerequester).expecting(session, 1);
+  // a corrupt seqno near the top opens a gap of almost every seqno there is
+  let mut live = MoldWriter::new("SESSION001", u64::MAX - 1);
+  live.add_message(b"x");
+  assert_eq!(tracker.track(&MoldReader::new(live.data()).unwrap()).gap, Some(SeqGap::new(1, u64::MAX - 2)));
+  assert_eq!(tracker.strategy().outstanding().count(), 1);
+
+  let start = Instant::now();
+  for secs in 0..3 {
+    tracker.recover_at(start + Duration::from_secs(secs), |_, _| {}).unwrap();
+  }
+  // the two requests in flight are sent three times, never more of them
+  assert_eq!(tracker.strategy().outstanding().collect::<Vec<_>>(),
+    vec![SeqGap::new(1, 100), SeqGap::new(101, 100), SeqGap::new(201, u64::MAX - 202)]);
+  assert_eq!(tracker.strategy().requests_sent(), 6);
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+use crate::alpha::Session;
+use crate::history::MessageHistory;
+use crate::itch::{RequestPacket, REQUEST_PACKET_SIZE};
+use crate::moldudp::MoldWriter;
+
+// What the server did with a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequestOutcome {
+  // answered with count messages from first, which can be fewer than asked
+  // for when they don't all fit in a packet or haven't all been sent
+  Answered { client: SocketAddr, first: u64, count: u64 },
+  // the first message asked for doesn't fit in a packet on its own, so
+  // nothing was sent
+  TooLarge { client: SocketAddr, request: RequestPacket },
+  // the rest are dropped without an answer
+  RateLimited { client: SocketAddr },
+  // first asked for isn't in the history, it's either aged out or not sent yet
+  OutOfWindow { client: SocketAddr, request: RequestPacket },
+  WrongSession { client: SocketAddr, request: RequestPacket },
+  // not a request packet, or one asking for no messages
+  Malformed { client: SocketAddr, len: usize },
+}
+
+// token bucket
+#[derive(Clone, Copy, Debug)]
+struct ClientRate {
+  tokens: f64,
+  last: Instant,
+}
+
+// The server side of MoldUDP64 rerequests: answers each RequestPacket with
+// one packet holding as many of the requested messages, from the first one
+// asked for, as fit within the maximum packet size. Following the spec,
+// requests it can't satisfy get no answer at all, leaving the client to
+// retry or give up: another session, a first message that has aged out of
+// the history, one that hasn't been sent yet, or one too big for a packet. Each client can be held
+// to a rate of requests, with a burst allowance.
+pub struct RetransmissionServer<H> {
+  socket: UdpSocket,
+  session: Session,
+  history: H,
+  max_packet_size: usize,
+  rate_limit: Option<(f64, f64)>,
+  clients: HashMap<SocketAddr, ClientRate>,
+  msg: Vec<u8>,
+  answered: u64,
+  messages_sent: u64,
+  unanswered: u64,
+}
+
+impl<H: MessageHistory> RetransmissionServer<H> {
+  pub fn new(socket: UdpSocket, session: Session, history: H) -> Self {
+    RetransmissionServer{socket, session, history, max_packet_size: 1400, rate_limit: None, clients: HashMap::new(),
+      msg: Vec::new(), answered: 0, messages_sent: 0, unanswered: 0}
+  }
+  // whole packet, header included; MoldWriter can't go past 1400
+  pub fn with_max_packet_size(mut self, max_packet_size: usize) -> Self {
+    self.max_packet_size = max_packet_size;
+    self
+  }
+  pub fn with_rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+    self.rate_limit = Some((requests_per_second, burst.max(1) as f64));
+    self
+  }
+  pub fn socket(&self) -> &UdpSocket {
+    &self.socket
+  }
+  pub fn session(&self) -> Session {
+    self.session
+  }
+  pub fn history(&self) -> &H {
+    &self.history
+  }
+  pub fn history_mut(&mut self) -> &mut H {
+    &mut self.history
+  }
+  // adds the next message of the session to the history
+  pub fn publish(&mut self, msg: &[u8]) -> io::Result<u64> {
+    self.history.append(msg)
+  }
+  pub fn answered(&self) -> u64 {
+    self.answered
+  }
+  pub fn messages_sent(&self) -> u64 {
+    self.messages_sent
+  }
+  pub fn unanswered(&self) -> u64 {
+    self.unanswered
+  }
+
+  // Waits for one request, as long as the socket's read timeout allows,
+  // and deals with it.
+  pub fn handle_request(&mut self) -> io::Result<RequestOutcome> {
+    let mut buf = [0u8; 64];
+    let (len, client) = self.socket.recv_from(&mut buf)?;
+    let outcome = self.answer(&buf[..len], client)?;
+    match outcome {
+      RequestOutcome::Answered { count, .. } => {
+        self.answered += 1;
+        self.messages_sent += count;
+      },
+      _ => self.unanswered += 1,
+    }
+    Ok(outcome)
+  }
+
+  fn answer(&mut self, packet: &[u8], client: SocketAddr) -> io::Result<RequestOutcome> {
+    if !self.allow(client) {
+      return Ok(RequestOutcome::RateLimited { client });
+    }
+    let request = match RequestPacket::decode(packet) {
+      Ok(request) if packet.len() == REQUEST_PACKET_SIZE && request.requested_message_count > 0 => request,
+      _ => return Ok(RequestOutcome::Malformed { client, len: packet.len() }),
+    };
+    if request.session != self.session {
+      return Ok(RequestOutcome::WrongSession { client, request });
+    }
+    let window = self.history.window();
+    let first = request.sequence_number;
+    if !window.contains(&first) {
+      return Ok(RequestOutcome::OutOfWindow { client, request });
+    }
+    let end = window.end.min(first.saturating_add(request.requested_message_count as u64));
+    let mut writer = MoldWriter::with_session(self.session, first);
+    let mut seqno = first;
+    while seqno < end && self.history.read(seqno, &mut self.msg)? {
+      if writer.data().len() + 2 + self.msg.len() > self.max_packet_size || !writer.can_fit(self.msg.len()) {
+        break;
+      }
+      writer.add_message(&self.msg);
+      seqno += 1;
+    }
+    if seqno == first {
+      return Ok(RequestOutcome::TooLarge { client, request });
+    }
+    self.socket.send_to(writer.data(), client)?;
+    Ok(RequestOutcome::Answered { client, first, count: seqno - first })
+  }
+
+  fn allow(&mut self, client: SocketAddr) -> bool {
+    let Some((per_second, burst)) = self.rate_limit else {
+      return true;
+    };
+    let now = Instant::now();
+    let rate = self.clients.entry(client).or_insert(ClientRate{tokens: burst, last: now});
+    let refill = now.duration_since(rate.last).as_secs_f64() * per_second;
+    rate.tokens = (rate.tokens + refill).min(burst);
+    rate.last = now;
+    if rate.tokens < 1.0 {
+      return false;
+    }
+    rate.tokens -= 1.0;
+    true
+  }
+}
+
+impl<H> RetransmissionServer<H> {
+  // forget clients that haven't asked for anything in a while, so a long
+  // run doesn't keep one entry per client port ever seen
+  pub fn forget_idle_clients(&mut self, idle: Duration) {
+    let now = Instant::now();
+    self.clients.retain(|_, rate| now.duration_since(rate.last) < idle);
+  }
+}
+
+#[test]
+fn retransmission_end_to_end() {
+  use crate::encode::Encode;
+  use crate::history::MemoryHistory;
+  use crate::moldudp::MoldReader;
+  use crate::rerequest::{wait_readable, Rerequester};
+  use crate::sequence::{SeqGap, SequenceTracker};
+  let session = Session::new("SESSION007").unwrap();
+  let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+  socket.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+  let mut server = RetransmissionServer::new(socket, session, MemoryHistory::new(100))
+    .with_max_packet_size(20 + 32 * 42)
+    .with_rate_limit(1.0, 4);
+  let msg = |n: u64| [n.to_be_bytes(); 5].concat();
+  for n in 1..=150 {
+    assert_eq!(server.publish(&msg(n)).unwrap(), n);
+  }
+  let endpoint = server.socket().local_addr().unwrap();
+
+  let rerequester = Rerequester::bind("127.0.0.1:0", endpoint).unwrap()
+    .with_timeout(Duration::from_secs(1))
+    .with_max_messages_per_request(64);
+  let client = rerequester.socket().local_addr().unwrap();
+  let mut tracker = SequenceTracker::with_strategy(rerequester).expecting(session, 60);
+  let mut live = MoldWriter::new("SESSION007", 150);
+  live.add_message(&msg(150));
+  assert_eq!(tracker.track(&MoldReader::new(live.data()).unwrap()).gap, Some(SeqGap::new(60, 90)));
+
+  let start = Instant::now();
+  let mut recovered = Vec::new();
+  let mut recover_until = |tracker: &mut SequenceTracker<Rerequester>, want: usize| {
+    while recovered.len() < want {
+      wait_readable(tracker.strategy().socket());
+      tracker.recover_at(start, |seqno, bytes| {
+        assert_eq!(bytes, msg(seqno));
+        recovered.push(seqno);
+      }).unwrap();
+    }
+    recovered.len()
+  };
+  tracker.recover_at(start, |_, _| {}).unwrap();
+  // each answer is cut short by the packet size
+  let answered = |first, count| RequestOutcome::Answered { client, first, count };
+  assert_eq!(server.handle_request().unwrap(), answered(60, 32));
+  assert_eq!(server.handle_request().unwrap(), answered(124, 26));
+  assert_eq!(recover_until(&mut tracker, 58), 58);
+  // the rest is asked for again once the request times out
+  tracker.recover_at(start + Duration::from_secs(1), |_, _| {}).unwrap();
+  assert_eq!(server.handle_request().unwrap(), answered(92, 32));
+  assert_eq!(recover_until(&mut tracker, 90), 90);
+  assert!(tracker.missing().is_empty());
+  assert_eq!(tracker.strategy().outstanding().count(), 0);
+
+  // not a request, outside the window, the wrong session, then over the
+  // rate limit
+  let other = UdpSocket::bind("127.0.0.1:0").unwrap();
+  let other_addr = other.local_addr().unwrap();
+  let wrong_session = Session::new("SESSION008").unwrap();
+  let requests = [
+    RequestPacket{session, sequence_number: 50, requested_message_count: 5},
+    RequestPacket{session, sequence_number: 151, requested_message_count: 5},
+    RequestPacket{session: wrong_session, sequence_number: 100, requested_message_count: 5},
+    RequestPacket{session, sequence_number: 100, requested_message_count: 5},
+  ];
+  other.send_to(b"nonsense", endpoint).unwrap();
+  for request in &requests {
+    other.send_to(&request.to_vec(), endpoint).unwrap();
+  }
+  let outcomes = (0..5).map(|_| server.handle_request().unwrap()).collect::<Vec<_>>();
+  assert_eq!(outcomes, vec![
+    RequestOutcome::Malformed { client: other_addr, len: 8 },
+    RequestOutcome::OutOfWindow { client: other_addr, request: requests[0] },
+    RequestOutcome::OutOfWindow { client: other_addr, request: requests[1] },
+    RequestOutcome::WrongSession { client: other_addr, request: requests[2] },
+    RequestOutcome::RateLimited { client: other_addr },
+  ]);
+  assert_eq!((server.answered(), server.messages_sent(), server.unanswered()), (3, 90, 5));
+  server.forget_idle_clients(Duration::ZERO);
+  assert!(server.clients.is_empty());
+}
+
+#[test]
+fn retransmission_too_large() {
+  use crate::encode::Encode;
+  use crate::history::MemoryHistory;
+  let session = Session::new("SESSION007").unwrap();
+  let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+  socket.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+  let mut server = RetransmissionServer::new(socket, session, MemoryHistory::new(10))
+    .with_max_packet_size(20 + 2 + 39);
+  server.publish(&[b'x'; 40]).unwrap();
+  let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+  client.set_nonblocking(true).unwrap();
+  let request = RequestPacket{session, sequence_number: 1, requested_message_count: 1};
+  client.send_to(&request.to_vec(), server.socket().local_addr().unwrap()).unwrap();
+  let from = client.local_addr().unwrap();
+  assert_eq!(server.handle_request().unwrap(), RequestOutcome::TooLarge { client: from, request });
+  assert_eq!((server.answered(), server.unanswered()), (0, 1));
+  assert_eq!(client.recv(&mut [0u8; 64]).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+}
@@ -0,0 +1,362 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+use crate::alpha::Session;
+use crate::moldudp::MoldReader;
+
+// A run of missing message sequence numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeqGap {
+  pub first_missing: u64,
+  pub count: u64,
+}
+
+impl SeqGap {
+  pub fn new(first_missing: u64, count: u64) -> Self {
+    SeqGap{first_missing, count}
+  }
+  // one past the last missing sequence number, held at u64::MAX for a gap
+  // running to the end of the sequence space
+  pub fn end(&self) -> u64 {
+    self.first_missing.saturating_add(self.count)
+  }
+  pub fn range(&self) -> Range<u64> {
+    self.first_missing..self.end()
+  }
+  fn from_range(range: Range<u64>) -> Self {
+    SeqGap::new(range.start, range.end - range.start)
+  }
+}
+
+// What to do about a gap the tracker just found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Recovery {
+  // the messages are being fetched, accept them when they turn up
+  Wait,
+  // give up on them, they'll be discarded as duplicates if they turn up
+  Skip,
+}
+
+// Plugged into a SequenceTracker to act on what it sees, typically by
+// asking a retransmission server for the missing messages.
+pub trait RecoveryStrategy {
+  fn on_gap(&mut self, session: Session, gap: SeqGap) -> Recovery;
+  // messages of a gap this strategy waited on arrived
+  fn on_filled(&mut self, _session: Session, _filled: SeqGap) {}
+  // the server moved to a new session, gaps in the old one are dropped
+  fn on_session_switch(&mut self, _from: Session, _to: Session) {}
+}
+
+// Counts gaps as lost straight away.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SkipGaps;
+
+impl RecoveryStrategy for SkipGaps {
+  fn on_gap(&mut self, _session: Session, _gap: SeqGap) -> Recovery {
+    Recovery::Skip
+  }
+}
+
+impl<S: RecoveryStrategy + ?Sized> RecoveryStrategy for &mut S {
+  fn on_gap(&mut self, session: Session, gap: SeqGap) -> Recovery {
+    (**self).on_gap(session, gap)
+  }
+  fn on_filled(&mut self, session: Session, filled: SeqGap) {
+    (**self).on_filled(session, filled)
+  }
+  fn on_session_switch(&mut self, from: Session, to: Session) {
+    (**self).on_session_switch(from, to)
+  }
+}
+
+pub const MAX_PREVIOUS_SESSIONS : usize = 16;
+
+// Follows the sequence numbers of one MoldUDP64 session at a time across
+// packets, telling which messages are new. A packet ahead of the next
+// expected sequence number opens a gap, handed to the recovery strategy;
+// messages behind it are duplicates unless they fill a gap the strategy is
+// waiting on. Overlap is worked out per message, so a packet can be part
+// duplicate and part new. Without a starting point the tracker picks up at
+// whatever the first packet's sequence number is; a later new session is
+// expected to start at 1. Packets of sessions already left behind are stale
+// and nothing in them is new, as are packets whose messages would run past
+// u64::MAX. Only the last MAX_PREVIOUS_SESSIONS sessions are remembered, a
+// packet from one older than that looks like a new session.
+pub struct SequenceTracker<S = SkipGaps> {
+  strategy: S,
+  session: Option<Session>,
+  previous: VecDeque<Session>,
+  next_seqno: u64,
+  // gaps the strategy is waiting on, in order
+  missing: Vec<SeqGap>,
+  gaps: u64,
+  lost: u64,
+  duplicates: u64,
+  stale_packets: u64,
+}
+
+// What a packet turned out to hold. The new messages are borrowed from the
+// packet.
+#[derive(Clone, Debug)]
+pub struct Tracked<'a> {
+  reader: MoldReader<'a>,
+  new: Vec<Range<u64>>,
+  // opened by this packet
+  pub gap: Option<SeqGap>,
+  // messages already seen, or given up on
+  pub duplicates: u64,
+  pub stale: bool,
+  // set when this packet started a new session
+  pub switched_from: Option<Session>,
+}
+
+impl<'a> Tracked<'a> {
+  pub fn new_count(&self) -> u64 {
+    self.new.iter().map(|range| range.end - range.start).sum()
+  }
+  pub fn is_new(&self, seqno: u64) -> bool {
+    self.new.iter().any(|range| range.contains(&seqno))
+  }
+  // the new messages with their sequence numbers, in packet order
+  pub fn new_messages(&self) -> impl Iterator<Item = (u64, &'a [u8])> + '_ {
+    let first = self.reader.seqno();
+    self.reader.iter()
+      .map_while(Result::ok)
+      .enumerate()
+      .map_while(move |(i, msg)| Some((first.checked_add(i as u64)?, msg)))
+      .filter(|(seqno, _)| self.is_new(*seqno))
+  }
+}
+
+impl SequenceTracker<SkipGaps> {
+  pub fn new() -> Self {
+    Self::with_strategy(SkipGaps)
+  }
+}
+
+impl Default for SequenceTracker<SkipGaps> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<S: RecoveryStrategy> SequenceTracker<S> {
+  pub fn with_strategy(strategy: S) -> Self {
+    SequenceTracker{strategy, session: None, previous: VecDeque::new(), next_seqno: 0, missing: Vec::new(), gaps: 0, lost: 0, duplicates: 0, stale_packets: 0}
+  }
+  // start from a known point instead of the first packet seen, e.g. 1 to
+  // catch everything from the start of the session
+  pub fn expecting(mut self, session: Session, next_seqno: u64) -> Self {
+    self.session = Some(session);
+    self.next_seqno = next_seqno;
+    self
+  }
+  pub fn strategy(&self) -> &S {
+    &self.strategy
+  }
+  pub fn strategy_mut(&mut self) -> &mut S {
+    &mut self.strategy
+  }
+  pub fn session(&self) -> Option<Session> {
+    self.session
+  }
+  pub fn next_seqno(&self) -> u64 {
+    self.next_seqno
+  }
+  // gaps still being waited on
+  pub fn missing(&self) -> &[SeqGap] {
+    &self.missing
+  }
+  pub fn gaps(&self) -> u64 {
+    self.gaps
+  }
+  // messages skipped, abandoned or left missing by a session switch
+  pub fn lost(&self) -> u64 {
+    self.lost
+  }
+  pub fn duplicates(&self) -> u64 {
+    self.duplicates
+  }
+  pub fn stale_packets(&self) -> u64 {
+    self.stale_packets
+  }
+
+  // stop waiting on part of a gap, e.g. once retransmission requests for it
+  // have run out of retries
+  pub fn abandon(&mut self, gap: SeqGap) {
+    let removed = self.remove_missing(gap.range());
+    self.lost += removed.iter().map(|range| range.end - range.start).sum::<u64>();
+  }
+
+  // Works out what is new in a packet of any kind. Heartbeats and
+  // end-of-session carry the next sequence number, so they can open a gap
+  // too. A packet that goes bad part way through counts only up to its
+  // last well formed message, the rest is left to show up as a gap.
+  pub fn track<'a>(&mut self, reader: &MoldReader<'a>) -> Tracked<'a> {
+    let session = Session::from_bytes(reader.session().try_into().unwrap());
+    let mut tracked = Tracked{reader: *reader, new: Vec::new(), gap: None, duplicates: 0, stale: false, switched_from: None};
+    let first = reader.seqno();
+    let Some(end) = first.checked_add(reader.iter().map_while(Result::ok).count() as u64) else {
+      self.stale_packets += 1;
+      tracked.stale = true;
+      return tracked;
+    };
+    match self.session {
+      None => {
+        self.session = Some(session);
+        self.next_seqno = reader.seqno();
+      },
+      Some(current) if current == session => {},
+      Some(_) if self.previous.contains(&session) => {
+        self.stale_packets += 1;
+        tracked.stale = true;
+        return tracked;
+      },
+      Some(current) => {
+        if self.previous.len() == MAX_PREVIOUS_SESSIONS {
+          self.previous.pop_front();
+        }
+        self.previous.push_back(current);
+        self.lost += self.missing.drain(..).map(|gap| gap.count).sum::<u64>();
+        self.session = Some(session);
+        self.next_seqno = 1;
+        self.strategy.on_session_switch(current, session);
+        tracked.switched_from = Some(current);
+      },
+    }
+
+    if first > self.next_seqno {
+      let gap = SeqGap::from_range(self.next_seqno..first);
+      self.gaps += 1;
+      tracked.gap = Some(gap);
+      match self.strategy.on_gap(session, gap) {
+        Recovery::Wait => self.missing.push(gap),
+        Recovery::Skip => self.lost += gap.count,
+      }
+      self.next_seqno = first;
+    }
+    if first < self.next_seqno {
+      for filled in self.remove_missing(first..end.min(self.next_seqno)) {
+        self.strategy.on_filled(session, SeqGap::from_range(filled.clone()));
+        tracked.new.push(filled);
+      }
+    }
+    if end > self.next_seqno {
+      tracked.new.push(self.next_seqno..end);
+      self.next_seqno = end;
+    }
+    tracked.duplicates = end - first - tracked.new_count();
+    self.duplicates += tracked.duplicates;
+    tracked
+  }
+
+  // takes range out of the missing gaps, returning the parts that were
+  // missing in order
+  fn remove_missing(&mut self, range: Range<u64>) -> Vec<Range<u64>> {
+    let mut removed = Vec::new();
+    let mut kept = Vec::with_capacity(self.missing.len() + 1);
+    for gap in self.missing.drain(..) {
+      let start = gap.first_missing.max(range.start);
+      let end = gap.end().min(range.end);
+      if start >= end {
+        kept.push(gap);
+        continue;
+      }
+      removed.push(start..end);
+      if gap.first_missing < start {
+        kept.push(SeqGap::from_range(gap.first_missing..start));
+      }
+      if end < gap.end() {
+        kept.push(SeqGap::from_range(end..gap.end()));
+      }
+    }
+    self.missing = kept;
+    removed
+  }
+}
+
+#[test]
+fn sequence_tracking() {
+  use crate::moldudp::MoldWriter;
+  fn packet(session: &str, seqno: u64, count: u8) -> MoldWriter {
+    let mut writer = MoldWriter::new(session, seqno);
+    for i in 0..count {
+      writer.add_message(&[b'a' + (seqno as u8 + i) % 26]);
+    }
+    writer
+  }
+  // waits on gaps starting below 100
+  #[derive(Default)]
+  struct Recorded {
+    gaps: Vec<SeqGap>,
+    filled: Vec<SeqGap>,
+    switches: usize,
+  }
+  impl RecoveryStrategy for Recorded {
+    fn on_gap(&mut self, _session: Session, gap: SeqGap) -> Recovery {
+      self.gaps.push(gap);
+      if gap.first_missing < 100 { Recovery::Wait } else { Recovery::Skip }
+    }
+    fn on_filled(&mut self, _session: Session, filled: SeqGap) {
+      self.filled.push(filled);
+    }
+    fn on_session_switch(&mut self, _from: Session, _to: Session) {
+      self.switches += 1;
+    }
+  }
+  let mut tracker = SequenceTracker::with_strategy(Recorded::default());
+  let mut track = |writer: MoldWriter| {
+    let tracked = tracker.track(&MoldReader::new(writer.data()).unwrap());
+    let new = tracked.new_messages().map(|(seqno, msg)| (seqno, msg[0])).collect::<Vec<_>>();
+    (new, tracked.gap, tracked.duplicates, tracked.stale, tracked.switched_from.is_some())
+  };
+
+  // picks up mid session
+  assert_eq!(track(packet("SESSION001", 10, 2)), (vec![(10, b'k'), (11, b'l')], None, 0, false, false));
+  assert_eq!(track(packet("SESSION001", 15, 2)), (vec![(15, b'p'), (16, b'q')], Some(SeqGap::new(12, 3)), 0, false, false));
+  // retransmission filling part of the gap with some overlap either side
+  assert_eq!(track(packet("SESSION001", 11, 3)), (vec![(12, b'm'), (13, b'n')], None, 1, false, false));
+  assert_eq!(track(packet("SESSION001", 14, 4)), (vec![(14, b'o'), (17, b'r')], None, 2, false, false));
+  assert_eq!(track(packet("SESSION001", 14, 4)).2, 4);
+  // heartbeat past the next seqno opens a gap
+  assert_eq!(track(packet("SESSION001", 120, 0)), (vec![], Some(SeqGap::new(18, 102)), 0, false, false));
+  // skipped by the strategy, so lost
+  assert_eq!(track(packet("SESSION001", 200, 1)).1, Some(SeqGap::new(120, 80)));
+  // new session, gaps in the old one go
+  assert_eq!(track(packet("SESSION002", 3, 1)), (vec![(3, b'd')], Some(SeqGap::new(1, 2)), 0, false, true));
+  assert_eq!(track(packet("SESSION001", 201, 1)), (vec![], None, 0, true, false));
+
+  assert_eq!(tracker.missing(), &[SeqGap::new(1, 2)]);
+  tracker.abandon(SeqGap::new(2, 5));
+  assert_eq!(tracker.missing(), &[SeqGap::new(1, 1)]);
+  assert_eq!((tracker.gaps(), tracker.lost(), tracker.duplicates(), tracker.stale_packets()), (4, 183, 7, 1));
+  assert_eq!(tracker.next_seqno(), 4);
+  let strategy = tracker.strategy();
+  assert_eq!(strategy.gaps.len(), 4);
+  assert_eq!(strategy.filled, vec![SeqGap::new(12, 2), SeqGap::new(14, 1)]);
+  assert_eq!(strategy.switches, 1);
+}
+
+#[test]
+fn sequence_limits() {
+  use crate::moldudp::MoldWriter;
+  let mut tracker = SequenceTracker::new();
+  let mut writer = MoldWriter::new("SESSION001", u64::MAX - 1);
+  writer.add_message(b"a");
+  assert_eq!(tracker.track(&MoldReader::new(writer.data()).unwrap()).new_count(), 1);
+  // a message at u64::MAX would leave nothing for the next seqno
+  let mut writer = MoldWriter::new("SESSION001", u64::MAX);
+  writer.add_message(b"b");
+  let tracked = tracker.track(&MoldReader::new(writer.data()).unwrap());
+  assert!(tracked.stale);
+  assert_eq!(tracked.new_messages().count(), 0);
+  assert_eq!((tracker.next_seqno(), tracker.stale_packets()), (u64::MAX, 1));
+  assert_eq!(SeqGap::new(u64::MAX - 1, 5).end(), u64::MAX);
+
+  // only the most recent sessions count as stale
+  let session = |n: usize| format!("SESSION{:03}", n);
+  for n in 2..=MAX_PREVIOUS_SESSIONS + 2 {
+    tracker.track(&MoldReader::new(MoldWriter::new(&session(n), 1).data()).unwrap());
+  }
+  assert!(tracker.track(&MoldReader::new(MoldWriter::new(&session(3), 1).data()).unwrap()).stale);
+  assert!(tracker.track(&MoldReader::new(MoldWriter::new(&session(1), 1).data()).unwrap()).switched_from.is_some());
+}
@@ -0,0 +1,194 @@
+use std::fmt;
+use crate::context::MessageContext;
+use crate::error::SessionError;
+use crate::itch::{eSystemEvent, ItchHandler, SystemEvent};
+use crate::timestamp::ItchTimestamp;
+
+// The trading day's SystemEvents in the order they must arrive.
+const EVENT_ORDER : [eSystemEvent; 6] = [
+  eSystemEvent::Start_of_Messages,
+  eSystemEvent::Start_of_System_hours,
+  eSystemEvent::Start_of_Market_hours,
+  eSystemEvent::End_of_Market_hours,
+  eSystemEvent::End_of_System_hours,
+  eSystemEvent::End_of_Messages,
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarketPhase {
+  // no SystemEvent seen yet
+  #[default]
+  NotStarted,
+  // Start_of_Messages or Start_of_System_hours, before the open
+  PreOpen,
+  // Start_of_Market_hours until End_of_Market_hours
+  Regular,
+  // after the close, until End_of_System_hours
+  PostClose,
+  // End_of_System_hours or End_of_Messages
+  Closed,
+}
+
+impl MarketPhase {
+  fn after(event: eSystemEvent) -> MarketPhase {
+    match event {
+      eSystemEvent::Start_of_Messages | eSystemEvent::Start_of_System_hours => MarketPhase::PreOpen,
+      eSystemEvent::Start_of_Market_hours => MarketPhase::Regular,
+      eSystemEvent::End_of_Market_hours => MarketPhase::PostClose,
+      eSystemEvent::End_of_System_hours | eSystemEvent::End_of_Messages => MarketPhase::Closed,
+      eSystemEvent::Unknown(_) => MarketPhase::NotStarted,
+    }
+  }
+}
+
+impl fmt::Display for MarketPhase {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let s = match *self {
+      MarketPhase::NotStarted => "not started",
+      MarketPhase::PreOpen => "pre-open",
+      MarketPhase::Regular => "regular",
+      MarketPhase::PostClose => "post-close",
+      MarketPhase::Closed => "closed",
+    };
+    f.write_str(s)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhaseChange {
+  pub from: MarketPhase,
+  pub to: MarketPhase,
+  pub event: eSystemEvent,
+  pub timestamp: ItchTimestamp,
+}
+
+// Tracks the market phase from the feed's SystemEvents. Events must come in
+// EVENT_ORDER. A repeat or an event from earlier in the day is rejected,
+// leaves the phase alone and is kept in anomalies(). Skipping ahead moves
+// the phase on but records each missed event in anomalies(), except before
+// the first event where a late join has simply missed the early ones.
+// Listeners are called on every phase change. As an ItchHandler it can sit
+// next to other handlers in a tuple.
+#[derive(Default)]
+pub struct MarketSession {
+  phase: MarketPhase,
+  last_event: Option<eSystemEvent>,
+  event_times: [Option<ItchTimestamp>; 6],
+  anomalies: Vec<SessionError>,
+  listeners: Vec<PhaseListener>,
+}
+
+type PhaseListener = Box<dyn FnMut(&PhaseChange) + Send>;
+
+fn event_index(event: eSystemEvent) -> Option<usize> {
+  EVENT_ORDER.iter().position(|&e| e == event)
+}
+
+impl MarketSession {
+  pub fn new() -> Self {
+    Self::default()
+  }
+  pub fn on_phase_change<F: FnMut(&PhaseChange) + Send + 'static>(&mut self, listener: F) -> &mut Self {
+    self.listeners.push(Box::new(listener));
+    self
+  }
+
+  pub fn apply(&mut self, event: eSystemEvent, timestamp: ItchTimestamp) -> Result<Option<PhaseChange>, SessionError> {
+    let skipped_from = self.last_event.and_then(event_index).map(|last| last + 1);
+    let result = self.transition(event, timestamp);
+    if let (Ok(_), Some(first_missed)) = (&result, skipped_from) {
+      let index = event_index(event).unwrap();
+      for missed in &EVENT_ORDER[first_missed..index] {
+        self.anomalies.push(SessionError::Skipped { code: event.code(), missed: missed.code() });
+      }
+    }
+    match result {
+      Ok(Some(ref change)) => self.listeners.iter_mut().for_each(|listener| listener(change)),
+      Err(err) => self.anomalies.push(err),
+      Ok(None) => {},
+    }
+    result
+  }
+
+  fn transition(&mut self, event: eSystemEvent, timestamp: ItchTimestamp) -> Result<Option<PhaseChange>, SessionError> {
+    let index = event_index(event).ok_or(SessionError::UnknownEvent { code: event.code() })?;
+    if let Some(last) = self.last_event {
+      let last_index = event_index(last).unwrap();
+      if index == last_index {
+        return Err(SessionError::Duplicate { code: event.code() });
+      }
+      if index < last_index {
+        return Err(SessionError::OutOfOrder { code: event.code(), after: last.code() });
+      }
+    }
+    self.last_event = Some(event);
+    self.event_times[index] = Some(timestamp);
+    let from = self.phase;
+    self.phase = MarketPhase::after(event);
+    if from == self.phase {
+      return Ok(None);
+    }
+    Ok(Some(PhaseChange{from, to: self.phase, event, timestamp}))
+  }
+
+  pub fn phase(&self) -> MarketPhase {
+    self.phase
+  }
+  pub fn is_regular_hours(&self) -> bool {
+    self.phase == MarketPhase::Regular
+  }
+  pub fn last_event(&self) -> Option<eSystemEvent> {
+    self.last_event
+  }
+  // when the event arrived, None if it hasn't (yet)
+  pub fn event_time(&self, event: eSystemEvent) -> Option<ItchTimestamp> {
+    event_index(event).and_then(|index| self.event_times[index])
+  }
+  pub fn anomalies(&self) -> &[SessionError] {
+    &self.anomalies
+  }
+}
+
+impl ItchHandler for MarketSession {
+  fn on_system_event(&mut self, msg: &SystemEvent, _ctx: &MessageContext) {
+    // rejected events are recorded in anomalies
+    let _ = self.apply(msg.event_code, msg.timestamp);
+  }
+}
+
+#[test]
+fn session_phases() {
+  use std::sync::{Arc, Mutex};
+  // so it can be moved into a task next to the async streams
+  fn is_send<T: Send>(_: &T) {}
+  let changes = Arc::new(Mutex::new(Vec::new()));
+  let mut session = MarketSession::new();
+  is_send(&session);
+  let seen = changes.clone();
+  session.on_phase_change(move |change| seen.lock().unwrap().push((change.from, change.to)));
+  let at = |hour| ItchTimestamp::from_hms_nano(hour, 0, 0, 0).unwrap();
+
+  assert_eq!(session.phase(), MarketPhase::NotStarted);
+  let event = |code| SystemEvent{message_type: SystemEvent::TYPE, event_code: eSystemEvent::from_code(code), timestamp: at(3), ..Default::default()};
+  session.on_system_event(&event(b'O'), &MessageContext::default());
+  assert_eq!(session.apply(eSystemEvent::Start_of_System_hours, at(4)), Ok(None));
+  assert_eq!(session.apply(eSystemEvent::Start_of_Market_hours, at(9)).unwrap().unwrap().to, MarketPhase::Regular);
+  assert!(session.is_regular_hours());
+  assert_eq!(session.apply(eSystemEvent::Start_of_Market_hours, at(10)), Err(SessionError::Duplicate { code: b'Q' }));
+  assert_eq!(session.apply(eSystemEvent::Start_of_System_hours, at(10)), Err(SessionError::OutOfOrder { code: b'S', after: b'Q' }));
+  assert_eq!(session.apply(eSystemEvent::from_code(b'?'), at(10)), Err(SessionError::UnknownEvent { code: b'?' }));
+  // jumping from the open straight to end of system hours moves the phase on
+  // but the missed close is an anomaly
+  session.apply(eSystemEvent::End_of_System_hours, at(20)).unwrap();
+  session.apply(eSystemEvent::End_of_Messages, at(21)).unwrap();
+
+  assert_eq!(session.phase(), MarketPhase::Closed);
+  assert_eq!(session.event_time(eSystemEvent::Start_of_Market_hours), Some(at(9)));
+  assert_eq!(session.event_time(eSystemEvent::End_of_Market_hours), None);
+  assert_eq!(session.anomalies()[3..], [SessionError::Skipped { code: b'E', missed: b'M' }]);
+  assert_eq!(*changes.lock().unwrap(), vec![
+    (MarketPhase::NotStarted, MarketPhase::PreOpen),
+    (MarketPhase::PreOpen, MarketPhase::Regular),
+    (MarketPhase::Regular, MarketPhase::Closed),
+  ]);
+}
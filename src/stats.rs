@@ -0,0 +1,292 @@
+use std::fmt::{self, Write as _};
+use crate::context::MessageContext;
+use crate::error::DecodeError;
+use crate::itch::{ItchHandler, ItchMessage};
+use crate::message::ItchMessageType;
+use crate::timestamp::ItchTimestamp;
+
+const NANOS_PER_SECOND : u64 = 1_000_000_000;
+
+// Feed counters, cheap enough to leave on for a whole day: everything is a
+// fixed array or a vector indexed by locate or second. Byte counts are wire
+// sizes, including unknown messages and trailing bytes. Stats gathered on
+// several threads or files combine with merge().
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItchStats {
+  by_type: [u64; 128],
+  by_locate: Vec<u64>,
+  // indexed by second since midnight
+  per_second: Vec<u64>,
+  messages: u64,
+  bytes: u64,
+  unknown: u64,
+  decode_errors: u64,
+  first_timestamp: Option<ItchTimestamp>,
+  last_timestamp: Option<ItchTimestamp>,
+  max_order_reference_number: u64,
+}
+
+impl Default for ItchStats {
+  fn default() -> Self {
+    ItchStats{
+      by_type: [0; 128],
+      by_locate: Vec::new(),
+      per_second: Vec::new(),
+      messages: 0,
+      bytes: 0,
+      unknown: 0,
+      decode_errors: 0,
+      first_timestamp: None,
+      last_timestamp: None,
+      max_order_reference_number: 0,
+    }
+  }
+}
+
+fn bump(counts: &mut Vec<u64>, index: usize, by: u64) {
+  if counts.len() <= index {
+    counts.resize(index + 1, 0);
+  }
+  counts[index] += by;
+}
+
+fn min_some<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(a.min(b)),
+    (a, b) => a.or(b),
+  }
+}
+
+impl ItchStats {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  fn record<M: ItchMessageType>(&mut self, msg: &M) {
+    self.messages += 1;
+    self.bytes += M::SIZE as u64;
+    self.by_type[M::TYPE as usize & 0x7f] += 1;
+    if let Some(locate) = msg.stock_locate() {
+      bump(&mut self.by_locate, locate as usize, 1);
+    }
+    if let Some(timestamp) = msg.timestamp() {
+      bump(&mut self.per_second, (timestamp.0 / NANOS_PER_SECOND) as usize, 1);
+      self.first_timestamp = min_some(self.first_timestamp, Some(timestamp));
+      self.last_timestamp = self.last_timestamp.max(Some(timestamp));
+    }
+    if let Some(order_reference_number) = msg.order_reference_number() {
+      self.max_order_reference_number = self.max_order_reference_number.max(order_reference_number);
+    }
+  }
+
+  // adds in stats collected elsewhere, e.g. on another thread or from another file
+  pub fn merge(&mut self, other: &ItchStats) {
+    for (count, more) in self.by_type.iter_mut().zip(other.by_type.iter()) {
+      *count += more;
+    }
+    for (locate, &count) in other.by_locate.iter().enumerate().filter(|(_, &count)| count > 0) {
+      bump(&mut self.by_locate, locate, count);
+    }
+    for (second, &count) in other.per_second.iter().enumerate().filter(|(_, &count)| count > 0) {
+      bump(&mut self.per_second, second, count);
+    }
+    self.messages += other.messages;
+    self.bytes += other.bytes;
+    self.unknown += other.unknown;
+    self.decode_errors += other.decode_errors;
+    self.first_timestamp = min_some(self.first_timestamp, other.first_timestamp);
+    self.last_timestamp = self.last_timestamp.max(other.last_timestamp);
+    self.max_order_reference_number = self.max_order_reference_number.max(other.max_order_reference_number);
+  }
+
+  // decoded messages, not counting unknown types or decode errors
+  pub fn messages(&self) -> u64 {
+    self.messages
+  }
+  pub fn bytes(&self) -> u64 {
+    self.bytes
+  }
+  pub fn unknown(&self) -> u64 {
+    self.unknown
+  }
+  pub fn decode_errors(&self) -> u64 {
+    self.decode_errors
+  }
+  pub fn count(&self, tipe: u8) -> u64 {
+    if tipe < 128 { self.by_type[tipe as usize] } else { 0 }
+  }
+  pub fn count_of<M: ItchMessageType>(&self) -> u64 {
+    self.count(M::TYPE)
+  }
+  pub fn locate_count(&self, locate: u16) -> u64 {
+    self.by_locate.get(locate as usize).copied().unwrap_or(0)
+  }
+  // (type byte, count) for every type seen
+  pub fn by_type(&self) -> impl Iterator<Item = (u8, u64)> + '_ {
+    self.by_type.iter().enumerate().filter(|(_, &count)| count > 0).map(|(tipe, &count)| (tipe as u8, count))
+  }
+  // (locate, count) for every locate seen
+  pub fn by_locate(&self) -> impl Iterator<Item = (u16, u64)> + '_ {
+    self.by_locate.iter().enumerate().filter(|(_, &count)| count > 0).map(|(locate, &count)| (locate as u16, count))
+  }
+  // (second since midnight, count) for every second with traffic
+  pub fn per_second(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+    self.per_second.iter().enumerate().filter(|(_, &count)| count > 0).map(|(second, &count)| (second as u32, count))
+  }
+  pub fn busiest_second(&self) -> Option<(u32, u64)> {
+    self.per_second().max_by_key(|&(second, count)| (count, std::cmp::Reverse(second)))
+  }
+  pub fn first_timestamp(&self) -> Option<ItchTimestamp> {
+    self.first_timestamp
+  }
+  pub fn last_timestamp(&self) -> Option<ItchTimestamp> {
+    self.last_timestamp
+  }
+  pub fn max_order_reference_number(&self) -> u64 {
+    self.max_order_reference_number
+  }
+  // AddOrder and AddOrderWithMpid
+  pub fn adds(&self) -> u64 {
+    self.count(b'A') + self.count(b'F')
+  }
+  // OrderExecuted and OrderExecutedWithPrice
+  pub fn executes(&self) -> u64 {
+    self.count(b'E') + self.count(b'C')
+  }
+  // OrderCancel and OrderDelete
+  pub fn cancels(&self) -> u64 {
+    self.count(b'X') + self.count(b'D')
+  }
+  pub fn replaces(&self) -> u64 {
+    self.count(b'U')
+  }
+  pub fn execute_ratio(&self) -> Option<f64> {
+    ratio(self.executes(), self.adds())
+  }
+  pub fn cancel_ratio(&self) -> Option<f64> {
+    ratio(self.cancels(), self.adds())
+  }
+
+  pub fn to_json(&self) -> String {
+    let mut out = String::new();
+    let timestamp = |timestamp: Option<ItchTimestamp>| timestamp.map_or("null".to_string(), |t| format!("\"{}\"", t));
+    let ratio = |ratio: Option<f64>| ratio.map_or("null".to_string(), |r| format!("{:.4}", r));
+    write!(out, "{{\"messages\":{},\"bytes\":{},\"unknown\":{},\"decode_errors\":{}", self.messages, self.bytes, self.unknown, self.decode_errors).unwrap();
+    write!(out, ",\"first_timestamp\":{},\"last_timestamp\":{}", timestamp(self.first_timestamp), timestamp(self.last_timestamp)).unwrap();
+    write!(out, ",\"max_order_reference_number\":{}", self.max_order_reference_number).unwrap();
+    write!(out, ",\"adds\":{},\"executes\":{},\"cancels\":{},\"replaces\":{}", self.adds(), self.executes(), self.cancels(), self.replaces()).unwrap();
+    write!(out, ",\"execute_ratio\":{},\"cancel_ratio\":{}", ratio(self.execute_ratio()), ratio(self.cancel_ratio())).unwrap();
+    let object = |out: &mut String, name: &str, entries: &mut dyn Iterator<Item = (String, u64)>| {
+      write!(out, ",\"{}\":{{", name).unwrap();
+      for (i, (key, count)) in entries.enumerate() {
+        write!(out, "{}\"{}\":{}", if i > 0 { "," } else { "" }, key, count).unwrap();
+      }
+      out.push('}');
+    };
+    object(&mut out, "by_type", &mut self.by_type().map(|(tipe, count)| ((tipe as char).to_string(), count)));
+    object(&mut out, "by_locate", &mut self.by_locate().map(|(locate, count)| (locate.to_string(), count)));
+    object(&mut out, "per_second", &mut self.per_second().map(|(second, count)| (second.to_string(), count)));
+    out.push('}');
+    out
+  }
+}
+
+fn ratio(num: u64, den: u64) -> Option<f64> {
+  if den == 0 { None } else { Some(num as f64 / den as f64) }
+}
+
+// the text report: totals, then counts by type and the ten busiest locates
+impl fmt::Display for ItchStats {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    writeln!(f, "messages       {} ({} bytes)", self.messages, self.bytes)?;
+    if self.unknown > 0 || self.decode_errors > 0 {
+      writeln!(f, "unknown        {}", self.unknown)?;
+      writeln!(f, "decode errors  {}", self.decode_errors)?;
+    }
+    if let (Some(first), Some(last)) = (self.first_timestamp, self.last_timestamp) {
+      writeln!(f, "first          {}", first)?;
+      writeln!(f, "last           {}", last)?;
+    }
+    if let Some((second, count)) = self.busiest_second() {
+      writeln!(f, "busiest second {} ({} messages)", ItchTimestamp(second as u64 * NANOS_PER_SECOND), count)?;
+    }
+    writeln!(f, "max order ref  {}", self.max_order_reference_number)?;
+    let ratio = |ratio: Option<f64>| ratio.map_or("-".to_string(), |r| format!("{:.4}", r));
+    writeln!(f, "adds {}  executes {} ({})  cancels {} ({})  replaces {}",
+      self.adds(), self.executes(), ratio(self.execute_ratio()), self.cancels(), ratio(self.cancel_ratio()), self.replaces())?;
+    writeln!(f, "by type:")?;
+    for (tipe, count) in self.by_type() {
+      writeln!(f, "  {} {:<31} {:>12}", tipe as char, ItchMessage::name_of_type(tipe).unwrap_or("?"), count)?;
+    }
+    let mut locates = self.by_locate().collect::<Vec<_>>();
+    locates.sort_by_key(|&(locate, count)| (std::cmp::Reverse(count), locate));
+    writeln!(f, "busiest locates:")?;
+    for (locate, count) in locates.into_iter().take(10) {
+      writeln!(f, "  {:<5} {:>12}", locate, count)?;
+    }
+    Ok(())
+  }
+}
+
+macro_rules! record_message {
+  ([] $(($on:ident, $msg:ty)),* $(,)?) => {
+    $(
+      fn $on(&mut self, msg: &$msg, _ctx: &MessageContext) {
+        self.record(msg);
+      }
+    )*
+  };
+}
+
+impl ItchHandler for ItchStats {
+  crate::for_each_itch_message!(record_message);
+  fn on_unknown(&mut self, _type_byte: u8, raw: &[u8], _ctx: &MessageContext) {
+    self.unknown += 1;
+    self.bytes += raw.len() as u64;
+  }
+  fn on_decode_error(&mut self, _err: DecodeError, raw: &[u8], _ctx: &MessageContext) {
+    self.decode_errors += 1;
+    self.bytes += raw.len() as u64;
+  }
+  fn on_trailing_bytes(&mut self, _type_byte: u8, trailing: &[u8], _ctx: &MessageContext) {
+    self.bytes += trailing.len() as u64;
+  }
+}
+
+#[test]
+fn stats_counts_and_merge() {
+  use crate::encode::Encode;
+  use crate::itch::*;
+  let at = |second: u32, nano: u32| ItchTimestamp::from_hms_nano(9, 30, second, nano).unwrap();
+  let ctx = MessageContext::default();
+  let mut morning = ItchStats::new();
+  let mut afternoon = ItchStats::new();
+  for i in 0..4 {
+    let add = AddOrder{message_type: AddOrder::TYPE, stock_locate: 7, timestamp: at(0, i), order_reference_number: 10 + i as u64, ..Default::default()};
+    crack_message(&add.to_vec(), &mut morning).unwrap();
+  }
+  let delete = OrderDelete{message_type: OrderDelete::TYPE, stock_locate: 9, timestamp: at(1, 0), order_reference_number: 11, ..Default::default()};
+  crack_message(&delete.to_vec(), &mut morning).unwrap();
+  let replace = OrderReplace{message_type: OrderReplace::TYPE, stock_locate: 7, timestamp: at(2, 0), original_order_reference_number: 12, new_order_reference_number: 99, ..Default::default()};
+  crack_message(&replace.to_vec(), &mut afternoon).unwrap();
+  crack_message(b"~\x01\x02", &mut afternoon).unwrap();
+  afternoon.on_trailing_bytes(b'U', b"\x00\x00", &ctx);
+
+  assert_eq!(morning.count_of::<AddOrder>(), 4);
+  assert_eq!(morning.cancel_ratio(), Some(0.25));
+  assert_eq!(morning.busiest_second(), Some((34200, 4)));
+  morning.merge(&afternoon);
+  assert_eq!(morning.messages(), 6);
+  assert_eq!(morning.bytes(), 4 * ADD_ORDER_SIZE as u64 + (ORDER_DELETE_SIZE + ORDER_REPLACE_SIZE) as u64 + 3 + 2);
+  assert_eq!(morning.unknown(), 1);
+  assert_eq!(morning.locate_count(7), 5);
+  assert_eq!(morning.max_order_reference_number(), 99);
+  assert_eq!((morning.first_timestamp(), morning.last_timestamp()), (Some(at(0, 0)), Some(at(2, 0))));
+  assert_eq!(morning.per_second().collect::<Vec<_>>(), vec![(34200, 4), (34201, 1), (34202, 1)]);
+
+  let report = morning.to_string();
+  assert!(report.contains("  A AddOrder                                   4"));
+  let json = morning.to_json();
+  assert!(json.starts_with("{\"messages\":6,\"bytes\":"));
+  assert!(json.contains(",\"by_type\":{\"A\":4,\"D\":1,\"U\":1},\"by_locate\":{\"7\":5,\"9\":1},"));
+}
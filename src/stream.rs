@@ -0,0 +1,270 @@
+use std::io::{self, Read};
+use crate::error::{DecodeError, StreamError};
+use crate::itch::{ItchMessage, MessageBlock, MESSAGE_BLOCK_SIZE};
+use crate::message::LengthCheck;
+
+// How messages are laid out back to back in a buffer or file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Framing {
+  // each message preceded by a big endian u16 length, as in MessageBlock
+  #[default]
+  LengthPrefixed,
+  // no framing, each message's length comes from its type byte
+  Raw,
+}
+
+// Decoded messages out of an in-memory buffer. With LengthPrefixed framing a
+// bad message is reported and skipped; with Raw framing there is no way to
+// find the next message so iteration stops after the error. Frames longer
+// than their message are accepted unless a Strict length check is set.
+pub struct ItchIter<'a> {
+  data: &'a [u8],
+  offset: usize,
+  framing: Framing,
+  check: LengthCheck,
+  done: bool,
+}
+
+impl<'a> ItchIter<'a> {
+  pub fn new(data: &'a [u8]) -> Self {
+    Self::with_framing(data, Framing::LengthPrefixed)
+  }
+  pub fn raw(data: &'a [u8]) -> Self {
+    Self::with_framing(data, Framing::Raw)
+  }
+  pub fn with_framing(data: &'a [u8], framing: Framing) -> Self {
+    ItchIter{data, offset: 0, framing, check: LengthCheck::Lenient, done: false}
+  }
+  pub fn with_length_check(mut self, check: LengthCheck) -> Self {
+    self.check = check;
+    self
+  }
+  // bytes consumed so far
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+  pub fn remaining(&self) -> &'a [u8] {
+    &self.data[self.offset..]
+  }
+
+  fn next_frame(&mut self) -> Result<&'a [u8], DecodeError> {
+    let rest = self.remaining();
+    match self.framing {
+      Framing::LengthPrefixed => {
+        let (block, _) = MessageBlock::from_bytes(rest).ok_or(DecodeError::Truncated {
+          type_byte: 0, expected: MESSAGE_BLOCK_SIZE, actual: rest.len(), offset: self.offset })?;
+        let len = block.message_length as usize;
+        let frame = rest.get(MESSAGE_BLOCK_SIZE..MESSAGE_BLOCK_SIZE+len).ok_or(DecodeError::Truncated {
+          type_byte: rest.get(MESSAGE_BLOCK_SIZE).copied().unwrap_or(0),
+          expected: len, actual: rest.len() - MESSAGE_BLOCK_SIZE, offset: self.offset + MESSAGE_BLOCK_SIZE })?;
+        self.offset += MESSAGE_BLOCK_SIZE + len;
+        Ok(frame)
+      },
+      Framing::Raw => {
+        let size = ItchMessage::size_of_type(rest[0]).ok_or(DecodeError::UnknownType {
+          type_byte: rest[0], offset: self.offset })?;
+        let frame = rest.get(..size).ok_or(DecodeError::Truncated {
+          type_byte: rest[0], expected: size, actual: rest.len(), offset: self.offset })?;
+        self.offset += size;
+        Ok(frame)
+      },
+    }
+  }
+}
+
+impl Iterator for ItchIter<'_> {
+  type Item = Result<ItchMessage, DecodeError>;
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done || self.offset == self.data.len() {
+      return None;
+    }
+    let start = self.offset;
+    let frame = match self.next_frame() {
+      Ok(frame) => frame,
+      Err(err) => {
+        self.done = true;
+        return Some(Err(err));
+      },
+    };
+    let base = self.offset - frame.len();
+    let result = decode_frame(frame, self.check).map_err(|err| err.at_offset(base));
+    if result.is_err() && self.framing == Framing::Raw {
+      self.done = true;
+      self.offset = start;
+    }
+    Some(result)
+  }
+}
+
+// Decoded messages pulled from any reader, e.g. a BufReader over a capture
+// file. Errors follow the same rules as ItchIter, and any io error ends the
+// stream. A clean end of input between messages ends it without an error.
+pub struct ItchStream<R> {
+  rdr: R,
+  framing: Framing,
+  check: LengthCheck,
+  buf: Vec<u8>,
+  offset: usize,
+  done: bool,
+}
+
+impl<R: Read> ItchStream<R> {
+  pub fn new(rdr: R) -> Self {
+    Self::with_framing(rdr, Framing::LengthPrefixed)
+  }
+  pub fn raw(rdr: R) -> Self {
+    Self::with_framing(rdr, Framing::Raw)
+  }
+  pub fn with_framing(rdr: R, framing: Framing) -> Self {
+    ItchStream{rdr, framing, check: LengthCheck::Lenient, buf: Vec::with_capacity(64), offset: 0, done: false}
+  }
+  pub fn with_length_check(mut self, check: LengthCheck) -> Self {
+    self.check = check;
+    self
+  }
+  // bytes read so far
+  pub fn offset(&self) -> usize {
+    self.offset
+  }
+  pub fn get_ref(&self) -> &R {
+    &self.rdr
+  }
+  pub fn into_inner(self) -> R {
+    self.rdr
+  }
+
+  // fills buf with len bytes, Ok(false) on a clean end of input when at_boundary
+  fn fill(&mut self, len: usize, at_boundary: bool) -> io::Result<bool> {
+    let start = self.buf.len();
+    self.buf.resize(start + len, 0);
+    let mut got = 0;
+    while got < len {
+      match self.rdr.read(&mut self.buf[start+got..]) {
+        Ok(0) if at_boundary && got == 0 => return Ok(false),
+        Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+        Ok(n) => got += n,
+        Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {},
+        Err(err) => return Err(err),
+      }
+    }
+    self.offset += len;
+    Ok(true)
+  }
+
+  // reads the next frame into buf, returning the offset of its first byte
+  fn next_frame(&mut self) -> Result<Option<usize>, StreamError> {
+    self.buf.clear();
+    match self.framing {
+      Framing::LengthPrefixed => {
+        if !self.fill(MESSAGE_BLOCK_SIZE, true)? {
+          return Ok(None);
+        }
+        let (block, _) = MessageBlock::from_bytes(&self.buf).expect("buffer holds a full length prefix");
+        self.buf.clear();
+        self.fill(block.message_length as usize, false)?;
+      },
+      Framing::Raw => {
+        if !self.fill(1, true)? {
+          return Ok(None);
+        }
+        let size = ItchMessage::size_of_type(self.buf[0]).ok_or(DecodeError::UnknownType {
+          type_byte: self.buf[0], offset: self.offset - 1 })?;
+        self.fill(size - 1, false)?;
+      },
+    }
+    Ok(Some(self.offset - self.buf.len()))
+  }
+}
+
+impl<R: Read> Iterator for ItchStream<R> {
+  type Item = Result<ItchMessage, StreamError>;
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.done {
+      return None;
+    }
+    let base = match self.next_frame() {
+      Ok(Some(base)) => base,
+      Ok(None) => {
+        self.done = true;
+        return None;
+      },
+      Err(err) => {
+        self.done = true;
+        return Some(Err(err));
+      },
+    };
+    let result = decode_frame(&self.buf, self.check).map_err(|err| err.at_offset(base));
+    if result.is_err() && self.framing == Framing::Raw {
+      self.done = true;
+    }
+    Some(result.map_err(StreamError::from))
+  }
+}
+
+pub(crate) fn decode_frame(frame: &[u8], check: LengthCheck) -> Result<ItchMessage, DecodeError> {
+  let (msg, size) = ItchMessage::decode(frame)?;
+  check.check(frame[0], size, frame.len())?;
+  Ok(msg)
+}
+
+#[cfg(test)]
+fn sample_messages() -> Vec<ItchMessage> {
+  use crate::itch::*;
+  use crate::timestamp::ItchTimestamp;
+  (1..=3).map(|i| ItchMessage::OrderDelete(OrderDelete{
+    message_type: OrderDelete::TYPE,
+    stock_locate: i,
+    timestamp: ItchTimestamp(i as u64 * 1000),
+    order_reference_number: i as u64,
+    ..Default::default()
+  })).collect()
+}
+
+#[test]
+fn iterate_framings() {
+  let msgs = sample_messages();
+  let mut prefixed = Vec::new();
+  let mut raw = Vec::new();
+  for msg in &msgs {
+    prefixed.extend_from_slice(&(msg.encoded_len() as u16).to_be_bytes());
+    msg.encode(&mut prefixed).unwrap();
+    msg.encode(&mut raw).unwrap();
+  }
+  assert_eq!(ItchIter::new(&prefixed).collect::<Result<Vec<_>, _>>(), Ok(msgs.clone()));
+  assert_eq!(ItchIter::raw(&raw).collect::<Result<Vec<_>, _>>(), Ok(msgs.clone()));
+  let streamed = ItchStream::new(io::BufReader::new(&prefixed[..])).collect::<Result<Vec<_>, _>>().unwrap();
+  assert_eq!(streamed, msgs);
+  let early = ItchStream::raw(&raw[..])
+    .map(Result::unwrap)
+    .take_while(|msg| msg.timestamp().unwrap().0 < 3000)
+    .count();
+  assert_eq!(early, 2);
+
+  // a bad type byte is skipped with framing and ends iteration without it
+  let mut bad = prefixed.clone();
+  bad[2] = b'!';
+  let results = ItchIter::new(&bad).collect::<Vec<_>>();
+  assert_eq!(results[0], Err(DecodeError::UnknownType { type_byte: b'!', offset: 2 }));
+  assert_eq!(results.len(), 3);
+  let mut bad = raw.clone();
+  bad[msgs[0].encoded_len()] = b'!';
+  assert_eq!(ItchIter::raw(&bad).filter(Result::is_err).count(), 1);
+  assert_eq!(ItchIter::raw(&bad).count(), 2);
+
+  // cut off mid message
+  let cut = &prefixed[..prefixed.len() - 1];
+  match ItchStream::new(cut).last() {
+    Some(Err(StreamError::Io(err))) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+    other => panic!("expected eof error, got {:?}", other),
+  }
+  assert!(matches!(ItchIter::new(cut).last(), Some(Err(DecodeError::Truncated { .. }))));
+
+  // a frame with an extra byte appended to the first message
+  let mut long = prefixed.clone();
+  long[1] += 1;
+  long.insert(2 + msgs[0].encoded_len(), 0xee);
+  assert_eq!(ItchIter::new(&long).filter(Result::is_ok).count(), 3);
+  let strict = ItchStream::new(&long[..]).with_length_check(LengthCheck::Strict).collect::<Vec<_>>();
+  assert!(matches!(strict[0], Err(StreamError::Decode(DecodeError::LengthMismatch { offset: 2, .. }))));
+  assert_eq!(strict.len(), 3);
+}
@@ -0,0 +1,294 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::error::TimestampError;
+
+const NANOS_PER_SECOND : u64 = 1_000_000_000;
+const NANOS_PER_MINUTE : u64 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR : u64 = 60 * NANOS_PER_MINUTE;
+const SECONDS_PER_DAY : i64 = 86_400;
+
+// Nanoseconds since midnight US/Eastern, as carried in the 6 byte timestamp
+// field of every message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ItchTimestamp(pub u64);
+
+impl ItchTimestamp {
+  // largest value the 48 bit wire field can hold
+  pub const MAX : ItchTimestamp = ItchTimestamp((1 << 48) - 1);
+
+  pub fn from_nanos(nanos: u64) -> Self {
+    ItchTimestamp(nanos)
+  }
+  pub fn from_hms_nano(hour: u32, minute: u32, second: u32, nano: u32) -> Option<Self> {
+    if hour > 23 || minute > 59 || second > 59 || nano as u64 >= NANOS_PER_SECOND {
+      return None;
+    }
+    Some(ItchTimestamp(hour as u64 * NANOS_PER_HOUR + minute as u64 * NANOS_PER_MINUTE + second as u64 * NANOS_PER_SECOND + nano as u64))
+  }
+  pub fn nanos(&self) -> u64 {
+    self.0
+  }
+  pub fn hour(&self) -> u32 {
+    (self.0 / NANOS_PER_HOUR) as u32
+  }
+  pub fn minute(&self) -> u32 {
+    (self.0 % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u32
+  }
+  pub fn second(&self) -> u32 {
+    (self.0 % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u32
+  }
+  pub fn nanosecond(&self) -> u32 {
+    (self.0 % NANOS_PER_SECOND) as u32
+  }
+  pub fn since_midnight(&self) -> Duration {
+    Duration::from_nanos(self.0)
+  }
+
+  pub fn checked_add(self, rhs: Duration) -> Option<Self> {
+    let nanos = u64::try_from(rhs.as_nanos()).ok()?;
+    self.0.checked_add(nanos).map(ItchTimestamp).filter(|ts| *ts <= Self::MAX)
+  }
+  pub fn checked_sub(self, rhs: Duration) -> Option<Self> {
+    let nanos = u64::try_from(rhs.as_nanos()).ok()?;
+    self.0.checked_sub(nanos).map(ItchTimestamp)
+  }
+  // zero if earlier is actually later, like Instant::duration_since
+  pub fn duration_since(&self, earlier: ItchTimestamp) -> Duration {
+    Duration::from_nanos(self.0.saturating_sub(earlier.0))
+  }
+
+  // nanoseconds since the unix epoch for this timestamp on the given session date
+  pub fn to_unix_nanos(&self, date: SessionDate) -> i128 {
+    let offset = eastern_utc_offset(date, self.0);
+    let local = date.days_since_epoch() as i128 * SECONDS_PER_DAY as i128 * NANOS_PER_SECOND as i128 + self.0 as i128;
+    local - offset as i128 * NANOS_PER_SECOND as i128
+  }
+  pub fn to_utc(&self, date: SessionDate) -> SystemTime {
+    let nanos = self.to_unix_nanos(date);
+    let since = Duration::new((nanos.unsigned_abs() / NANOS_PER_SECOND as u128) as u64, (nanos.unsigned_abs() % NANOS_PER_SECOND as u128) as u32);
+    if nanos >= 0 { UNIX_EPOCH + since } else { UNIX_EPOCH - since }
+  }
+}
+
+impl fmt::Display for ItchTimestamp {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:02}:{:02}:{:02}.{:09}", self.hour(), self.minute(), self.second(), self.nanosecond())
+  }
+}
+
+// accepts HH:MM, HH:MM:SS and HH:MM:SS.fraction with up to 9 fractional digits
+impl FromStr for ItchTimestamp {
+  type Err = TimestampError;
+  fn from_str(s: &str) -> Result<Self, TimestampError> {
+    let (hms, fraction) = match s.find('.') {
+      Some(dot) => (&s[..dot], &s[dot+1..]),
+      None => (s, ""),
+    };
+    if fraction.len() > 9 || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+      return Err(TimestampError::Invalid);
+    }
+    let mut parts = [0u32; 3];
+    let mut count = 0;
+    for part in hms.split(':') {
+      if count == 3 || part.len() != 2 || !part.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(TimestampError::Invalid);
+      }
+      parts[count] = part.parse().map_err(|_| TimestampError::Invalid)?;
+      count += 1;
+    }
+    if count < 2 || (count == 2 && !fraction.is_empty()) || (s.contains('.') && fraction.is_empty()) {
+      return Err(TimestampError::Invalid);
+    }
+    let nano = fraction.bytes().chain(std::iter::repeat(b'0')).take(9)
+      .fold(0, |acc: u32, c| acc * 10 + (c - b'0') as u32);
+    ItchTimestamp::from_hms_nano(parts[0], parts[1], parts[2], nano).ok_or(TimestampError::OutOfRange)
+  }
+}
+
+impl Add<Duration> for ItchTimestamp {
+  type Output = ItchTimestamp;
+  fn add(self, rhs: Duration) -> ItchTimestamp {
+    self.checked_add(rhs).expect("overflow when adding duration to timestamp")
+  }
+}
+
+impl Sub<Duration> for ItchTimestamp {
+  type Output = ItchTimestamp;
+  fn sub(self, rhs: Duration) -> ItchTimestamp {
+    self.checked_sub(rhs).expect("overflow when subtracting duration from timestamp")
+  }
+}
+
+impl Sub for ItchTimestamp {
+  type Output = Duration;
+  fn sub(self, rhs: ItchTimestamp) -> Duration {
+    self.duration_since(rhs)
+  }
+}
+
+// HH:MM:SS.nnnnnnnnn for human readable formats, raw nanoseconds otherwise
+#[cfg(feature = "serde")]
+impl serde::Serialize for ItchTimestamp {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+      serializer.collect_str(self)
+    } else {
+      serializer.serialize_u64(self.0)
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ItchTimestamp {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    if deserializer.is_human_readable() {
+      let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+      s.parse().map_err(serde::de::Error::custom)
+    } else {
+      <u64 as serde::Deserialize>::deserialize(deserializer).map(ItchTimestamp)
+    }
+  }
+}
+
+// Calendar date of a trading session, proleptic gregorian.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionDate {
+  year: i32,
+  month: u32,
+  day: u32,
+}
+
+impl SessionDate {
+  pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+    if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+      return None;
+    }
+    Some(SessionDate{year, month, day})
+  }
+  pub fn year(&self) -> i32 {
+    self.year
+  }
+  pub fn month(&self) -> u32 {
+    self.month
+  }
+  pub fn day(&self) -> u32 {
+    self.day
+  }
+  // days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+  pub fn days_since_epoch(&self) -> i64 {
+    let y = if self.month <= 2 { self.year as i64 - 1 } else { self.year as i64 };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = self.month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+  }
+  // 0 is sunday
+  pub fn weekday(&self) -> u32 {
+    (self.days_since_epoch() + 4).rem_euclid(7) as u32
+  }
+}
+
+impl fmt::Display for SessionDate {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+  }
+}
+
+// YYYY-MM-DD or YYYYMMDD
+impl FromStr for SessionDate {
+  type Err = TimestampError;
+  fn from_str(s: &str) -> Result<Self, TimestampError> {
+    let digits : String = match s.len() {
+      10 if s.as_bytes()[4] == b'-' && s.as_bytes()[7] == b'-' => s.split('-').collect(),
+      8 => s.to_string(),
+      _ => return Err(TimestampError::Invalid),
+    };
+    if digits.len() != 8 || !digits.bytes().all(|c| c.is_ascii_digit()) {
+      return Err(TimestampError::Invalid);
+    }
+    let year = digits[0..4].parse().map_err(|_| TimestampError::Invalid)?;
+    let month = digits[4..6].parse().map_err(|_| TimestampError::Invalid)?;
+    let day = digits[6..8].parse().map_err(|_| TimestampError::Invalid)?;
+    SessionDate::new(year, month, day).ok_or(TimestampError::OutOfRange)
+  }
+}
+
+fn is_leap_year(year: i32) -> bool {
+  (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+  match month {
+    2 if is_leap_year(year) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+// day of month of the nth (1 based) sunday
+fn nth_sunday(year: i32, month: u32, n: u32) -> u32 {
+  let first = SessionDate{year, month, day: 1}.weekday();
+  1 + (7 - first) % 7 + (n - 1) * 7
+}
+
+// UTC offset in seconds of US/Eastern at the given wall clock time, using the
+// post 2007 rules: daylight time from 2am on the second sunday in march until
+// 2am on the first sunday in november. The repeated hour in november is
+// taken as daylight time.
+fn eastern_utc_offset(date: SessionDate, nanos_since_midnight: u64) -> i64 {
+  const EST : i64 = -5 * 3600;
+  const EDT : i64 = -4 * 3600;
+  let two_am = 2 * NANOS_PER_HOUR;
+  let dst = match date.month {
+    4..=10 => true,
+    3 => {
+      let start = nth_sunday(date.year, 3, 2);
+      date.day > start || (date.day == start && nanos_since_midnight >= two_am)
+    },
+    11 => {
+      let end = nth_sunday(date.year, 11, 1);
+      date.day < end || (date.day == end && nanos_since_midnight < two_am)
+    },
+    _ => false,
+  };
+  if dst { EDT } else { EST }
+}
+
+#[test]
+fn timestamp_display_and_parse() {
+  let ts = ItchTimestamp::from_hms_nano(9, 30, 0, 1234).unwrap();
+  assert_eq!(ts.to_string(), "09:30:00.000001234");
+  assert_eq!("09:30:00.000001234".parse::<ItchTimestamp>(), Ok(ts));
+  assert_eq!("09:30".parse::<ItchTimestamp>(), ItchTimestamp::from_hms_nano(9, 30, 0, 0).ok_or(TimestampError::Invalid));
+  assert_eq!("16:00:00.5".parse::<ItchTimestamp>().unwrap().nanosecond(), 500_000_000);
+  assert_eq!("9:30".parse::<ItchTimestamp>(), Err(TimestampError::Invalid));
+  assert_eq!("09:30.5".parse::<ItchTimestamp>(), Err(TimestampError::Invalid));
+  assert_eq!("25:00:00".parse::<ItchTimestamp>(), Err(TimestampError::OutOfRange));
+  assert_eq!(ts + Duration::from_secs(60) - ts, Duration::from_secs(60));
+  assert_eq!(ItchTimestamp::MAX.checked_add(Duration::from_nanos(1)), None);
+}
+
+#[test]
+fn timestamp_to_utc() {
+  let open = "09:30".parse::<ItchTimestamp>().unwrap();
+  // 2021-03-12 is before the switch to daylight time, 2021-03-15 after
+  let winter : SessionDate = "2021-03-12".parse().unwrap();
+  let summer : SessionDate = "20210315".parse().unwrap();
+  assert_eq!(winter.weekday(), 5);
+  assert_eq!(open.to_unix_nanos(winter), 1615559400 * NANOS_PER_SECOND as i128);
+  assert_eq!(open.to_unix_nanos(summer), 1615815000 * NANOS_PER_SECOND as i128);
+  assert_eq!(open.to_utc(summer), UNIX_EPOCH + Duration::from_secs(1615815000));
+  // switch days themselves flip at 2am local
+  let spring_forward = SessionDate::new(2021, 3, 14).unwrap();
+  let fall_back = SessionDate::new(2021, 11, 7).unwrap();
+  assert_eq!(eastern_utc_offset(spring_forward, NANOS_PER_HOUR), -5 * 3600);
+  assert_eq!(eastern_utc_offset(spring_forward, 3 * NANOS_PER_HOUR), -4 * 3600);
+  assert_eq!(eastern_utc_offset(fall_back, NANOS_PER_HOUR), -4 * 3600);
+  assert_eq!(eastern_utc_offset(fall_back, 3 * NANOS_PER_HOUR), -5 * 3600);
+  assert_eq!(SessionDate::new(2021, 2, 29), None);
+}